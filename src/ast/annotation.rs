@@ -3,10 +3,10 @@ extern crate ast_derive;
 use ast_derive::AST;
 
 use crate::syntax::{
-    error::ParseError, Context, Lexer, Parse, StartsHere, StringWithOffset, Token,
+    error::ParseError, Context, Lexer, Parse, Ranged, StartsHere, StringWithOffset, Token,
 };
 
-use super::Expression;
+use super::{Expression, Literal};
 
 /// Annotations for statements
 #[derive(Debug, PartialEq, Eq, AST, Clone)]
@@ -17,6 +17,25 @@ pub struct Annotation {
     pub args: Vec<Expression>,
 }
 
+impl Annotation {
+    /// Parse `extern` prefix of function declaration as `@extern` annotation.
+    ///
+    /// `extern "symbol" fn ...` is the same as `@extern("symbol") fn ...`
+    pub fn parse_extern(context: &mut Context<impl Lexer>) -> Result<Self, ParseError> {
+        let keyword = context.consume_keyword::<"extern">()?;
+
+        let mut args = Vec::new();
+        if context.lexer.peek() == Some(Token::String) {
+            args.push(Literal::parse(context)?.into());
+        }
+
+        Ok(Annotation {
+            name: StringWithOffset::from("extern").at(keyword.start()),
+            args,
+        })
+    }
+}
+
 impl StartsHere for Annotation {
     /// Check if annotation 100% starts at current position
     fn starts_here(context: &mut Context<impl Lexer>) -> bool {
@@ -6,8 +6,8 @@ use derive_more::From;
 use crate::{
     ast::{Annotation, Expression, Statement, TypeReference},
     syntax::{
-        error::ParseError, Context, Identifier, Keyword, Lexer, OperatorKind, Parse, Ranged,
-        StartsHere, StringWithOffset, Token,
+        error::ParseError, Context, Identifier, Keyword, Lexer, OperatorKind, Parse, Punctuation,
+        Ranged, StartsHere, StringWithOffset, Token,
     },
 };
 
@@ -16,23 +16,23 @@ use super::GenericParameter;
 /// Parameter of function
 #[derive(Debug, PartialEq, Eq, AST, Clone)]
 pub struct Parameter {
-    /// Location of '<'
-    pub less: usize,
+    /// Opening '<'
+    pub less: Punctuation<"<">,
     /// Parameter's name
     pub name: Identifier,
     /// Parameter's type
     pub ty: TypeReference,
-    /// Location of '>'
-    pub greater: usize,
+    /// Closing '>'
+    pub greater: Punctuation<">">,
 }
 
 impl Ranged for Parameter {
     fn start(&self) -> usize {
-        self.less
+        self.less.start()
     }
 
     fn end(&self) -> usize {
-        self.greater + 1
+        self.greater.end()
     }
 }
 
@@ -41,7 +41,7 @@ impl Parse for Parameter {
 
     /// Parse parameter using lexer
     fn parse(context: &mut Context<impl Lexer>) -> Result<Self, Self::Err> {
-        let less = context.lexer.consume(Token::Less)?.start();
+        let less = context.consume_punctuation::<"<">()?;
 
         let name = context
             .consume_id()
@@ -52,7 +52,7 @@ impl Parse for Parameter {
 
         let ty = TypeReference::parse(context)?;
 
-        let greater = context.lexer.consume_greater()?.start();
+        let greater = context.consume_punctuation::<">">()?;
 
         Ok(Parameter {
             less,
@@ -94,11 +94,15 @@ impl Parse for FunctionNamePart {
         let token = context.lexer.consume_one_of(&[
             Token::Id,
             Token::EscapedId,
+            Token::In,
+            Token::For,
             Token::Less,
             Token::Greater,
             Token::LBracket,
             Token::RBracket,
+            Token::Dot,
             Token::Star,
+            Token::Ampersand,
             Token::Operator(OperatorKind::Prefix),
             Token::Operator(OperatorKind::Infix),
             Token::Operator(OperatorKind::Postfix),
@@ -106,10 +110,14 @@ impl Parse for FunctionNamePart {
         match token {
             Token::Id
             | Token::EscapedId
+            | Token::In
+            | Token::For
             | Token::Greater
             | Token::LBracket
             | Token::RBracket
+            | Token::Dot
             | Token::Star
+            | Token::Ampersand
             | Token::Operator(_) => Ok(context.lexer.string_with_offset().into()),
             Token::Less => {
                 // '<' here is an operator
@@ -117,7 +125,7 @@ impl Parse for FunctionNamePart {
                     return Ok(context.lexer.string_with_offset().into());
                 }
 
-                let less = context.lexer.span().start;
+                let less = Punctuation::at(context.lexer.span().start);
 
                 let name = context
                     .consume_id()
@@ -128,7 +136,7 @@ impl Parse for FunctionNamePart {
 
                 let ty = TypeReference::parse(context)?;
 
-                let greater = context.lexer.consume_greater()?.start();
+                let greater = context.consume_punctuation::<">">()?;
 
                 Ok(Parameter {
                     less,
@@ -273,24 +281,24 @@ mod tests {
                     Identifier::from("distance").at(3).into(),
                     Identifier::from("from").at(12).into(),
                     Parameter {
-                        less: 17,
+                        less: Punctuation::at(17),
                         name: Identifier::from("a").at(18).into(),
                         ty: TypeReference {
                             name: Identifier::from("Point").at(21).into(),
                             generic_parameters: Vec::new(),
                         },
-                        greater: 26,
+                        greater: Punctuation::at(26),
                     }
                     .into(),
                     Identifier::from("to").at(28).into(),
                     Parameter {
-                        less: 31,
+                        less: Punctuation::at(31),
                         name: Identifier::from("b").at(32).into(),
                         ty: TypeReference {
                             name: Identifier::from("Point").at(35).into(),
                             generic_parameters: Vec::new(),
                         },
-                        greater: 40,
+                        greater: Punctuation::at(40),
                     }
                     .into(),
                 ],
@@ -329,4 +337,82 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn property_declaration() {
+        let func = "fn <self: Point>.length -> Integer"
+            .parse::<FunctionDeclaration>()
+            .unwrap();
+        assert_eq!(
+            func,
+            FunctionDeclaration {
+                keyword: Keyword::<"fn">::at(0),
+                generic_parameters: vec![],
+                name_parts: vec![
+                    Parameter {
+                        less: Punctuation::at(3),
+                        name: Identifier::from("self").at(4).into(),
+                        ty: TypeReference {
+                            name: Identifier::from("Point").at(10).into(),
+                            generic_parameters: Vec::new(),
+                        },
+                        greater: Punctuation::at(15),
+                    }
+                    .into(),
+                    Identifier::from(".").at(16).into(),
+                    Identifier::from("length").at(17).into(),
+                ],
+                return_type: Some(TypeReference {
+                    name: Identifier::from("Integer").at(27).into(),
+                    generic_parameters: Vec::new(),
+                }),
+                annotations: vec![],
+                body: vec![],
+                implicit_return: false,
+            }
+        );
+    }
+
+    #[test]
+    fn keywords_in_name() {
+        let func = "fn iterator for <set: Set> in <x: X>"
+            .parse::<FunctionDeclaration>()
+            .unwrap();
+        assert_eq!(
+            func,
+            FunctionDeclaration {
+                keyword: Keyword::<"fn">::at(0),
+                generic_parameters: vec![],
+                name_parts: vec![
+                    Identifier::from("iterator").at(3).into(),
+                    Identifier::from("for").at(12).into(),
+                    Parameter {
+                        less: Punctuation::at(16),
+                        name: Identifier::from("set").at(17).into(),
+                        ty: TypeReference {
+                            name: Identifier::from("Set").at(22).into(),
+                            generic_parameters: Vec::new(),
+                        },
+                        greater: Punctuation::at(25),
+                    }
+                    .into(),
+                    Identifier::from("in").at(27).into(),
+                    Parameter {
+                        less: Punctuation::at(30),
+                        name: Identifier::from("x").at(31).into(),
+                        ty: TypeReference {
+                            name: Identifier::from("X").at(34).into(),
+                            generic_parameters: Vec::new(),
+                        },
+                        greater: Punctuation::at(35),
+                    }
+                    .into(),
+                ],
+                return_type: None,
+                annotations: vec![],
+                body: vec![],
+                implicit_return: false,
+            }
+        );
+    }
 }
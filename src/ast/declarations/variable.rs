@@ -45,21 +45,14 @@ impl StartsHere for VariableDeclaration {
     }
 }
 
-impl Parse for VariableDeclaration {
-    type Err = ParseError;
-
-    /// Parse variable declaration using lexer
-    fn parse(context: &mut Context<impl Lexer>) -> Result<Self, Self::Err> {
-        let keyword = context.consume_keyword::<"let">()?;
-
-        let mutable = context.lexer.consume(Token::Mut).is_ok();
-
-        let name = context.consume_id().or_else(|_| {
-            Err(MissingVariableName {
-                at: context.lexer.span().end.into(),
-            })
-        })?;
-
+impl VariableDeclaration {
+    /// Parse the rest of variable declaration, if you have keyword, mutability and name
+    pub(crate) fn parse_with_name(
+        context: &mut Context<impl Lexer>,
+        keyword: Keyword<"let">,
+        mutability: Mutability,
+        name: Identifier,
+    ) -> Result<Self, ParseError> {
         let ty = if context.lexer.consume(Token::Colon).is_ok() {
             Some(TypeReference::parse(context)?)
         } else {
@@ -77,11 +70,35 @@ impl Parse for VariableDeclaration {
             name,
             ty,
             initializer,
-            mutability: match mutable {
+            mutability,
+        })
+    }
+}
+
+impl Parse for VariableDeclaration {
+    type Err = ParseError;
+
+    /// Parse variable declaration using lexer
+    fn parse(context: &mut Context<impl Lexer>) -> Result<Self, Self::Err> {
+        let keyword = context.consume_keyword::<"let">()?;
+
+        let mutable = context.lexer.consume(Token::Mut).is_ok();
+
+        let name = context.consume_id().or_else(|_| {
+            Err(MissingVariableName {
+                at: context.lexer.span().end.into(),
+            })
+        })?;
+
+        VariableDeclaration::parse_with_name(
+            context,
+            keyword,
+            match mutable {
                 true => Mutability::Mutable,
                 false => Mutability::Immutable,
             },
-        })
+            name,
+        )
     }
 }
 
@@ -4,7 +4,7 @@ use ast_derive::AST;
 
 use derive_more::{From, TryInto};
 
-use super::{parse_binary_expression, Expression, TypeReference, Typename};
+use super::{parse_binary_expression, variable::NAME_TOKENS, Expression, TypeReference, Typename};
 
 use crate::syntax::{
     error::ParseError, Context, Identifier, Lexer, Parse, Ranged, StartsHere, StringWithOffset,
@@ -47,15 +47,22 @@ impl Parse for CallNamePart {
             return Ok(context.lexer.string_with_offset().into());
         }
 
-        let expr = parse_binary_expression(context)?;
-        Ok(match expr {
+        Ok(parse_binary_expression(context)?.into_name_part())
+    }
+}
+
+impl Expression {
+    /// Convert expression to cell of function call.
+    /// Identifiers become text, as they may be a part of function's name
+    pub fn into_name_part(self) -> CallNamePart {
+        match self {
             Expression::VariableReference(var) => var.name.into(),
             Expression::TypeReference(TypeReference {
                 name: Typename::Identifier(name),
                 generic_parameters,
             }) if generic_parameters.len() == 0 => name.into(),
-            _ => expr.into(),
-        })
+            _ => self.into(),
+        }
     }
 }
 
@@ -94,6 +101,26 @@ impl Call {
         format
     }
 
+    /// Desugar method call `receiver.name rest...` to `name receiver rest...`
+    ///
+    /// Returns `None`, if this call doesn't start with member reference
+    pub fn as_method_call(&self) -> Option<Call> {
+        let Some(CallNamePart::Argument(Expression::MemberReference(m))) = self.name_parts.first()
+        else {
+            return None;
+        };
+
+        let mut name_parts = vec![
+            m.name.clone().into(),
+            CallNamePart::Argument((*m.base).clone()),
+        ];
+        name_parts.extend(self.name_parts.iter().skip(1).cloned());
+        Some(Call {
+            kind: self.kind,
+            name_parts,
+        })
+    }
+
     /// Parse atomic function call using lexer
     pub fn parse_atomic(
         context: &mut Context<impl Lexer>,
@@ -114,10 +141,7 @@ impl Call {
 impl StartsHere for Call {
     /// Check that call may start at current lexer position
     fn starts_here(context: &mut Context<impl Lexer>) -> bool {
-        context
-            .lexer
-            .try_match_one_of(&[Token::Id, Token::EscapedId])
-            .is_ok()
+        context.lexer.try_match_one_of(NAME_TOKENS).is_ok()
     }
 }
 
@@ -139,7 +163,7 @@ impl Parse for Call {
             name_parts.push(CallNamePart::parse(context)?);
 
             let token = context.lexer.peek();
-            if token.map_or(true, |t| t.ends_expression()) {
+            if context.block_ended() || token.map_or(true, |t| t.ends_expression()) {
                 break;
             }
         }
@@ -1,7 +1,9 @@
 extern crate ast_derive;
 use ast_derive::AST;
 
-use crate::syntax::{error::ParseError, Context, Identifier, Lexer, Parse, Ranged, Token};
+use crate::syntax::{
+    error::ParseError, Context, Identifier, Lexer, Parse, Punctuation, Ranged, Token,
+};
 
 use super::{Expression, TypeReference, VariableReference};
 
@@ -49,12 +51,12 @@ impl Parse for Initializer {
 pub struct Constructor {
     /// Type of constructed object
     pub ty: TypeReference,
-    /// Offset of '{'
-    pub lbrace: usize,
+    /// Opening brace
+    pub lbrace: Punctuation<"{">,
     /// Member initializers
     pub initializers: Vec<Initializer>,
-    /// Offset of '}'
-    pub rbrace: usize,
+    /// Closing brace
+    pub rbrace: Punctuation<"}">,
 }
 
 impl Ranged for Constructor {
@@ -63,7 +65,7 @@ impl Ranged for Constructor {
     }
 
     fn end(&self) -> usize {
-        self.rbrace + 1
+        self.rbrace.end()
     }
 }
 
@@ -82,7 +84,7 @@ impl Constructor {
         context: &mut Context<impl Lexer>,
         ty: TypeReference,
     ) -> Result<Self, <Self as Parse>::Err> {
-        let lbrace = context.lexer.consume(Token::LBrace)?.start();
+        let lbrace = context.consume_punctuation::<"{">()?;
         let mut initializers = Vec::new();
         while context.lexer.peek() != Some(Token::RBrace) {
             initializers.push(Initializer::parse(context)?);
@@ -93,7 +95,7 @@ impl Constructor {
 
             context.lexer.consume(Token::Comma)?;
         }
-        let rbrace = context.lexer.consume(Token::RBrace)?.start();
+        let rbrace = context.consume_punctuation::<"}">()?;
 
         Ok(Constructor {
             ty,
@@ -121,9 +123,9 @@ mod tests {
                     name: Identifier::from("Empty").into(),
                     generic_parameters: Vec::new(),
                 },
-                lbrace: 6,
+                lbrace: Punctuation::at(6),
                 initializers: Vec::new(),
-                rbrace: 7,
+                rbrace: Punctuation::at(7),
             }
         );
     }
@@ -138,7 +140,7 @@ mod tests {
                     name: Identifier::from("Point").into(),
                     generic_parameters: Vec::new(),
                 },
-                lbrace: 6,
+                lbrace: Punctuation::at(6),
                 initializers: vec![
                     Initializer {
                         name: None,
@@ -155,7 +157,7 @@ mod tests {
                         .into(),
                     },
                 ],
-                rbrace: 11,
+                rbrace: Punctuation::at(11),
             }
         );
     }
@@ -170,7 +172,7 @@ mod tests {
                     name: Identifier::from("Point").into(),
                     generic_parameters: Vec::new(),
                 },
-                lbrace: 6,
+                lbrace: Punctuation::at(6),
                 initializers: vec![
                     Initializer {
                         name: Identifier::from("x").at(7).into(),
@@ -189,7 +191,7 @@ mod tests {
                         .into()
                     },
                 ],
-                rbrace: 17,
+                rbrace: Punctuation::at(17),
             }
         );
     }
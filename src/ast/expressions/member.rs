@@ -2,16 +2,18 @@ extern crate ast_derive;
 use ast_derive::AST;
 
 use crate::syntax::{
-    error::ParseError, Context, Identifier, Lexer, Parse, Ranged, Token,
+    error::ParseError, Context, Identifier, Lexer, Parse, Ranged, StringWithOffset, Token,
 };
 
-use super::{parse_atomic_expression, Expression};
+use super::{parse_atomic_expression, Call, CallNamePart, Expression, FnKind};
 
 /// AST for member reference
 #[derive(Debug, PartialEq, Eq, AST, Clone)]
 pub struct MemberReference {
     /// Base expression
     pub base: Box<Expression>,
+    /// Dot between base and member name
+    pub dot: StringWithOffset,
     /// Referenced member name
     pub name: Identifier,
 }
@@ -22,12 +24,37 @@ impl MemberReference {
         context: &mut Context<impl Lexer>,
         mut base: Box<Expression>,
     ) -> Result<Self, <Self as Parse>::Err> {
-        while context.lexer.consume(Token::Dot).is_ok() {
+        while let Ok(dot) = context.lexer.consume(Token::Dot) {
             let name = context.consume_id()?;
-            base = Box::new(MemberReference { base, name }.into());
+            base = Box::new(MemberReference { base, dot, name }.into());
         }
         return Ok((*base).try_into().unwrap());
     }
+
+    /// Get call to property accessor `fn <self: T>.name`
+    /// for this member reference
+    pub fn as_property_call(&self) -> Call {
+        Call {
+            kind: FnKind::Function,
+            name_parts: vec![
+                (*self.base).clone().into(),
+                self.dot.clone().into(),
+                self.name.clone().into(),
+            ],
+        }
+    }
+
+    /// Get call to free function `name <receiver>`
+    /// for this member reference
+    pub fn as_method_call(&self) -> Call {
+        Call {
+            kind: FnKind::Function,
+            name_parts: vec![
+                self.name.clone().into(),
+                CallNamePart::Argument((*self.base).clone()),
+            ],
+        }
+    }
 }
 
 impl Parse for MemberReference {
@@ -68,6 +95,7 @@ mod tests {
         assert_eq!(
             m,
             MemberReference {
+                dot: StringWithOffset::from(".").at(5),
                 name: Identifier::from("x").at(6),
                 base: Box::new(
                     VariableReference {
@@ -85,9 +113,11 @@ mod tests {
         assert_eq!(
             m,
             MemberReference {
+                dot: StringWithOffset::from(".").at(6),
                 name: Identifier::from("name").at(7),
                 base: Box::new(
                     MemberReference {
+                        dot: StringWithOffset::from(".").at(3),
                         name: Identifier::from("ty").at(4),
                         base: Box::new(
                             VariableReference {
@@ -101,4 +131,82 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_method_call() {
+        let m = "x.print".parse::<MemberReference>().unwrap();
+        assert_eq!(
+            m.as_method_call(),
+            Call {
+                kind: FnKind::Function,
+                name_parts: vec![
+                    Identifier::from("print").at(2).into(),
+                    CallNamePart::Argument(
+                        VariableReference {
+                            name: Identifier::from("x"),
+                        }
+                        .into()
+                    ),
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn test_property_call_with_whitespace() {
+        let m = "point . x".parse::<MemberReference>().unwrap();
+        assert_eq!(
+            m.as_property_call(),
+            Call {
+                kind: FnKind::Function,
+                name_parts: vec![
+                    CallNamePart::Argument(
+                        VariableReference {
+                            name: Identifier::from("point"),
+                        }
+                        .into()
+                    ),
+                    Identifier::from(".").at(6).into(),
+                    Identifier::from("x").at(8).into(),
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn test_member_of_indexed() {
+        use crate::ast::Literal;
+
+        let m = "a[0].size".parse::<MemberReference>().unwrap();
+        assert_eq!(
+            m,
+            MemberReference {
+                dot: StringWithOffset::from(".").at(4),
+                name: Identifier::from("size").at(5),
+                base: Box::new(
+                    Call {
+                        kind: FnKind::Function,
+                        name_parts: vec![
+                            CallNamePart::Argument(
+                                VariableReference {
+                                    name: Identifier::from("a"),
+                                }
+                                .into()
+                            ),
+                            Identifier::from("[").at(1).into(),
+                            CallNamePart::Argument(
+                                Literal::Integer {
+                                    offset: 2,
+                                    value: "0".into(),
+                                }
+                                .into()
+                            ),
+                            Identifier::from("]").at(3).into(),
+                        ],
+                    }
+                    .into()
+                ),
+            }
+        );
+    }
 }
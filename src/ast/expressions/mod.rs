@@ -22,6 +22,7 @@ pub use constructor::*;
 extern crate ast_derive;
 use ast_derive::AST;
 
+use crate::ast::Loop;
 use crate::syntax::{
     error::{MissingExpression, ParseError},
     Context, Lexer, OperatorKind, Parse, Ranged, StartsHere, Token,
@@ -39,6 +40,7 @@ pub enum Expression {
     TypeReference(TypeReference),
     MemberReference(MemberReference),
     Constructor(Constructor),
+    Loop(Loop),
 }
 
 impl StartsHere for Expression {
@@ -48,6 +50,7 @@ impl StartsHere for Expression {
             || VariableReference::starts_here(context)
             || TypeReference::starts_here(context)
             || Tuple::starts_here(context)
+            || Loop::starts_here(context)
             || matches!(
                 context.lexer.peek(),
                 Some(Token::Operator(_) | Token::Less | Token::Greater | Token::Star)
@@ -57,6 +60,11 @@ impl StartsHere for Expression {
 
 /// Parse atomic expression
 fn parse_atomic_expression(context: &mut Context<impl Lexer>) -> Result<Expression, ParseError> {
+    if Loop::starts_here(context) {
+        // Nothing may follow the body of a loop on the same line
+        return Ok(Loop::parse(context)?.into());
+    }
+
     let mut expr: Expression = if Literal::starts_here(context) {
         Literal::parse(context)?.into()
     } else if Tuple::starts_here(context) {
@@ -83,24 +91,27 @@ fn parse_atomic_expression(context: &mut Context<impl Lexer>) -> Result<Expressi
         .into());
     };
 
-    if context.lexer.try_match(Token::Dot).is_ok() {
-        expr = MemberReference::parse_with_base(context, Box::new(expr))?.into();
-    }
-
-    if let Ok(lbracket) = context.lexer.try_match(Token::LBracket)
-        && expr.end() == lbracket.start()
-    {
-        let lbracket = context.lexer.consume(Token::LBracket)?;
-        let mut name_parts = vec![expr.into(), lbracket.into()];
-        if context.lexer.try_match(Token::RBracket).is_err() {
-            name_parts.push(Expression::parse(context)?.into());
-        }
-        name_parts.push(context.lexer.consume(Token::RBracket)?.into());
-        return Ok(Call {
-            kind: FnKind::Function,
-            name_parts,
+    // Member references and indexing may be chained, e.g `x.items[0].print`
+    loop {
+        if context.lexer.try_match(Token::Dot).is_ok() {
+            expr = MemberReference::parse_with_base(context, Box::new(expr))?.into();
+        } else if let Ok(lbracket) = context.lexer.try_match(Token::LBracket)
+            && expr.end() == lbracket.start()
+        {
+            let lbracket = context.lexer.consume(Token::LBracket)?;
+            let mut name_parts = vec![expr.into(), lbracket.into()];
+            if context.lexer.try_match(Token::RBracket).is_err() {
+                name_parts.push(Expression::parse(context)?.into());
+            }
+            name_parts.push(context.lexer.consume(Token::RBracket)?.into());
+            expr = Call {
+                kind: FnKind::Function,
+                name_parts,
+            }
+            .into();
+        } else {
+            break;
         }
-        .into());
     }
 
     Ok(expr)
@@ -109,6 +120,9 @@ fn parse_atomic_expression(context: &mut Context<impl Lexer>) -> Result<Expressi
 /// postfix-expression: atomic-expression postfix-operator?
 fn parse_postfix_expression(context: &mut Context<impl Lexer>) -> Result<Expression, ParseError> {
     let operand = parse_atomic_expression(context)?;
+    if context.block_ended() {
+        return Ok(operand);
+    }
 
     Ok(
         if let Ok(operator) = context
@@ -142,13 +156,39 @@ fn parse_prefix_expression(context: &mut Context<impl Lexer>) -> Result<Expressi
     })
 }
 
+/// Parse `as` conversions of operand of binary operator, if any.
+/// They bind tighter than binary operators: `"a" + 1 as String` is `"a" + (1 as String)`
+fn parse_conversions(
+    context: &mut Context<impl Lexer>,
+    mut operand: Expression,
+) -> Result<Expression, ParseError> {
+    while !context.block_ended()
+        && context.lexer.peek() == Some(Token::Id)
+        && context.lexer.peek_slice() == "as"
+    {
+        let keyword = context.lexer.consume(Token::Id)?;
+        let ty: Expression = TypeReference::parse(context)?.into();
+        operand = Call {
+            kind: FnKind::Function,
+            name_parts: vec![
+                operand.into_name_part(),
+                keyword.into(),
+                ty.into_name_part(),
+            ],
+        }
+        .into();
+    }
+
+    Ok(operand)
+}
+
 /// Parse right hand side of binary expression
 fn parse_binary_rhs(
     context: &mut Context<impl Lexer>,
     prev_op: Option<&str>,
     mut left: Expression,
 ) -> Result<Expression, ParseError> {
-    while context.lexer.peek().is_some_and(|t| t.is_infix_operator()) {
+    while !context.block_ended() && context.lexer.peek().is_some_and(|t| t.is_infix_operator()) {
         let op = context.lexer.consume_operator()?;
 
         if prev_op
@@ -158,7 +198,8 @@ fn parse_binary_rhs(
         }
 
         let mut right = parse_prefix_expression(context)?;
-        if context.lexer.peek().is_some_and(|t| t.is_infix_operator()) {
+        right = parse_conversions(context, right)?;
+        if !context.block_ended() && context.lexer.peek().is_some_and(|t| t.is_infix_operator()) {
             let next_op = context.lexer.peek_slice();
             if context
                 .precedence_groups
@@ -231,6 +272,35 @@ impl Ranged for Expression {
             Expression::TypeReference(ty_ref) => ty_ref.range(),
             Expression::MemberReference(m) => m.range(),
             Expression::Constructor(c) => c.range(),
+            Expression::Loop(l) => l.range(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_conversion_binds_tighter_than_operators() {
+        let expr = "\"a\" + 1 as String + \"b\"".parse::<Expression>().unwrap();
+        let Expression::Call(concatenation) = expr else {
+            panic!("expected call, got {expr:?}");
+        };
+        assert_eq!(concatenation.name_format(), "<> + <>");
+
+        let Some(CallNamePart::Argument(Expression::Call(left))) = concatenation.name_parts.first()
+        else {
+            panic!("expected call, got {concatenation:?}");
+        };
+        assert_eq!(left.name_format(), "<> + <>");
+
+        let Some(CallNamePart::Argument(Expression::Call(conversion))) = left.name_parts.last()
+        else {
+            panic!("expected call, got {left:?}");
+        };
+        assert_eq!(conversion.name_format(), "<> as String");
+    }
+}
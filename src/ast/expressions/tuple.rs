@@ -1,28 +1,30 @@
 extern crate ast_derive;
 use ast_derive::AST;
 
-use crate::syntax::{error::ParseError, Context, Lexer, Parse, Ranged, StartsHere, Token};
+use crate::syntax::{
+    error::ParseError, Context, Lexer, Parse, Punctuation, Ranged, StartsHere, Token,
+};
 
 use super::Expression;
 
 /// AST for tuple
 #[derive(Debug, PartialEq, Eq, AST, Clone)]
 pub struct Tuple {
-    /// Offset of '('
-    pub lparen: usize,
+    /// Opening parenthesis
+    pub lparen: Punctuation<"(">,
     /// Expression in parentheses
     pub expressions: Vec<Expression>,
-    /// Offset of ')'
-    pub rparen: usize,
+    /// Closing parenthesis
+    pub rparen: Punctuation<")">,
 }
 
 impl Ranged for Tuple {
     fn start(&self) -> usize {
-        self.lparen
+        self.lparen.start()
     }
 
     fn end(&self) -> usize {
-        self.rparen + 1
+        self.rparen.end()
     }
 }
 
@@ -37,7 +39,7 @@ impl Parse for Tuple {
     type Err = ParseError;
 
     fn parse(context: &mut Context<impl Lexer>) -> Result<Self, Self::Err> {
-        let lparen = context.lexer.consume(Token::LParen)?.start();
+        let lparen = context.consume_punctuation::<"(">()?;
 
         let mut expressions = Vec::new();
         while context.lexer.peek().map_or(false, |t| t != Token::RParen) {
@@ -50,7 +52,7 @@ impl Parse for Tuple {
             context.lexer.consume(Token::Comma)?;
         }
 
-        let rparen = context.lexer.consume(Token::RParen)?.start();
+        let rparen = context.consume_punctuation::<")">()?;
 
         Ok(Tuple {
             lparen,
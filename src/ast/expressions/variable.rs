@@ -12,19 +12,28 @@ pub struct VariableReference {
     pub name: Identifier,
 }
 
+/// Tokens, that may be used as a name of variable or function in expressions.
+///
+/// `type`, `in` and `for` keywords may be used without escaping,
+/// e.g. `type of x`, `x in set` or `iterator for array`
+pub(crate) const NAME_TOKENS: &[Token] = &[
+    Token::Id,
+    Token::EscapedId,
+    Token::Type,
+    Token::In,
+    Token::For,
+];
+
 impl StartsHere for VariableReference {
     /// Check that variable reference may start at current lexer position
     fn starts_here(context: &mut Context<impl Lexer>) -> bool {
-        context
-            .lexer
-            .try_match_one_of(&[Token::Id, Token::EscapedId])
-            .is_ok_and(|_| {
-                Identifier::from(context.lexer.peek_string_with_offset())
-                    .as_str()
-                    .chars()
-                    .nth(0)
-                    .is_some_and(|c| c.is_lowercase())
-            })
+        context.lexer.try_match_one_of(NAME_TOKENS).is_ok_and(|_| {
+            Identifier::from(context.lexer.peek_string_with_offset())
+                .as_str()
+                .chars()
+                .nth(0)
+                .is_some_and(|c| c.is_lowercase())
+        })
     }
 }
 
@@ -33,8 +42,9 @@ impl Parse for VariableReference {
 
     /// Parse variable reference using lexer
     fn parse(context: &mut Context<impl Lexer>) -> Result<Self, Self::Err> {
+        context.lexer.consume_one_of(NAME_TOKENS)?;
         Ok(VariableReference {
-            name: context.consume_id()?,
+            name: context.lexer.string_with_offset().into(),
         })
     }
 }
@@ -45,3 +55,58 @@ impl Ranged for VariableReference {
         self.name.range()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::{Call, CallNamePart, Expression, FnKind, Literal};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_type_of() {
+        let expr = "type of 1".parse::<Expression>().unwrap();
+        assert_eq!(
+            expr,
+            Call {
+                kind: FnKind::Function,
+                name_parts: vec![
+                    CallNamePart::Text(Identifier::from("type").at(0)),
+                    CallNamePart::Text(Identifier::from("of").at(5)),
+                    Literal::Integer {
+                        offset: 8,
+                        value: "1".to_string(),
+                    }
+                    .into(),
+                ],
+            }
+            .into()
+        );
+    }
+
+    #[test]
+    fn test_in() {
+        let expr = "x in set".parse::<Expression>().unwrap();
+        assert_eq!(
+            expr,
+            Call {
+                kind: FnKind::Function,
+                name_parts: vec![
+                    CallNamePart::Argument(
+                        VariableReference {
+                            name: Identifier::from("x").at(0),
+                        }
+                        .into()
+                    ),
+                    CallNamePart::Text(Identifier::from("in").at(2)),
+                    CallNamePart::Argument(
+                        VariableReference {
+                            name: Identifier::from("set").at(5),
+                        }
+                        .into()
+                    ),
+                ],
+            }
+            .into()
+        );
+    }
+}
@@ -38,9 +38,11 @@ impl Parse for Module {
         let mut errors = Vec::new();
         let mut statements = Vec::new();
 
+        context.recover = true;
         context.lexer.skip_spaces();
         while context.lexer.peek().is_some() {
             let res = Statement::parse(context);
+            errors.append(&mut context.errors);
             match res {
                 Ok(stmt) => {
                     statements.push(stmt);
@@ -48,7 +50,7 @@ impl Parse for Module {
                 }
                 Err(e) => {
                     errors.push(e);
-                    context.lexer.skip_till_next_line();
+                    context.lexer.skip_till_next_statement(0);
                 }
             }
         }
@@ -73,3 +75,32 @@ impl Module {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recovery() {
+        let errors = "fn f:\n\tlet = 1\n\tprintln 1\nlet = 2\nprintln 3"
+            .parse::<Module>()
+            .unwrap_err()
+            .errors;
+        assert_eq!(errors.len(), 2);
+    }
+
+    #[test]
+    fn test_recovery_inside_blocks() {
+        let errors = "fn f:\n\tlet = 1\n\tprintln 1\n\tlet = 2\nlet = 3\nprintln 3"
+            .parse::<Module>()
+            .unwrap_err()
+            .errors;
+        assert_eq!(errors.len(), 3);
+
+        let errors = "fn f:\n\tfn g:\n\t\tlet = 1\n\t\t\n\t\tprintln 2\n\tlet = 3\nprintln 4"
+            .parse::<Module>()
+            .unwrap_err()
+            .errors;
+        assert_eq!(errors.len(), 2);
+    }
+}
@@ -47,3 +47,47 @@ impl Parse for Assignment {
         Ok(Assignment { target, value })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::{Call, CallNamePart, FnKind, Literal, VariableReference};
+    use crate::syntax::{Identifier, StringWithOffset};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_assignment_to_index() {
+        let assignment = "a[0] = 1".parse::<Assignment>().unwrap();
+        assert_eq!(
+            assignment,
+            Assignment {
+                target: Call {
+                    kind: FnKind::Function,
+                    name_parts: vec![
+                        CallNamePart::Argument(
+                            VariableReference {
+                                name: Identifier::from("a").at(0),
+                            }
+                            .into()
+                        ),
+                        StringWithOffset::from("[").at(1).into(),
+                        CallNamePart::Argument(
+                            Literal::Integer {
+                                offset: 2,
+                                value: "0".to_string(),
+                            }
+                            .into()
+                        ),
+                        StringWithOffset::from("]").at(3).into(),
+                    ],
+                }
+                .into(),
+                value: Literal::Integer {
+                    offset: 7,
+                    value: "1".to_string(),
+                }
+                .into(),
+            }
+        );
+    }
+}
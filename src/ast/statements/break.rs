@@ -0,0 +1,124 @@
+extern crate ast_derive;
+use ast_derive::AST;
+
+use crate::ast::Expression;
+use crate::syntax::{error::ParseError, Lexer, Parse, Token};
+use crate::syntax::{Context, Keyword, Ranged, StartsHere};
+
+/// AST for break statement
+///
+/// # Example
+/// ```ppl
+/// break
+/// break x + 1
+/// ```
+#[derive(Debug, PartialEq, Eq, AST, Clone)]
+pub struct Break {
+    /// Keyword `break`
+    pub keyword: Keyword<"break">,
+    /// Value, passed out of loop
+    pub value: Option<Expression>,
+}
+
+impl Ranged for Break {
+    fn start(&self) -> usize {
+        self.keyword.start()
+    }
+
+    fn end(&self) -> usize {
+        self.value.as_ref().map_or(self.keyword.end(), |v| v.end())
+    }
+}
+
+impl StartsHere for Break {
+    /// Check that break may start at current lexer position
+    fn starts_here(context: &mut Context<impl Lexer>) -> bool {
+        context.lexer.peek() == Some(Token::Break)
+    }
+}
+
+impl Parse for Break {
+    type Err = ParseError;
+
+    /// Parse break using lexer
+    fn parse(context: &mut Context<impl Lexer>) -> Result<Self, Self::Err> {
+        let keyword = context.consume_keyword::<"break">()?;
+
+        let value = if Expression::starts_here(context) {
+            Some(Expression::parse(context)?)
+        } else {
+            None
+        };
+
+        Ok(Break { keyword, value })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::{Declaration, Literal, Loop, Module, Statement, VariableDeclaration};
+    use crate::mutability::Mutability;
+    use crate::syntax::Identifier;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_break() {
+        let stmt = "break".parse::<Break>().unwrap();
+        assert_eq!(
+            stmt,
+            Break {
+                keyword: Keyword::<"break">::at(0),
+                value: None,
+            }
+        );
+
+        let stmt = "break 1".parse::<Break>().unwrap();
+        assert_eq!(
+            stmt,
+            Break {
+                keyword: Keyword::<"break">::at(0),
+                value: Some(
+                    Literal::Integer {
+                        offset: 6,
+                        value: "1".to_string(),
+                    }
+                    .into()
+                ),
+            }
+        );
+    }
+
+    #[test]
+    fn test_loop_expression() {
+        let stmts = "let x = loop:\n\tbreak 1\nx"
+            .parse::<Module>()
+            .unwrap()
+            .statements;
+        assert_eq!(stmts.len(), 2);
+        assert_eq!(
+            stmts[0],
+            Statement::Declaration(Declaration::Variable(VariableDeclaration {
+                keyword: Keyword::<"let">::at(0),
+                name: Identifier::from("x").at(4),
+                ty: None,
+                initializer: Loop {
+                    keyword: Keyword::<"loop">::at(8),
+                    body: vec![Break {
+                        keyword: Keyword::<"break">::at(15),
+                        value: Some(
+                            Literal::Integer {
+                                offset: 21,
+                                value: "1".to_string(),
+                            }
+                            .into()
+                        ),
+                    }
+                    .into()],
+                }
+                .into(),
+                mutability: Mutability::Immutable,
+            }))
+        );
+    }
+}
@@ -0,0 +1,208 @@
+extern crate ast_derive;
+use ast_derive::AST;
+
+use crate::ast::{Declaration, Expression, Statement, TypeReference, VariableDeclaration};
+use crate::mutability::{Mutability, Mutable};
+use crate::syntax::error::{MissingVariableName, ParseError};
+use crate::syntax::{Context, Identifier, Keyword, Lexer, Parse, Ranged, Token};
+
+/// AST for destructuring of an object into variables
+///
+/// # Example
+/// ```ppl
+/// let Point { x, y } = p
+/// let (x, y) = point pair
+/// ```
+#[derive(Debug, PartialEq, Eq, AST, Clone)]
+pub struct Destructuring {
+    /// Keyword `let`
+    pub keyword: Keyword<"let">,
+    /// Type of destructured object.
+    /// `None` for tuple form, where members are taken in order
+    pub ty: Option<TypeReference>,
+    /// Names of members to introduce as variables
+    pub names: Vec<Identifier>,
+    /// Destructured object
+    pub initializer: Expression,
+
+    /// Are introduced variables mutable
+    pub mutability: Mutability,
+}
+
+impl Destructuring {
+    /// Parse the rest of destructuring, if you have keyword, mutability and type name
+    pub(crate) fn parse_with_ty(
+        context: &mut Context<impl Lexer>,
+        keyword: Keyword<"let">,
+        mutability: Mutability,
+        ty: Identifier,
+    ) -> Result<Self, ParseError> {
+        context.lexer.consume(Token::LBrace)?;
+
+        let names = context.parse_comma_separated(|context| context.consume_id());
+
+        context.lexer.consume(Token::RBrace)?;
+
+        let ty = TypeReference {
+            name: ty.into(),
+            generic_parameters: vec![],
+        };
+        Self::parse_initializer(context, keyword, mutability, Some(ty), names)
+    }
+
+    /// Parse the rest of tuple destructuring, if you have keyword and mutability
+    pub(crate) fn parse_tuple(
+        context: &mut Context<impl Lexer>,
+        keyword: Keyword<"let">,
+        mutability: Mutability,
+    ) -> Result<Self, ParseError> {
+        context.lexer.consume(Token::LParen)?;
+
+        let names = context.parse_comma_separated(|context| context.consume_id());
+
+        context.lexer.consume(Token::RParen)?;
+
+        Self::parse_initializer(context, keyword, mutability, None, names)
+    }
+
+    /// Parse `= <initializer>` part of destructuring
+    fn parse_initializer(
+        context: &mut Context<impl Lexer>,
+        keyword: Keyword<"let">,
+        mutability: Mutability,
+        ty: Option<TypeReference>,
+        names: Vec<Identifier>,
+    ) -> Result<Self, ParseError> {
+        context.lexer.consume(Token::Assign)?;
+
+        let initializer = Expression::parse(context)?;
+
+        context.consume_eol()?;
+
+        Ok(Destructuring {
+            keyword,
+            ty,
+            names,
+            initializer,
+            mutability,
+        })
+    }
+}
+
+impl Ranged for Destructuring {
+    fn start(&self) -> usize {
+        self.keyword.start()
+    }
+
+    fn end(&self) -> usize {
+        self.initializer.end()
+    }
+}
+
+impl Mutable for Destructuring {
+    fn is_mutable(&self) -> bool {
+        self.mutability.is_mutable()
+    }
+}
+
+/// Parse `let` keyword and mutability
+fn parse_let_keyword(
+    context: &mut Context<impl Lexer>,
+) -> Result<(Keyword<"let">, Mutability), ParseError> {
+    let keyword = context.consume_keyword::<"let">()?;
+
+    let mutability = match context.lexer.consume(Token::Mut).is_ok() {
+        true => Mutability::Mutable,
+        false => Mutability::Immutable,
+    };
+
+    Ok((keyword, mutability))
+}
+
+/// Parse statement, starting with `let`.
+/// It is either [`VariableDeclaration`] or [`Destructuring`]
+pub(crate) fn parse_let(context: &mut Context<impl Lexer>) -> Result<Statement, ParseError> {
+    let (keyword, mutability) = parse_let_keyword(context)?;
+
+    if context.lexer.peek() == Some(Token::LParen) {
+        return Ok(Destructuring::parse_tuple(context, keyword, mutability)?.into());
+    }
+
+    let name = context.consume_id().or_else(|_| {
+        Err(MissingVariableName {
+            at: context.lexer.span().end.into(),
+        })
+    })?;
+
+    if context.lexer.try_match(Token::LBrace).is_ok() {
+        return Ok(Destructuring::parse_with_ty(context, keyword, mutability, name)?.into());
+    }
+
+    Ok(Declaration::from(VariableDeclaration::parse_with_name(
+        context, keyword, mutability, name,
+    )?)
+    .into())
+}
+
+impl Parse for Destructuring {
+    type Err = ParseError;
+
+    /// Parse destructuring using lexer
+    fn parse(context: &mut Context<impl Lexer>) -> Result<Self, Self::Err> {
+        let (keyword, mutability) = parse_let_keyword(context)?;
+
+        if context.lexer.peek() == Some(Token::LParen) {
+            return Destructuring::parse_tuple(context, keyword, mutability);
+        }
+
+        let ty = context.consume_id()?;
+
+        Destructuring::parse_with_ty(context, keyword, mutability, ty)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::VariableReference;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_destructuring() {
+        let d = "let Point { x, y } = p".parse::<Destructuring>().unwrap();
+        assert_eq!(
+            d,
+            Destructuring {
+                keyword: Keyword::<"let">::at(0),
+                ty: Some(TypeReference {
+                    name: Identifier::from("Point").at(4).into(),
+                    generic_parameters: vec![],
+                }),
+                names: vec![Identifier::from("x").at(12), Identifier::from("y").at(15)],
+                initializer: VariableReference {
+                    name: Identifier::from("p").at(21),
+                }
+                .into(),
+                mutability: Mutability::Immutable,
+            }
+        );
+    }
+
+    #[test]
+    fn test_tuple_destructuring() {
+        let d = "let mut (x, y) = p".parse::<Destructuring>().unwrap();
+        assert_eq!(
+            d,
+            Destructuring {
+                keyword: Keyword::<"let">::at(0),
+                ty: None,
+                names: vec![Identifier::from("x").at(9), Identifier::from("y").at(12)],
+                initializer: VariableReference {
+                    name: Identifier::from("p").at(17),
+                }
+                .into(),
+                mutability: Mutability::Mutable,
+            }
+        );
+    }
+}
@@ -0,0 +1,72 @@
+extern crate ast_derive;
+use ast_derive::AST;
+
+use crate::syntax::{error::ParseError, Lexer, Parse, Token};
+use crate::syntax::{Context, Identifier, Keyword, Ranged, StartsHere};
+
+/// AST for export statement.
+/// Lists names, that are visible to modules, importing this one
+///
+/// # Example
+/// ```ppl
+/// export Point, distance from to
+/// ```
+#[derive(Debug, PartialEq, Eq, AST, Clone)]
+pub struct Export {
+    /// Keyword `export`
+    pub keyword: Keyword<"export">,
+    /// Names of exported types, functions and variables.
+    /// Functions are named without parameters, e.g. `distance from to`
+    pub names: Vec<Identifier>,
+}
+
+impl Ranged for Export {
+    fn start(&self) -> usize {
+        self.keyword.start()
+    }
+
+    fn end(&self) -> usize {
+        self.names.last().map_or(self.keyword.end(), |n| n.end())
+    }
+}
+
+impl StartsHere for Export {
+    /// Check that export statement starts at current lexer position
+    fn starts_here(context: &mut Context<impl Lexer>) -> bool {
+        context.lexer.peek() == Some(Token::Export)
+    }
+}
+
+impl Parse for Export {
+    type Err = ParseError;
+
+    /// Parse [`Export`] statement inside parsing context
+    fn parse(context: &mut Context<impl Lexer>) -> Result<Self, Self::Err> {
+        let keyword = context.consume_keyword::<"export">()?;
+
+        let names = context.parse_comma_separated(|context| context.consume_function_name());
+
+        Ok(Export { keyword, names })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_export() {
+        let stmt = "export Point, distance from to".parse::<Export>().unwrap();
+        assert_eq!(
+            stmt,
+            Export {
+                keyword: Keyword::<"export">::at(0),
+                names: vec![
+                    Identifier::from("Point").at(7),
+                    Identifier::from("distance from to").at(14),
+                ],
+            }
+        );
+    }
+}
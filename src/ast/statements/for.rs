@@ -0,0 +1,126 @@
+extern crate ast_derive;
+
+use ast_derive::AST;
+
+use crate::ast::{Expression, Statement};
+use crate::syntax::error::EmptyBlock;
+use crate::syntax::{error::ParseError, Lexer, Parse, Token};
+use crate::syntax::{Context, Identifier, Keyword, Ranged, StartsHere};
+
+/// AST for for-loop over iterable object
+///
+/// # Example
+/// ```ppl
+/// for x in array:
+///     println x
+/// ```
+#[derive(Debug, PartialEq, Eq, AST, Clone)]
+pub struct For {
+    /// Keyword `for`
+    pub keyword: Keyword<"for">,
+    /// Name of loop variable
+    pub name: Identifier,
+    /// Keyword `in`
+    pub in_keyword: Keyword<"in">,
+    /// Object to iterate over
+    pub iterable: Expression,
+    /// Body of loop
+    pub body: Vec<Statement>,
+}
+
+impl Ranged for For {
+    fn start(&self) -> usize {
+        self.keyword.start()
+    }
+
+    fn end(&self) -> usize {
+        self.body
+            .last()
+            .map_or_else(|| self.iterable.end(), |s| s.end())
+    }
+}
+
+impl StartsHere for For {
+    /// Check that loop starts at current lexer position
+    fn starts_here(context: &mut Context<impl Lexer>) -> bool {
+        context.lexer.peek() == Some(Token::For)
+    }
+}
+
+impl Parse for For {
+    type Err = ParseError;
+
+    /// Parse loop using lexer
+    fn parse(context: &mut Context<impl Lexer>) -> Result<Self, Self::Err> {
+        let keyword = context.consume_keyword::<"for">()?;
+
+        let name = context.consume_id()?;
+
+        let in_keyword = context.consume_keyword::<"in">()?;
+
+        let iterable = Expression::parse(context)?;
+
+        let colon = context.lexer.consume(Token::Colon)?;
+
+        let error_range = keyword.start()..colon.start();
+        let body = context.parse_block(Statement::parse, error_range)?;
+
+        if body.is_empty() {
+            return Err(EmptyBlock {
+                at: (keyword.start()..colon.start()).into(),
+            }
+            .into());
+        }
+
+        Ok(For {
+            keyword,
+            name,
+            in_keyword,
+            iterable,
+            body,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::{Call, CallNamePart, FnKind, VariableReference};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_for() {
+        let stmt = "for x in array:\n\tprintln x".parse::<For>().unwrap();
+        assert_eq!(
+            stmt,
+            For {
+                keyword: Keyword::<"for">::at(0),
+                name: Identifier::from("x").at(4),
+                in_keyword: Keyword::<"in">::at(6),
+                iterable: VariableReference {
+                    name: Identifier::from("array").at(9),
+                }
+                .into(),
+                body: vec![Expression::from(Call {
+                    kind: FnKind::Function,
+                    name_parts: vec![
+                        CallNamePart::Text(Identifier::from("println").at(17)),
+                        CallNamePart::Argument(
+                            VariableReference {
+                                name: Identifier::from("x").at(25),
+                            }
+                            .into()
+                        ),
+                    ],
+                })
+                .into()],
+            }
+        );
+    }
+
+    #[test]
+    fn test_for_statement() {
+        let stmt = "for x in set:\n\tprintln x".parse::<Statement>().unwrap();
+        assert!(matches!(stmt, Statement::For(_)));
+    }
+}
@@ -15,9 +15,24 @@ pub use r#loop::*;
 mod r#while;
 pub use r#while::*;
 
+mod r#break;
+pub use r#break::*;
+
+mod r#for;
+pub use r#for::*;
+
 mod r#use;
 pub use r#use::*;
 
+mod export;
+pub use export::*;
+
+mod destructuring;
+pub use destructuring::*;
+
+mod static_assert;
+pub use static_assert::*;
+
 extern crate ast_derive;
 use ast_derive::AST;
 
@@ -40,7 +55,12 @@ pub enum Statement {
     If(If),
     Loop(Loop),
     While(While),
+    Break(Break),
+    For(For),
     Use(Use),
+    Export(Export),
+    Destructuring(Destructuring),
+    StaticAssert(StaticAssert),
 }
 
 impl Ranged for Statement {
@@ -54,7 +74,12 @@ impl Ranged for Statement {
             If(s) => s.range(),
             Loop(s) => s.range(),
             While(s) => s.range(),
+            Break(s) => s.range(),
+            For(s) => s.range(),
             Use(s) => s.range(),
+            Export(s) => s.range(),
+            Destructuring(s) => s.range(),
+            StaticAssert(s) => s.range(),
         }
     }
 }
@@ -63,6 +88,7 @@ impl StartsHere for Statement {
     /// Check that statement may start at current lexer position
     fn starts_here(context: &mut Context<impl Lexer>) -> bool {
         Annotation::starts_here(context)
+            || context.lexer.peek() == Some(Token::Extern)
             || Declaration::starts_here(context)
             || Expression::starts_here(context)
             || Assignment::starts_here(context)
@@ -70,7 +96,11 @@ impl StartsHere for Statement {
             || If::starts_here(context)
             || Loop::starts_here(context)
             || While::starts_here(context)
+            || Break::starts_here(context)
+            || For::starts_here(context)
             || Use::starts_here(context)
+            || Export::starts_here(context)
+            || StaticAssert::starts_here(context)
     }
 }
 
@@ -91,9 +121,20 @@ impl Parse for Statement {
             annotations.push(Annotation::parse(context)?);
             context.lexer.skip_spaces();
         }
+        if context.lexer.peek() == Some(Token::Extern) {
+            annotations.push(Annotation::parse_extern(context)?);
+        }
 
-        let mut res: Statement = if Declaration::starts_here(context) {
+        let mut res: Statement = if context.lexer.peek() == Some(Token::Let) {
+            parse_let(context)?
+        } else if Declaration::starts_here(context) {
             Declaration::parse(context)?.into()
+        } else if Loop::starts_here(context) {
+            // Loop on its own is a statement, even though it may be used as expression
+            Loop::parse(context)?.into()
+        } else if For::starts_here(context) {
+            // `for` may be a part of function name, but not at the start of statement
+            For::parse(context)?.into()
         } else if Expression::starts_here(context) {
             let target = Expression::parse(context)?;
 
@@ -110,9 +151,11 @@ impl Parse for Statement {
             match context.lexer.peek() {
                 Some(Token::Return) => Return::parse(context)?.into(),
                 Some(Token::If) => If::parse(context)?.into(),
-                Some(Token::Loop) => Loop::parse(context)?.into(),
                 Some(Token::While) => While::parse(context)?.into(),
+                Some(Token::Break) => Break::parse(context)?.into(),
                 Some(Token::Use) => Use::parse(context)?.into(),
+                Some(Token::Export) => Export::parse(context)?.into(),
+                Some(Token::Static) => StaticAssert::parse(context)?.into(),
                 t => unreachable!("Unexpected token {:#?} at start of statement", t),
             }
         };
@@ -134,7 +177,10 @@ impl Parse for Statement {
             Statement::Assignment(_)
                 | Statement::Expression(_)
                 | Statement::Return(_)
+                | Statement::Break(_)
                 | Statement::Use(_)
+                | Statement::Export(_)
+                | Statement::StaticAssert(_)
         ) {
             context.consume_eol()?;
         }
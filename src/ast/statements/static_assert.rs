@@ -0,0 +1,50 @@
+extern crate ast_derive;
+use ast_derive::AST;
+
+use crate::ast::Expression;
+use crate::syntax::{error::ParseError, Lexer, Parse, Token};
+use crate::syntax::{Context, Keyword, Ranged, StartsHere};
+
+/// AST for assertion, checked at compile time
+///
+/// # Example
+/// ```ppl
+/// static assert (2 + 2 == 4) "Math is broken"
+/// ```
+#[derive(Debug, PartialEq, Eq, AST, Clone)]
+pub struct StaticAssert {
+    /// Keyword `static`
+    pub keyword: Keyword<"static">,
+    /// Call to assertion function
+    pub assertion: Expression,
+}
+
+impl Ranged for StaticAssert {
+    fn start(&self) -> usize {
+        self.keyword.start()
+    }
+
+    fn end(&self) -> usize {
+        self.assertion.end()
+    }
+}
+
+impl StartsHere for StaticAssert {
+    /// Check that static assertion starts at current lexer position
+    fn starts_here(context: &mut Context<impl Lexer>) -> bool {
+        context.lexer.peek() == Some(Token::Static)
+    }
+}
+
+impl Parse for StaticAssert {
+    type Err = ParseError;
+
+    /// Parse [`StaticAssert`] inside parsing context
+    fn parse(context: &mut Context<impl Lexer>) -> Result<Self, Self::Err> {
+        let keyword = context.consume_keyword::<"static">()?;
+
+        let assertion = Expression::parse(context)?;
+
+        Ok(StaticAssert { keyword, assertion })
+    }
+}
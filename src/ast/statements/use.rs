@@ -50,7 +50,7 @@ impl Parse for Use {
         let path = context.parse_separated(
             |context| {
                 context
-                    .consume_id()
+                    .consume_function_name()
                     .or_else(|_| context.lexer.consume(Token::Star).map(Into::into))
             },
             Token::Dot,
@@ -59,3 +59,24 @@ impl Parse for Use {
         Ok(Use { keyword, path })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_use_function() {
+        let stmt = "use geometry.distance from to".parse::<Use>().unwrap();
+        assert_eq!(
+            stmt,
+            Use {
+                keyword: Keyword::<"use">::at(0),
+                path: vec![
+                    Identifier::from("geometry").at(4),
+                    Identifier::from("distance from to").at(13),
+                ],
+            }
+        );
+    }
+}
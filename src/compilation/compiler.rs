@@ -9,12 +9,13 @@ use crate::{
     ast,
     hir::{ClassData, FunctionData, ModuleData, TraitData},
     semantics::{ModuleContext, ToHIR},
-    SourceFile,
+    syntax::Interner,
+    MemoryReporter, SourceFile,
 };
 use log::trace;
-use miette::{bail, miette};
+use miette::{bail, miette, Diagnostic};
 
-use super::{Package, PackageData};
+use super::{CompilingFile, LintLevel, LintLevels, Package, PackageData, INTERFACE_EXTENSION};
 
 /// Module index inside a Compiler
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
@@ -89,6 +90,20 @@ pub struct Compiler {
     pub root: PathBuf,
     /// Import builtin module
     pub import_builtin: bool,
+    /// Reporter to capture diagnostics of compiled packages
+    pub reporter: Option<MemoryReporter>,
+    /// Warnings, that are not reported yet
+    pub warnings: Vec<miette::Report>,
+    /// Levels of warnings, configured by user
+    pub lints: LintLevels,
+    /// Number of reported warnings with [`LintLevel::Deny`] level
+    pub denied_warnings: usize,
+    /// Directory with interfaces of compiled dependencies
+    pub interfaces: Option<PathBuf>,
+    /// Remove assertions from compiled code
+    pub strip_asserts: bool,
+    /// Interned strings of parsed modules
+    pub interner: Interner,
 }
 
 impl Compiler {
@@ -121,6 +136,13 @@ impl Compiler {
             traits: Default::default(),
             root: Default::default(),
             import_builtin: false,
+            reporter: None,
+            warnings: vec![],
+            lints: Default::default(),
+            denied_warnings: 0,
+            interfaces: None,
+            strip_asserts: false,
+            interner: Default::default(),
         }
     }
 
@@ -132,6 +154,14 @@ impl Compiler {
         }
     }
 
+    /// Return compiler that captures diagnostics into `reporter`
+    pub fn with_reporter(self, reporter: MemoryReporter) -> Self {
+        Self {
+            reporter: Some(reporter),
+            ..self
+        }
+    }
+
     /// Get current package
     pub fn current_package(&self) -> Package {
         self.package_stack
@@ -153,6 +183,39 @@ impl Compiler {
         self.current_module().data(self).source_file()
     }
 
+    /// Add warning for current source file, unless it's allowed.
+    /// Warnings are also captured by reporter, if any
+    pub fn warn(&mut self, warning: impl Diagnostic + Send + Sync + 'static) {
+        let code = warning.code().map(|c| c.to_string()).unwrap_or_default();
+        match self.lints.level(&code) {
+            LintLevel::Allow => return,
+            LintLevel::Warn => {}
+            LintLevel::Deny => self.denied_warnings += 1,
+        }
+
+        let warning = miette::Report::new(warning).with_source_code(self.current_file().clone());
+        if let Some(reporter) = &self.reporter {
+            reporter.report(warning.as_ref());
+        }
+        self.warnings.push(warning);
+    }
+
+    /// Print warnings to stderr.
+    /// Fails, if any of them were denied
+    pub fn report_warnings(&mut self) -> miette::Result<()> {
+        for warning in self.warnings.drain(..) {
+            eprintln!("{warning:?}");
+        }
+
+        if self.denied_warnings > 0 {
+            bail!(
+                "compilation failed due to {} denied warning(s)",
+                self.denied_warnings
+            );
+        }
+        Ok(())
+    }
+
     /// Locate module by name
     ///
     /// # Module search order
@@ -180,7 +243,7 @@ impl Compiler {
         }
 
         trace!(target: "steps", "Parsing `{}`", path.display());
-        let ast = ast::Module::from_file(path)?;
+        let ast = self.interner.scope(|| ast::Module::from_file(path))?;
         self.asts.insert(canonic_path, ast.clone());
         Ok(ast)
     }
@@ -188,10 +251,18 @@ impl Compiler {
     /// Get compiled module from cache or compile it
     ///
     /// # Module search order
-    /// 1. `{root}/src/{name}.ppl`
-    /// 2. `{root}/src/{name}/mod.ppl`
+    /// 1. `{interfaces}/{name}.ppli`, if package has up to date interfaces
+    /// 2. `{root}/src/{name}.ppl`
+    /// 3. `{root}/src/{name}/mod.ppl`
     pub(crate) fn compile(&mut self, name: &str) -> miette::Result<Module> {
         let path = self.locate(name)?;
+        let path = self
+            .package_stack
+            .last()
+            .and_then(|package| package.data(self).interfaces.as_ref())
+            .map(|interfaces| interfaces.join(format!("{name}.{INTERFACE_EXTENSION}")))
+            .filter(|interface| interface.exists())
+            .unwrap_or(path);
         let canonic_path = std::fs::canonicalize(&path).unwrap();
 
         if let Some(index) = self.modules.get_index_of(&canonic_path) {
@@ -213,6 +284,7 @@ impl Compiler {
         self.modules.insert(canonic_path, data.clone());
 
         trace!(target: "steps", "Lowering to hir `{}`", path.display());
+        let _compiling = CompilingFile::new(&path);
         let mut context = ModuleContext::new(ModuleData::new(source_file.clone()), self);
         let hir = ast
             .to_hir(&mut context)
@@ -248,8 +320,22 @@ impl Compiler {
         )
     }
 
-    /// Get compiled package from cache or compile it
+    /// Get compiled package from cache or compile it.
+    /// Errors of top-level package are also captured by reporter, if any
     pub fn compile_package(&mut self, package: &str) -> miette::Result<Package> {
+        let is_top_level = self.package_stack.is_empty();
+        let result = self.compile_package_impl(package);
+        if is_top_level
+            && let Err(err) = &result
+            && let Some(reporter) = &self.reporter
+        {
+            reporter.report(err.as_ref());
+        }
+        result
+    }
+
+    /// Get compiled package from cache or compile it
+    fn compile_package_impl(&mut self, package: &str) -> miette::Result<Package> {
         if let Some(index) = self.packages.get_index_of(package) {
             return Ok(Package::with_index(index));
         }
@@ -260,6 +346,12 @@ impl Compiler {
         let old_root = self.root.clone();
         let root = self.locate_package(&name)?;
         self.root = root.clone();
+        // Dependencies don't need to be compiled from sources again
+        let interfaces = if self.package_stack.is_empty() {
+            None
+        } else {
+            self.up_to_date_interfaces(&name, &root)
+        };
         self.packages.insert(
             name.clone(),
             PackageData {
@@ -267,6 +359,7 @@ impl Compiler {
                 name: name.clone(),
                 modules: Default::default(),
                 dependencies: Default::default(),
+                interfaces,
             },
         );
 
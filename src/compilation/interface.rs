@@ -0,0 +1,567 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use derive_visitor::{DriveMut, VisitorMut};
+use miette::{miette, Diagnostic};
+use thiserror::Error;
+
+use crate::{
+    ast::{self, Declaration, Statement},
+    hir::{
+        Call, ClassOrTrait, Expression, Function, Generic, ModuleData, ParameterOrVariable, Type,
+        Typed, Variable, VariableReference,
+    },
+    named::Named,
+    syntax::Ranged,
+    DataHolder,
+};
+
+use super::{Compiler, Module, Package};
+
+/// Extension of module interface files
+pub const INTERFACE_EXTENSION: &str = "ppli";
+
+/// Reason, why module has no interface
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq)]
+pub enum NoInterface {
+    /// Global variables can't be declared without initialization
+    #[error("module `{module}` exports global variables and has no interface")]
+    #[diagnostic(code(compilation::exported_variables))]
+    ExportedVariables {
+        /// Name of module
+        module: String,
+    },
+    /// Interface is made from source of module
+    #[error("module `{module}` wasn't compiled from source and has no interface")]
+    #[diagnostic(code(compilation::no_source))]
+    NoSource {
+        /// Name of module
+        module: String,
+    },
+    /// Interface would refer to item, that isn't in it
+    #[error("exported `{item}` of module `{module}` uses `{used}`, which isn't exported")]
+    #[diagnostic(
+        code(compilation::uses_not_exported),
+        help("add `{used}` to export list of module `{module}`")
+    )]
+    UsesNotExported {
+        /// Name of module
+        module: String,
+        /// Exported item
+        item: String,
+        /// Not exported item, used by exported one
+        used: String,
+    },
+}
+
+/// Collects items, used in function body
+#[derive(VisitorMut, Default)]
+#[visitor(Expression(enter), Call(enter), VariableReference(enter))]
+struct UsedItems {
+    /// Types of expressions
+    types: Vec<Type>,
+    /// Called functions
+    functions: Vec<Function>,
+    /// Referenced variables
+    variables: Vec<Variable>,
+}
+
+impl UsedItems {
+    fn enter_expression(&mut self, expression: &mut Expression) {
+        self.types.push(expression.ty());
+    }
+
+    fn enter_call(&mut self, call: &mut Call) {
+        self.functions.push(call.function.clone());
+    }
+
+    fn enter_variable_reference(&mut self, reference: &mut VariableReference) {
+        if let ParameterOrVariable::Variable(variable) = &reference.variable {
+            self.variables.push(variable.clone());
+        }
+    }
+}
+
+impl Compiler {
+    /// Compact interface of compiled module.
+    ///
+    /// Interface is a PPL source with `use` statements, exported types and traits,
+    /// and signatures of exported functions.
+    /// Generic functions keep their bodies, as they are instantiated by importers.
+    ///
+    /// There is no interface, if module exports global variables,
+    /// as they can't be declared without initialization,
+    /// or if exported items use items, that aren't exported
+    pub fn interface(&self, module: Module) -> Result<String, NoInterface> {
+        let data = module.data(self);
+        if data
+            .variables
+            .keys()
+            .any(|name| !name.starts_with('$') && data.is_exported(name))
+        {
+            return Err(NoInterface::ExportedVariables {
+                module: data.name().to_string(),
+            });
+        }
+        check_exported_uses(data, module)?;
+
+        let no_source = || NoInterface::NoSource {
+            module: data.name().to_string(),
+        };
+        let path = fs::canonicalize(data.source_file.path()).map_err(|_| no_source())?;
+        let ast = self.asts.get(&path).ok_or_else(no_source)?;
+        let source = data.source_file.contents();
+
+        let mut interface = String::new();
+        for statement in &ast.statements {
+            let item = match statement {
+                Statement::Use(u) => &source[line_start(source, u.start())..u.end()],
+                Statement::Declaration(Declaration::Type(ty))
+                    if data.is_exported(ty.name.as_str()) =>
+                {
+                    let start = ty
+                        .annotations
+                        .first()
+                        .map_or(ty.start(), |a| a.name.start());
+                    item_source(source, start)
+                }
+                Statement::Declaration(Declaration::Trait(tr))
+                    if data.is_exported(tr.name.as_str()) =>
+                {
+                    item_source(source, tr.start())
+                }
+                Statement::Declaration(Declaration::Function(f)) => {
+                    let Some(function) = data.iter_functions().find(|function| {
+                        let function = function.read().unwrap();
+                        function.module == module && function.keyword.start() == f.start()
+                    }) else {
+                        continue;
+                    };
+                    if !data.is_function_exported(function) {
+                        continue;
+                    }
+
+                    let start = f.annotations.first().map_or(f.start(), |a| a.name.start());
+                    let function = function.read().unwrap();
+                    if function.is_generic() && function.is_definition() {
+                        item_source(source, start)
+                    } else {
+                        interface.push_str(signature(source, start, f));
+                        if f.return_type.is_none() {
+                            interface.push_str(&format!(" -> {}", function.return_type.name()));
+                        }
+                        interface.push('\n');
+                        continue;
+                    }
+                }
+                _ => continue,
+            };
+            interface.push_str(item);
+            interface.push('\n');
+        }
+        Ok(interface)
+    }
+
+    /// Write interfaces of package's modules to `{dir}/{package}`.
+    ///
+    /// Nothing is written, if some of the modules has no interface
+    pub fn write_interfaces(&self, package: Package, dir: &Path) -> miette::Result<()> {
+        let data = package.data(self);
+        if data.interfaces.is_some() {
+            return Ok(());
+        }
+
+        let dir = dir.join(&data.name);
+        if dir.exists() {
+            fs::remove_dir_all(&dir).map_err(|e| miette!("Can't remove {dir:?}: {e}"))?;
+        }
+
+        let interfaces: Result<Vec<_>, _> = data
+            .modules
+            .iter()
+            .map(|m| Ok((m.data(self).name().to_string(), self.interface(*m)?)))
+            .collect();
+        let Ok(interfaces) = interfaces else {
+            return Ok(());
+        };
+
+        fs::create_dir_all(&dir).map_err(|e| miette!("Can't create {dir:?}: {e}"))?;
+        for (name, interface) in interfaces {
+            let file = dir.join(format!("{name}.{INTERFACE_EXTENSION}"));
+            fs::write(&file, interface).map_err(|e| miette!("Can't write {file:?}: {e}"))?;
+        }
+        Ok(())
+    }
+
+    /// Directory with interfaces of package,
+    /// if none of package's sources were modified after them
+    pub(crate) fn up_to_date_interfaces(&self, package: &str, root: &Path) -> Option<PathBuf> {
+        let dir = self.interfaces.as_ref()?.join(package);
+        let oldest_interface = modification_times(&dir, INTERFACE_EXTENSION)
+            .into_iter()
+            .min()?;
+        let newest_source = modification_times(&root.join("src"), "ppl")
+            .into_iter()
+            .max()?;
+        (newest_source <= oldest_interface).then_some(dir)
+    }
+}
+
+/// Check that exported items of module use only exported items of it
+fn check_exported_uses(data: &ModuleData, module: Module) -> Result<(), NoInterface> {
+    if data.exports.is_none() {
+        return Ok(());
+    }
+
+    let error = |item: &str, used: String| NoInterface::UsesNotExported {
+        module: data.name().to_string(),
+        item: item.to_string(),
+        used,
+    };
+
+    for (name, ty) in &data.types {
+        if !data.is_exported(name) {
+            continue;
+        }
+        let types: Vec<Type> = match ty {
+            ClassOrTrait::Class(class) => {
+                let class = class.read().unwrap();
+                if class.module != module {
+                    continue;
+                }
+                class.members().iter().map(|m| m.ty()).collect()
+            }
+            ClassOrTrait::Trait(tr) => {
+                let tr = tr.read().unwrap();
+                if tr.module != module {
+                    continue;
+                }
+                tr.functions.values().flat_map(signature_types).collect()
+            }
+        };
+        if let Some(used) = types
+            .iter()
+            .flat_map(|ty| not_exported_types(ty, data, module))
+            .next()
+        {
+            return Err(error(name, used));
+        }
+    }
+
+    for function in data.iter_functions() {
+        if function.read().unwrap().module != module || !data.is_function_exported(function) {
+            continue;
+        }
+        let name = function.read().unwrap().text_name();
+
+        // Bodies of generic functions are in interface too
+        let mut used = UsedItems::default();
+        if function.read().unwrap().is_generic() {
+            let mut body = function.read().unwrap().body.clone();
+            body.drive_mut(&mut used);
+        }
+
+        let types = signature_types(function).into_iter().chain(used.types);
+        if let Some(used) = types
+            .flat_map(|ty| not_exported_types(&ty, data, module))
+            .next()
+        {
+            return Err(error(&name, used));
+        }
+
+        if let Some(used) = used.functions.iter().find(|f| {
+            let declared_here = f.read().is_ok_and(|f| f.module == module && f.tr.is_none());
+            declared_here && !data.is_function_exported(f)
+        }) {
+            return Err(error(&name, used.read().unwrap().text_name()));
+        }
+
+        if let Some(used) = used
+            .variables
+            .iter()
+            .find(|v| data.variables.values().any(|global| global.is_same(v)))
+        {
+            return Err(error(&name, used.name().to_string()));
+        }
+    }
+    Ok(())
+}
+
+/// Types of parameters and return type of function
+fn signature_types(function: &Function) -> Vec<Type> {
+    let function = function.read().unwrap();
+    function
+        .parameters()
+        .map(|p| p.ty())
+        .chain(std::iter::once(function.return_type.clone()))
+        .collect()
+}
+
+/// Names of types and traits of module, mentioned in `ty`, that aren't exported
+fn not_exported_types(ty: &Type, data: &ModuleData, module: Module) -> Vec<String> {
+    let not_exported = |name: &str, declared_in: Module| {
+        (declared_in == module && !data.is_exported(name)).then(|| name.to_string())
+    };
+    match ty {
+        Type::Class(class) => {
+            let class = class.read().unwrap();
+            class
+                .generics()
+                .iter()
+                .flat_map(|ty| not_exported_types(ty, data, module))
+                .chain(not_exported(class.basename.as_str(), class.module))
+                .collect()
+        }
+        Type::Trait(tr) => {
+            let tr = tr.read().unwrap();
+            not_exported(tr.name.as_str(), tr.module)
+                .into_iter()
+                .collect()
+        }
+        Type::SelfType(s) => not_exported_types(&s.associated_trait.clone().into(), data, module),
+        Type::Generic(generic) => generic
+            .constraint
+            .iter()
+            .flat_map(|c| not_exported_types(&c.referenced_type, data, module))
+            .collect(),
+        Type::Function(f) => f
+            .parameters
+            .iter()
+            .chain(std::iter::once(f.return_type.as_ref()))
+            .flat_map(|ty| not_exported_types(ty, data, module))
+            .collect(),
+        Type::Unknown => vec![],
+    }
+}
+
+/// Signature of function declaration, including its annotations
+fn signature<'s>(source: &'s str, start: usize, f: &ast::FunctionDeclaration) -> &'s str {
+    let end = f
+        .return_type
+        .as_ref()
+        .map(|ty| ty.end())
+        .or_else(|| f.name_parts.last().map(|part| part.end()))
+        .unwrap_or(f.keyword.end());
+    &source[line_start(source, start)..end]
+}
+
+/// Source of top-level item, that starts at `offset`.
+/// Item continues while its lines are indented
+fn item_source(source: &str, offset: usize) -> &str {
+    let start = line_start(source, offset);
+    let mut end = line_end(source, offset);
+    let mut next = end + 1;
+    while next < source.len() {
+        let next_end = line_end(source, next);
+        let line = &source[next..next_end];
+        if !line.trim().is_empty() {
+            if !line.starts_with(['\t', ' ']) {
+                break;
+            }
+            end = next_end;
+        }
+        next = next_end + 1;
+    }
+    &source[start..end]
+}
+
+/// Offset of the start of line with `offset`
+fn line_start(source: &str, offset: usize) -> usize {
+    source[..offset].rfind('\n').map_or(0, |i| i + 1)
+}
+
+/// Offset of the end of line with `offset`
+fn line_end(source: &str, offset: usize) -> usize {
+    source[offset..]
+        .find('\n')
+        .map_or(source.len(), |i| offset + i)
+}
+
+/// Modification times of files with `extension` in `dir` and its subdirectories
+fn modification_times(dir: &Path, extension: &str) -> Vec<SystemTime> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+    entries
+        .flatten()
+        .flat_map(|entry| {
+            let path = entry.path();
+            if path.is_dir() {
+                modification_times(&path, extension)
+            } else if path.extension().is_some_and(|e| e == extension) {
+                entry
+                    .metadata()
+                    .and_then(|m| m.modified())
+                    .into_iter()
+                    .collect()
+            } else {
+                vec![]
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use pretty_assertions::assert_eq;
+    use tempdir::TempDir;
+
+    use super::*;
+    use crate::compilation::PackageData;
+
+    /// Create package with `src/lib.ppl` in temporary directory
+    fn package(source: &str) -> TempDir {
+        let dir = TempDir::new("ppl").unwrap();
+        fs::create_dir(dir.path().join("src")).unwrap();
+        fs::write(dir.path().join("src/lib.ppl"), source).unwrap();
+        dir
+    }
+
+    #[test]
+    fn interface() {
+        let dir = package(
+            "type Point:
+	x: Integer
+	y: Integer
+
+type Hidden:
+	x: Integer
+
+fn distance from <a: Point> to <b: Point> => a.x - b.x
+
+fn<T> first of <x: &T> and <y: &T> -> &T:
+	return x
+
+fn helper => 1
+
+export Point, distance from to, first of and",
+        );
+
+        let mut compiler = Compiler::new().at(dir.path());
+        let module = compiler.compile("lib").unwrap();
+        assert_eq!(
+            compiler.interface(module).unwrap(),
+            "type Point:
+	x: Integer
+	y: Integer
+fn distance from <a: Point> to <b: Point> -> Integer
+fn<T> first of <x: &T> and <y: &T> -> &T:
+	return x
+"
+        );
+    }
+
+    #[test]
+    fn interface_uses_not_exported() {
+        let interface = |source: &str| {
+            let dir = package(source);
+            let mut compiler = Compiler::new().at(dir.path());
+            let module = compiler.compile("lib").unwrap();
+            compiler.interface(module)
+        };
+
+        assert_eq!(
+            interface(
+                "type Hidden:
+	x: Integer
+
+fn hidden => Hidden { x: 1 }
+
+export hidden"
+            ),
+            Err(NoInterface::UsesNotExported {
+                module: "lib".to_string(),
+                item: "hidden".to_string(),
+                used: "Hidden".to_string(),
+            })
+        );
+
+        assert_eq!(
+            interface(
+                "fn helper => 1
+
+fn<T> first of <x: &T> and <y: &T> -> &T:
+	helper
+	return x
+
+export first of and"
+            ),
+            Err(NoInterface::UsesNotExported {
+                module: "lib".to_string(),
+                item: "first of and".to_string(),
+                used: "helper".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn module_from_interface() {
+        let dir = package("fn answer => 42");
+        let interfaces = dir.path().join("deps");
+        fs::create_dir_all(interfaces.join("helper")).unwrap();
+        fs::write(
+            interfaces.join("helper").join("lib.ppli"),
+            "fn answer -> Integer\n",
+        )
+        .unwrap();
+
+        let mut compiler = Compiler::new().at(dir.path());
+        compiler.interfaces = Some(interfaces.clone());
+        assert_eq!(
+            compiler.up_to_date_interfaces("helper", dir.path()),
+            Some(interfaces.join("helper"))
+        );
+
+        let package = Package::with_index(compiler.packages.len());
+        compiler.packages.insert(
+            "helper".to_string(),
+            PackageData {
+                name: "helper".to_string(),
+                root: dir.path().into(),
+                modules: vec![],
+                dependencies: Default::default(),
+                interfaces: Some(interfaces.join("helper")),
+            },
+        );
+        compiler.package_stack.push(package);
+
+        let module = compiler.compile("lib").unwrap();
+        let answer = module
+            .data(&compiler)
+            .iter_functions()
+            .find(|f| f.read().unwrap().text_name() == "answer")
+            .unwrap()
+            .read()
+            .unwrap();
+        assert!(!answer.is_definition());
+    }
+
+    #[test]
+    fn outdated_interfaces() {
+        let dir = package("fn answer => 42");
+        let interfaces = dir.path().join("deps");
+        fs::create_dir_all(interfaces.join("helper")).unwrap();
+        fs::write(
+            interfaces.join("helper").join("lib.ppli"),
+            "fn answer -> Integer\n",
+        )
+        .unwrap();
+
+        // Source was modified after interface
+        fs::File::options()
+            .write(true)
+            .open(dir.path().join("src/lib.ppl"))
+            .unwrap()
+            .set_modified(SystemTime::now() + Duration::from_secs(60))
+            .unwrap();
+
+        let mut compiler = Compiler::new().at(dir.path());
+        compiler.interfaces = Some(interfaces);
+        assert_eq!(compiler.up_to_date_interfaces("helper", dir.path()), None);
+    }
+}
@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+
+/// How to treat warnings with specific name
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum LintLevel {
+    /// Don't report warning
+    Allow,
+    /// Report warning
+    Warn,
+    /// Report warning and fail compilation
+    Deny,
+}
+
+/// Levels of warnings, configured by user
+#[derive(Debug, Default, Clone)]
+pub struct LintLevels {
+    /// Levels of warnings by their names
+    levels: HashMap<String, LintLevel>,
+}
+
+impl LintLevels {
+    /// Set level of warning with `name`.
+    /// Name is either full diagnostic code, like `semantics::deprecated`,
+    /// or its last segment, like `deprecated`
+    pub fn set(&mut self, name: impl Into<String>, level: LintLevel) {
+        self.levels.insert(name.into(), level);
+    }
+
+    /// Level of warning with diagnostic `code`
+    pub fn level(&self, code: &str) -> LintLevel {
+        let name = code.rsplit("::").next().unwrap_or(code);
+        self.levels
+            .get(code)
+            .or_else(|| self.levels.get(name))
+            .copied()
+            .unwrap_or(LintLevel::Warn)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn level() {
+        let mut lints = LintLevels::default();
+        lints.set("deprecated", LintLevel::Allow);
+        lints.set("semantics::concatenation_in_loop", LintLevel::Deny);
+
+        assert_eq!(lints.level("semantics::deprecated"), LintLevel::Allow);
+        assert_eq!(
+            lints.level("semantics::concatenation_in_loop"),
+            LintLevel::Deny
+        );
+        assert_eq!(lints.level("semantics::unknown"), LintLevel::Warn);
+
+        assert_eq!(lints.level("semantics::unused_variable"), LintLevel::Warn);
+        lints.set("unused_variable", LintLevel::Allow);
+        assert_eq!(lints.level("semantics::unused_variable"), LintLevel::Allow);
+    }
+}
@@ -1,5 +1,17 @@
 mod compiler;
 pub use compiler::*;
 
+mod interface;
+pub use interface::*;
+
+mod lints;
+pub use lints::*;
+
 mod package;
 pub use package::*;
+
+mod progress;
+pub use progress::*;
+
+mod stats;
+pub use stats::*;
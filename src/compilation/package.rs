@@ -40,4 +40,7 @@ pub struct PackageData {
     pub modules: Vec<Module>,
     /// List of dependencies for this package
     pub dependencies: HashSet<Package>,
+    /// Directory with up to date interfaces of package's modules.
+    /// Modules are loaded from them instead of their sources
+    pub interfaces: Option<PathBuf>,
 }
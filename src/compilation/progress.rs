@@ -0,0 +1,37 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+/// Stack of files being compiled. Innermost file is the last one
+static FILES: Mutex<Vec<PathBuf>> = Mutex::new(vec![]);
+
+/// Get path of the innermost file, that is being compiled right now
+pub fn file_being_compiled() -> Option<PathBuf> {
+    FILES.lock().ok()?.last().cloned()
+}
+
+/// Guard, that marks file as being compiled until dropped.
+/// Used to report location of internal compiler errors
+pub struct CompilingFile {
+    /// Private field to force construction via [`CompilingFile::new`]
+    _private: (),
+}
+
+impl CompilingFile {
+    /// Mark file as being compiled
+    pub fn new(path: &Path) -> Self {
+        if let Ok(mut files) = FILES.lock() {
+            files.push(path.to_path_buf());
+        }
+        Self { _private: () }
+    }
+}
+
+impl Drop for CompilingFile {
+    fn drop(&mut self) {
+        if let Ok(mut files) = FILES.lock() {
+            files.pop();
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use std::fmt::Display;
+
+use derive_visitor::{DriveMut, VisitorMut};
+
+use crate::{
+    hir::{Expression, ModuleData, Statement},
+    named::Named,
+};
+
+use super::{Compiler, Module};
+
+/// Counts nodes of HIR
+#[derive(VisitorMut, Default)]
+#[visitor(Statement(enter), Expression(enter))]
+struct NodeCounter {
+    /// Number of statements
+    statements: usize,
+    /// Number of expressions
+    expressions: usize,
+}
+
+impl NodeCounter {
+    fn enter_statement(&mut self, _: &mut Statement) {
+        self.statements += 1;
+    }
+
+    fn enter_expression(&mut self, _: &mut Expression) {
+        self.expressions += 1;
+    }
+}
+
+/// Statistics of a single compiled module
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleStatistics {
+    /// Name of module
+    pub name: String,
+    /// Number of top-level AST statements
+    pub ast_statements: usize,
+    /// Number of HIR statements, including nested ones
+    pub hir_statements: usize,
+    /// Number of HIR expressions, including nested ones
+    pub hir_expressions: usize,
+    /// Number of functions, visible in module
+    pub functions: usize,
+    /// Number of statements in bodies of functions, defined in module
+    pub function_statements: usize,
+    /// Number of functions, defined in module
+    pub defined_functions: usize,
+    /// Number of monomorphized instances of generic functions
+    pub monomorphizations: usize,
+    /// Number of variables, visible in module
+    pub variables: usize,
+    /// Number of types and traits, visible in module
+    pub types: usize,
+}
+
+impl ModuleStatistics {
+    /// Collect statistics of module
+    fn collect(handle: Module, module: &mut ModuleData, ast_statements: usize) -> Self {
+        let mut counter = NodeCounter::default();
+        module.drive_mut(&mut counter);
+
+        let functions = module.functions.values().flat_map(|fs| fs.values());
+        let bodies: Vec<_> = functions
+            .clone()
+            .map(|f| f.read().unwrap())
+            .filter(|f| f.module == handle && f.is_definition())
+            .map(|f| f.body.len())
+            .collect();
+
+        Self {
+            name: module.name().to_string(),
+            ast_statements,
+            hir_statements: counter.statements,
+            hir_expressions: counter.expressions,
+            functions: functions.count(),
+            function_statements: bodies.iter().sum(),
+            defined_functions: bodies.len(),
+            monomorphizations: module.monomorphized_functions.len(),
+            variables: module.variables.len(),
+            types: module.types.len(),
+        }
+    }
+
+    /// Average number of statements in bodies of defined functions
+    pub fn average_function_size(&self) -> f64 {
+        if self.defined_functions == 0 {
+            return 0.0;
+        }
+        self.function_statements as f64 / self.defined_functions as f64
+    }
+}
+
+/// Statistics of compilation, used to decide what to optimize in compiler
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Statistics {
+    /// Statistics of every compiled module
+    pub modules: Vec<ModuleStatistics>,
+    /// Number of parsed ASTs
+    pub asts: usize,
+    /// Number of functions across all modules
+    pub functions: usize,
+    /// Number of classes across all modules
+    pub classes: usize,
+    /// Number of traits across all modules
+    pub traits: usize,
+}
+
+impl Compiler {
+    /// Collect statistics of modules, compiled so far
+    pub fn statistics(&mut self) -> Statistics {
+        let modules = self
+            .modules
+            .iter_mut()
+            .enumerate()
+            .map(|(index, (path, module))| {
+                let ast_statements = self.asts.get(path).map_or(0, |ast| ast.statements.len());
+                ModuleStatistics::collect(Module::with_index(index), module, ast_statements)
+            })
+            .collect();
+
+        Statistics {
+            modules,
+            asts: self.asts.len(),
+            functions: self.functions.len(),
+            classes: self.classes.len(),
+            traits: self.traits.len(),
+        }
+    }
+}
+
+impl Display for Statistics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{:<24} {:>8} {:>8} {:>8} {:>8} {:>8} {:>8} {:>8} {:>8}",
+            "module", "ast", "stmts", "exprs", "fns", "avg fn", "mono", "vars", "types"
+        )?;
+        for m in &self.modules {
+            writeln!(
+                f,
+                "{:<24} {:>8} {:>8} {:>8} {:>8} {:>8.1} {:>8} {:>8} {:>8}",
+                m.name,
+                m.ast_statements,
+                m.hir_statements,
+                m.hir_expressions,
+                m.functions,
+                m.average_function_size(),
+                m.monomorphizations,
+                m.variables,
+                m.types
+            )?;
+        }
+        writeln!(f)?;
+        writeln!(f, "ASTs:      {}", self.asts)?;
+        writeln!(f, "Functions: {}", self.functions)?;
+        writeln!(f, "Classes:   {}", self.classes)?;
+        write!(f, "Traits:    {}", self.traits)
+    }
+}
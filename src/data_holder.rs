@@ -1,4 +1,4 @@
-use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard, TryLockResult};
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard, TryLockResult, Weak};
 
 /// Trait for structs that hold some data
 pub trait DataHolder {
@@ -8,6 +8,9 @@ pub trait DataHolder {
     /// Create a holder for some data
     fn new(data: Self::Data) -> Self;
 
+    /// Create a holder that shares already allocated data
+    fn from_inner(inner: Arc<RwLock<Self::Data>>) -> Self;
+
     /// Get a reference to the inner data
     fn inner(&self) -> &Arc<RwLock<Self::Data>>;
 
@@ -20,4 +23,146 @@ pub trait DataHolder {
     fn write(&self) -> TryLockResult<RwLockWriteGuard<'_, Self::Data>> {
         self.inner().try_write()
     }
+
+    /// Call `f` with data locked for reading.
+    ///
+    /// Unlike [`DataHolder::read`], guard never leaves this call,
+    /// so it can't be accidentally held while nested code locks the same data.
+    ///
+    /// # Panics
+    /// Panics with the name of the data type, if data is locked for writing
+    #[track_caller]
+    fn map<R>(&self, f: impl FnOnce(&Self::Data) -> R) -> R {
+        let Ok(data) = self.read() else {
+            panic!(
+                "`{}` is already locked for writing",
+                std::any::type_name::<Self::Data>()
+            );
+        };
+        f(&data)
+    }
+
+    /// Call `f` with data locked for writing.
+    ///
+    /// # Panics
+    /// Panics with the name of the data type, if data is already locked
+    #[track_caller]
+    fn map_mut<R>(&self, f: impl FnOnce(&mut Self::Data) -> R) -> R {
+        let Ok(mut data) = self.write() else {
+            panic!("`{}` is already locked", std::any::type_name::<Self::Data>());
+        };
+        f(&mut data)
+    }
+
+    /// Do both holders point to the same data?
+    ///
+    /// Unlike `==`, doesn't lock any of the holders
+    fn is_same(&self, other: &Self) -> bool
+    where
+        Self: Sized,
+    {
+        Arc::ptr_eq(self.inner(), other.inner())
+    }
+
+    /// Create a weak handle to the data, that doesn't keep it alive.
+    /// Use it to break reference cycles
+    fn downgrade(&self) -> WeakHolder<Self>
+    where
+        Self: Sized,
+    {
+        WeakHolder {
+            inner: Arc::downgrade(self.inner()),
+        }
+    }
+}
+
+/// Weak handle to the data of some [`DataHolder`]
+#[derive(Debug)]
+pub struct WeakHolder<H: DataHolder> {
+    inner: Weak<RwLock<H::Data>>,
+}
+
+impl<H: DataHolder> WeakHolder<H> {
+    /// Get holder back, if data is still alive
+    pub fn upgrade(&self) -> Option<H> {
+        self.inner.upgrade().map(H::from_inner)
+    }
+
+    /// Does this handle point to the same data as holder?
+    pub fn is_same(&self, holder: &H) -> bool {
+        std::ptr::eq(self.inner.as_ptr(), Arc::as_ptr(holder.inner()))
+    }
+}
+
+impl<H: DataHolder> Clone for WeakHolder<H> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Holder {
+        inner: Arc<RwLock<i32>>,
+    }
+
+    impl DataHolder for Holder {
+        type Data = i32;
+
+        fn new(data: Self::Data) -> Self {
+            Self::from_inner(Arc::new(RwLock::new(data)))
+        }
+
+        fn from_inner(inner: Arc<RwLock<Self::Data>>) -> Self {
+            Self { inner }
+        }
+
+        fn inner(&self) -> &Arc<RwLock<Self::Data>> {
+            &self.inner
+        }
+    }
+
+    #[test]
+    fn identity() {
+        let a = Holder::new(1);
+        let b = Holder::new(1);
+        assert!(a.is_same(&a.clone()));
+        assert!(!a.is_same(&b));
+
+        // Doesn't lock
+        let _guard = a.write().unwrap();
+        assert!(a.is_same(&a));
+    }
+
+    #[test]
+    fn map() {
+        let a = Holder::new(1);
+        a.map_mut(|x| *x += 1);
+        assert_eq!(a.map(|x| *x), 2);
+        // Nested reads are fine
+        assert_eq!(a.map(|x| *x + a.map(|y| *y)), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "`i32` is already locked")]
+    fn map_mut_while_reading() {
+        let a = Holder::new(1);
+        a.map(|_| a.map_mut(|x| *x += 1));
+    }
+
+    #[test]
+    fn weak() {
+        let a = Holder::new(42);
+        let weak = a.downgrade();
+        assert!(weak.is_same(&a));
+        assert_eq!(*weak.upgrade().unwrap().read().unwrap(), 42);
+
+        drop(a);
+        assert!(weak.upgrade().is_none());
+    }
 }
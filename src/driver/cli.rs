@@ -1,4 +1,4 @@
-use self::commands::{Build, New, Run};
+use self::commands::{Build, Doctor, Explain, New, Run, TestCorpus};
 use clap::{Parser, Subcommand};
 use derive_more::From;
 
@@ -8,6 +8,9 @@ pub struct Args {
     /// Subcommand to execute
     #[command(subcommand)]
     pub command: Option<Command>,
+    /// Optimization level of REPL, from `-O0` (no optimizations) to `-O3`
+    #[arg(short = 'O', value_name = "level", default_value_t = 0, value_parser = clap::value_parser!(u8).range(0..=3))]
+    pub optimization: u8,
 }
 
 /// The subcommands of ppl
@@ -19,6 +22,12 @@ pub enum Command {
     Build(Build),
     /// Build and run package
     Run(Run),
+    /// Compile every PPL file in directory and report results
+    TestCorpus(TestCorpus),
+    /// Check that builtin module matches runtime library
+    Doctor(Doctor),
+    /// Print detailed explanation of a diagnostic
+    Explain(Explain),
 }
 
 pub mod commands {
@@ -45,6 +54,31 @@ pub mod commands {
         /// Output type of compilation
         #[arg(long = "emit", value_name = "output type")]
         pub output_type: Option<OutputType>,
+        /// Record call frames of PPL functions to show backtraces on panics
+        #[arg(long)]
+        pub debug: bool,
+        /// Check that generated code destroys every value exactly once
+        #[arg(long)]
+        pub verify_destructors: bool,
+        /// Print statistics of compiled modules
+        #[arg(long)]
+        pub stats: bool,
+        /// Report warnings with this name, like `deprecated`
+        #[arg(long, value_name = "warning")]
+        pub warn: Vec<String>,
+        /// Don't report warnings with this name
+        #[arg(long, value_name = "warning")]
+        pub allow: Vec<String>,
+        /// Fail compilation on warnings with this name.
+        /// Takes priority over `--warn` and `--allow`
+        #[arg(long, value_name = "warning")]
+        pub deny: Vec<String>,
+        /// Optimization level, from `-O0` (no optimizations) to `-O3`
+        #[arg(short = 'O', value_name = "level", default_value_t = 0, value_parser = clap::value_parser!(u8).range(0..=3))]
+        pub optimization: u8,
+        /// Keep assertions, when building with optimizations
+        #[arg(long)]
+        pub keep_asserts: bool,
     }
 
     impl Default for Build {
@@ -52,13 +86,49 @@ pub mod commands {
             Self {
                 output_dir: PathBuf::from("target"),
                 output_type: None,
+                debug: false,
+                verify_destructors: false,
+                stats: false,
+                warn: vec![],
+                allow: vec![],
+                deny: vec![],
+                optimization: 0,
+                keep_asserts: false,
             }
         }
     }
 
     /// Command to build and run a package
     #[derive(Parser, Debug)]
-    pub struct Run {}
+    pub struct Run {
+        /// Arguments to pass to the program
+        #[arg(
+            value_name = "args",
+            trailing_var_arg = true,
+            allow_hyphen_values = true
+        )]
+        pub args: Vec<String>,
+    }
+
+    /// Command to compile every PPL file in a directory tree
+    #[derive(Parser, Debug)]
+    pub struct TestCorpus {
+        /// Directory with PPL files
+        #[arg(value_name = "dir")]
+        pub dir: PathBuf,
+    }
+
+    /// Command to cross-reference builtin module with runtime library
+    #[derive(Parser, Debug)]
+    pub struct Doctor {}
+
+    /// Command to explain a diagnostic
+    #[derive(Parser, Debug)]
+    pub struct Explain {
+        /// Code of diagnostic, like `semantics::no_member` or just `no_member`
+        #[arg(value_name = "code")]
+        pub code: String,
+    }
 
     pub mod compile {
         use std::str::FromStr;
@@ -69,6 +139,7 @@ pub mod commands {
         #[derive(Debug, PartialEq, Eq, Clone, Copy, ValueEnum)]
         pub enum OutputType {
             HIR,
+            Interface,
             IR,
             Bitcode,
             Object,
@@ -83,6 +154,7 @@ pub mod commands {
             pub fn extension(&self) -> &'static str {
                 match self {
                     Self::HIR => "hir",
+                    Self::Interface => "ppli",
                     Self::IR => "ll",
                     Self::Bitcode => "bc",
                     Self::Object => "o",
@@ -140,10 +212,11 @@ pub mod commands {
             fn from_str(s: &str) -> Result<Self, Self::Err> {
                 match s {
                     "hir" | ".hir" => Ok(Self::HIR),
-                    "ir" | ".ll" => Ok(Self::IR),
-                    "bitcode" | ".bc" => Ok(Self::Bitcode),
-                    "object" | ".o" => Ok(Self::Object),
-                    "assembler" | ".s" => Ok(Self::Assembler),
+                    "interface" | ".ppli" => Ok(Self::Interface),
+                    "ir" | "llvm-ir" | ".ll" => Ok(Self::IR),
+                    "bitcode" | "llvm-bc" | ".bc" => Ok(Self::Bitcode),
+                    "object" | "obj" | ".o" => Ok(Self::Object),
+                    "assembler" | "assembly" | "asm" | ".s" => Ok(Self::Assembler),
                     "executable" | "exe" | "bin" | ".out" => Ok(Self::Executable),
                     "library" | "lib" | "static-library" | ".a" | ".lib" => Ok(Self::StaticLibrary),
                     "dynamic-library" | "dll" | "dylib" | ".so" => Ok(Self::DynamicLibrary),
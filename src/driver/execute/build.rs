@@ -4,15 +4,20 @@ use std::{
 };
 
 use cmd_lib::{run_cmd, run_fun};
+use inkwell::targets::{FileType, TargetMachine};
 use log::{debug, trace};
 use miette::{bail, miette};
 use tempdir::TempDir;
 
 use crate::{
-    compilation::{Compiler, Package},
+    compilation::{Compiler, LintLevel, Package},
     driver::commands::{compile::OutputType, Build},
-    ir::HIRModuleLowering,
+    ir::{
+        host_target_machine, optimization_level, optimize, set_target, verify_destructors,
+        HIRModuleLowering,
+    },
     named::Named,
+    ErrVec,
 };
 
 use super::Execute;
@@ -43,9 +48,28 @@ impl Execute for Build {
         } else {
             Compiler::new()
         };
+        let levels = [
+            (&self.allow, LintLevel::Allow),
+            (&self.warn, LintLevel::Warn),
+            (&self.deny, LintLevel::Deny),
+        ];
+        for (names, level) in levels {
+            for name in names {
+                compiler.lints.set(name, level);
+            }
+        }
+        compiler.interfaces = Some(output_dir.join("deps"));
+        compiler.strip_asserts = self.optimization > 0 && !self.keep_asserts;
         let compiler = &mut compiler;
 
-        let package = compiler.compile_package(package)?;
+        let package = compiler.compile_package(package);
+        let warnings = compiler.report_warnings();
+        let package = package?;
+        warnings?;
+
+        if self.stats {
+            eprintln!("{}", compiler.statistics());
+        }
 
         let output_type = self.output_type.unwrap_or(output_type);
         let dependencies_dir = output_dir.join("deps");
@@ -54,7 +78,15 @@ impl Execute for Build {
         )
         .map_err(|e| miette!("{e}"))?;
 
-        package.emit(compiler, output_dir, output_type, dependencies_dir)
+        package.emit(
+            compiler,
+            output_dir,
+            output_type,
+            dependencies_dir,
+            self.debug,
+            self.verify_destructors,
+            self.optimization,
+        )
     }
 }
 
@@ -65,6 +97,9 @@ trait Emit {
         output_dir: PathBuf,
         output_type: OutputType,
         dependencies_dir: PathBuf,
+        debug: bool,
+        verify_destructors: bool,
+        optimization: u8,
     ) -> miette::Result<PathBuf>;
 }
 
@@ -75,6 +110,9 @@ impl Emit for Package {
         output_dir: PathBuf,
         output_type: OutputType,
         dependencies_dir: PathBuf,
+        debug: bool,
+        verify_destructors: bool,
+        optimization: u8,
     ) -> miette::Result<PathBuf> {
         let name = &self.data(compiler).name;
         let filename = output_type.named(name);
@@ -83,16 +121,33 @@ impl Emit for Package {
             .map_err(|e| miette!("Can't canonicalize output folder: {e}"))?
             .join(&filename);
 
+        // Package was loaded from interfaces, because it's already built
+        if let Some(interfaces) = &self.data(compiler).interfaces {
+            if !output_file.exists() {
+                bail!(
+                    "{} is missing. Remove {} to rebuild it",
+                    output_file.display(),
+                    interfaces.display()
+                );
+            }
+            return Ok(output_file);
+        }
+
         let dependencies = self.data(compiler).dependencies.clone();
         let dependencies: Vec<_> = dependencies
             .iter()
             .map(|package| {
-                package.emit(
+                let library = package.emit(
                     compiler,
                     dependencies_dir.clone(),
                     OutputType::DynamicLibrary,
                     dependencies_dir.clone(),
-                )
+                    debug,
+                    verify_destructors,
+                    optimization,
+                )?;
+                compiler.write_interfaces(*package, &dependencies_dir)?;
+                Ok::<_, miette::Report>(library)
             })
             .try_collect()?;
 
@@ -107,15 +162,55 @@ impl Emit for Package {
             return Ok(output_file);
         }
 
+        if output_type == OutputType::Interface {
+            let modules = self.data(compiler).modules.clone();
+            for m in modules {
+                let interface = compiler.interface(m)?;
+                let interface_file =
+                    output_dir.join(OutputType::Interface.named(&m.data(compiler).name()));
+                fs::write(&interface_file, interface)
+                    .map_err(|e| miette!("Can't write {interface_file:?}: {e}"))?;
+            }
+            return Ok(output_file);
+        }
+
+        // Target machine is only needed to optimize modules and to emit native code
+        let machine = (optimization > 0
+            || matches!(output_type, OutputType::Object | OutputType::Assembler))
+        .then(|| host_target_machine(optimization_level(optimization)))
+        .transpose()?;
+        if matches!(
+            output_type,
+            OutputType::IR | OutputType::Bitcode | OutputType::Object | OutputType::Assembler
+        ) {
+            let modules = self.data(compiler).modules.clone();
+            for m in modules {
+                let llvm = inkwell::context::Context::create();
+                let with_main = false;
+                let ir = m.data(compiler).to_ir(&llvm, with_main, debug, m);
+                debug!(target: "ir", "{}", ir.to_string());
+                if verify_destructors {
+                    check_destructors(&ir)?;
+                }
+                if let Some(machine) = &machine {
+                    optimize(&ir, optimization, machine)?;
+                }
+                let file = output_file.with_file_name(output_type.named(&m.data(compiler).name()));
+                emit_artifact(&ir, output_type, &file, machine.as_ref())?;
+            }
+            return Ok(output_file.with_file_name(output_type.named(&module.data(compiler).name())));
+        }
+
         let with_main = output_type == OutputType::Executable;
 
         let llvm = inkwell::context::Context::create();
-        let ir = module.data(compiler).to_ir(&llvm, with_main, module);
+        let ir = module.data(compiler).to_ir(&llvm, with_main, debug, module);
         debug!(target: "ir", "{}", ir.to_string());
-        if output_type == OutputType::IR {
-            fs::write(&output_file, ir.to_string())
-                .map_err(|e| miette!("Can't write {output_file:?}: {e}"))?;
-            return Ok(output_file);
+        if verify_destructors {
+            check_destructors(&ir)?;
+        }
+        if let Some(machine) = &machine {
+            optimize(&ir, optimization, machine)?;
         }
 
         let temp_dir = TempDir::new("ppl").map_err(|e| miette!("Can't create tmp folder: {e}"))?;
@@ -128,37 +223,32 @@ impl Emit for Package {
             .to_string_lossy()
             .into_owned();
         trace!(target: "steps", "generating bitcode for {} => {}", path, bitcode.display());
-
         ir.write_bitcode_to_path(&bitcode);
-        if output_type == OutputType::Bitcode {
-            fs::copy(&bitcode, &output_file).map_err(|e| {
-                miette!(
-                    "Can't copy {} to {}: {e}",
-                    bitcode.display(),
-                    output_file.display()
-                )
-            })?;
-            return Ok(output_file);
-        }
 
-        let bitcodes: Vec<_> = self.data(compiler)
+        let bitcodes = self.data(compiler)
             .modules
             .iter()
             .filter(|m| **m != module)
-            .map(|m| {
+            .map(|m| -> miette::Result<String> {
                 let compilation_module = m.clone();
                 let m = m.data(compiler);
                 let llvm = inkwell::context::Context::create();
                 let with_main = false;
-                let ir = m.to_ir(&llvm, with_main, compilation_module);
+                let ir = m.to_ir(&llvm, with_main, debug, compilation_module);
+                if verify_destructors {
+                    check_destructors(&ir)?;
+                }
+                if let Some(machine) = &machine {
+                    optimize(&ir, optimization, machine)?;
+                }
                 let filename = m.name().to_string();
                 let bitcode = temp_dir.path().join(filename).with_extension("bc");
                 trace!(target: "steps", "generating bitcode for {} => {}", m.source_file().path().to_string_lossy(), bitcode.display());
                 ir.write_bitcode_to_path(&bitcode);
-                bitcode.to_string_lossy().to_string()
+                Ok(bitcode.to_string_lossy().to_string())
             })
-            .chain(std::iter::once(bitcode.to_string_lossy().to_string()))
-            .collect();
+            .chain(std::iter::once(Ok(bitcode.to_string_lossy().to_string())))
+            .try_collect::<Vec<_>>()?;
 
         let mut clang = std::process::Command::new("clang-18");
 
@@ -172,11 +262,12 @@ impl Emit for Package {
         trace!(target: "steps", "assembling {}", output_file.display());
         let command = match output_type {
             OutputType::HIR => unreachable!("HIR is already written"),
+            OutputType::Interface => unreachable!("Interface is already written"),
             OutputType::IR => unreachable!("IR is already written"),
-            OutputType::Bitcode => unreachable!("IR is already written"),
+            OutputType::Bitcode => unreachable!("Bitcode is already written"),
+            OutputType::Object => unreachable!("Object file is already written"),
+            OutputType::Assembler => unreachable!("Assembly is already written"),
 
-            OutputType::Object => clang.arg("-c"),
-            OutputType::Assembler => clang.arg("-S"),
             OutputType::StaticLibrary => clang.args(&["-c", "-fPIC"]),
             OutputType::DynamicLibrary => {
                 if cfg!(target_os = "macos") {
@@ -191,6 +282,7 @@ impl Emit for Package {
         .args(&bitcodes)
         .args(dependencies)
         .arg("-Wno-override-module")
+        .arg(format!("-O{optimization}"))
         .arg("-g")
         .arg("-fsanitize=address")
         .args(&["-o", output_file.to_str().unwrap()]);
@@ -210,3 +302,43 @@ impl Emit for Package {
         Ok(output_file)
     }
 }
+
+/// Write LLVM module to `file` as artifact of `output_type`
+fn emit_artifact(
+    ir: &inkwell::module::Module,
+    output_type: OutputType,
+    file: &Path,
+    machine: Option<&TargetMachine>,
+) -> miette::Result<()> {
+    trace!(target: "steps", "emitting {}", file.display());
+    let file_type = match output_type {
+        OutputType::IR => {
+            return fs::write(file, ir.to_string())
+                .map_err(|e| miette!("Can't write {file:?}: {e}"));
+        }
+        OutputType::Bitcode => {
+            if !ir.write_bitcode_to_path(file) {
+                bail!("Can't write {file:?}");
+            }
+            return Ok(());
+        }
+        OutputType::Object => FileType::Object,
+        OutputType::Assembler => FileType::Assembly,
+        _ => unreachable!("{output_type:?} is not an LLVM artifact"),
+    };
+    let machine = machine.expect("target machine is required to emit native code");
+    set_target(ir, machine);
+    machine
+        .write_to_file(ir, file_type, file)
+        .map_err(|e| miette!("Can't write {file:?}: {e}"))
+}
+
+/// Check that values in LLVM IR of module are destroyed exactly once
+fn check_destructors(ir: &inkwell::module::Module) -> miette::Result<()> {
+    let errors = verify_destructors(ir);
+    if errors.is_empty() {
+        return Ok(());
+    }
+
+    Err(ErrVec::from(errors).into())
+}
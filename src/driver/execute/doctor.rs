@@ -0,0 +1,213 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use miette::{miette, Diagnostic};
+use thiserror::Error;
+
+use crate::{ast, driver::commands::Doctor, ErrVec};
+
+use super::Execute;
+
+/// Diagnostic for builtin function, that has no symbol in runtime
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq)]
+#[error("`{symbol}` is declared in `{file}`, but isn't exported by runtime")]
+#[diagnostic(code(doctor::missing_runtime_symbol))]
+pub struct MissingRuntimeSymbol {
+    /// Mangled name of function
+    pub symbol: String,
+    /// File with declaration
+    pub file: String,
+}
+
+/// Diagnostic for runtime function, that is documented, but not declared in builtin module
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq)]
+#[error("`{symbol}` is documented in `{file}`, but isn't declared in builtin module")]
+#[diagnostic(code(doctor::undeclared_builtin))]
+pub struct UndeclaredBuiltin {
+    /// Exported symbol
+    pub symbol: String,
+    /// File with runtime function
+    pub file: String,
+}
+
+/// Mismatches between builtin module and runtime
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq)]
+pub enum Mismatch {
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    MissingRuntimeSymbol(#[from] MissingRuntimeSymbol),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    UndeclaredBuiltin(#[from] UndeclaredBuiltin),
+}
+
+/// Symbol with file, where it's mentioned
+struct Symbol {
+    /// Name of symbol
+    name: String,
+    /// File, where symbol is mentioned
+    file: PathBuf,
+}
+
+/// Files with extension in directory
+fn files_with_extension(dir: &Path, extension: &str) -> miette::Result<Vec<PathBuf>> {
+    let entries = fs::read_dir(dir).map_err(|e| miette!("Can't read {}: {e}", dir.display()))?;
+    let mut files = vec![];
+    for entry in entries {
+        let path = entry.map_err(|e| miette!("{e}"))?.path();
+        if path.extension().is_some_and(|ext| ext == extension) {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Mangled names of functions, that are declared without body in builtin module
+fn declared_symbols(dir: &Path) -> miette::Result<Vec<Symbol>> {
+    let mut symbols = vec![];
+    for file in files_with_extension(dir, "ppl")? {
+        let module = ast::Module::from_file(&file)?;
+        for statement in &module.statements {
+            let ast::Statement::Declaration(ast::Declaration::Function(f)) = statement else {
+                continue;
+            };
+            if !f.body.is_empty() {
+                continue;
+            }
+
+            let mangled_name = f
+                .annotations
+                .iter()
+                .filter(|a| a.name.as_str() == "mangle_as")
+                .find_map(|a| match a.args.first() {
+                    Some(ast::Expression::Literal(ast::Literal::String { value, .. })) => {
+                        Some(value.clone())
+                    }
+                    _ => None,
+                });
+            if let Some(name) = mangled_name {
+                symbols.push(Symbol {
+                    name,
+                    file: file.clone(),
+                });
+            }
+        }
+    }
+    Ok(symbols)
+}
+
+/// Runtime functions with `#[no_mangle]` and symbols from their `# PPL` documentation
+fn runtime_symbols(dir: &Path) -> miette::Result<(Vec<Symbol>, Vec<Symbol>)> {
+    let mut exported = vec![];
+    let mut documented = vec![];
+    for file in files_with_extension(dir, "rs")? {
+        let source =
+            fs::read_to_string(&file).map_err(|e| miette!("Can't read {}: {e}", file.display()))?;
+        let lines: Vec<_> = source.lines().map(str::trim).collect();
+        for (i, line) in lines.iter().enumerate() {
+            if let Some(name) = line
+                .strip_prefix("/// @mangle_as(\"")
+                .and_then(|rest| rest.strip_suffix("\")"))
+            {
+                documented.push(Symbol {
+                    name: name.to_string(),
+                    file: file.clone(),
+                });
+            }
+
+            if *line != "#[no_mangle]" {
+                continue;
+            }
+            let name = lines[i + 1..]
+                .iter()
+                .find(|l| !l.starts_with("#["))
+                .and_then(|l| l.split_once("fn "))
+                .and_then(|(_, rest)| {
+                    rest.split(|c: char| !c.is_alphanumeric() && c != '_')
+                        .next()
+                });
+            if let Some(name) = name {
+                exported.push(Symbol {
+                    name: name.to_string(),
+                    file: file.clone(),
+                });
+            }
+        }
+    }
+    Ok((exported, documented))
+}
+
+/// Cross-reference builtin module in `ppl_dir` with runtime sources in `runtime_dir`
+fn check(ppl_dir: &Path, runtime_dir: &Path) -> miette::Result<Vec<Mismatch>> {
+    let declared = declared_symbols(ppl_dir)?;
+    let (exported, documented) = runtime_symbols(runtime_dir)?;
+
+    let file_name = |file: &Path| {
+        file.file_name()
+            .map_or_else(String::new, |name| name.to_string_lossy().to_string())
+    };
+
+    let mut mismatches = vec![];
+    for symbol in &declared {
+        if !exported.iter().any(|s| s.name == symbol.name) {
+            mismatches.push(
+                MissingRuntimeSymbol {
+                    symbol: symbol.name.clone(),
+                    file: file_name(&symbol.file),
+                }
+                .into(),
+            );
+        }
+    }
+    for symbol in &documented {
+        if !declared.iter().any(|s| s.name == symbol.name) {
+            mismatches.push(
+                UndeclaredBuiltin {
+                    symbol: symbol.name.clone(),
+                    file: file_name(&symbol.file),
+                }
+                .into(),
+            );
+        }
+    }
+    Ok(mismatches)
+}
+
+/// Directory with sources of builtin module
+fn ppl_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("ppl/src")
+}
+
+/// Directory with sources of runtime
+fn runtime_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("src/runtime/src")
+}
+
+impl Execute for Doctor {
+    type Output = miette::Result<()>;
+
+    /// Check that builtin module matches runtime
+    fn execute(&self) -> Self::Output {
+        let mismatches = check(&ppl_dir(), &runtime_dir())?;
+        if !mismatches.is_empty() {
+            return Err(ErrVec::from(mismatches).into());
+        }
+
+        println!("Builtin module matches runtime");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_module_matches_runtime() {
+        let mismatches = check(&ppl_dir(), &runtime_dir()).unwrap();
+        assert_eq!(mismatches, vec![]);
+    }
+}
@@ -0,0 +1,526 @@
+use miette::bail;
+
+use crate::driver::commands::Explain;
+
+use super::Execute;
+
+/// Detailed explanations of diagnostics, keyed by their codes
+const EXPLANATIONS: &[(&str, &str)] = &[
+    (
+        "lexer::invalid_token",
+        "Source code contains characters, that don't form any PPL token.
+
+    let x = 1 ` 2
+
+Remove or replace the offending characters.",
+    ),
+    (
+        "lexer::invalid_indentation",
+        "PPL uses tabs for indentation. Spaces at the start of a line are an error.
+
+    fn answer -> Integer:
+        return 42   // indented with spaces
+
+Indent blocks with tabs instead.",
+    ),
+    (
+        "lexer::extra_token",
+        "Parser has read a complete statement, but there are tokens left on the line.
+
+    let x = 1 2
+
+Remove extra tokens or split them into a separate statement.",
+    ),
+    (
+        "lexer::missing_token",
+        "A token, required by grammar, is missing.
+
+    fn answer -> Integer
+        return 42
+
+Here `:` is missing after the function's signature.",
+    ),
+    (
+        "lexer::unexpected_token",
+        "Parser met a token, that can't appear at this position.
+
+    let = 1
+
+Check syntax of the statement around the highlighted token.",
+    ),
+    (
+        "parser::missing_expression",
+        "An expression is expected, but there is none.
+
+    let x =
+
+Provide the missing expression.",
+    ),
+    (
+        "parser::missing_declaration",
+        "Annotations and `extern` must be followed by a declaration.
+
+    @builtin
+
+Put a type or function declaration right after them.",
+    ),
+    (
+        "parser::missing_statement",
+        "A statement is expected, but there is none.",
+    ),
+    (
+        "parser::missing_variable_name",
+        "Variable declaration has no name.
+
+    let = 1
+
+Name the variable: `let x = 1`.",
+    ),
+    (
+        "parser::empty_block",
+        "Blocks must contain at least one statement.
+
+    if x > 0:
+    println x
+
+Indent the block's statements with a tab.",
+    ),
+    (
+        "semantics::undefined_variable",
+        "Variable is used before it's declared or isn't declared at all.
+
+    y = 2
+
+Declare it first: `let mut y = 2`.",
+    ),
+    (
+        "semantics::unknown_type",
+        "Type with this name isn't declared in current module or imported ones.
+
+    let x: Integr = 1
+
+Check spelling of the type or import the module, that declares it.",
+    ),
+    (
+        "semantics::unknown_annotation",
+        "Annotation isn't known to the compiler or has wrong arguments.
+
+    @inlined
+    fn answer => 42
+
+Known annotations are `@mangle_as`, `@builtin`, `@inline`, `@noinline`,
+`@extern`, `@intrinsic` and `@deprecated`.",
+    ),
+    (
+        "semantics::misplaced_annotation",
+        "Annotation may only be used on functions.
+
+    @inline
+    type Point:
+        x: Integer",
+    ),
+    (
+        "semantics::conflicting_annotations",
+        "Annotations specify incompatible things about a function,
+like two different names to link it with.
+
+    @mangle_as(\"foo\")
+    @extern
+    fn foo",
+    ),
+    (
+        "semantics::non_c_abi_type",
+        "Type can't be passed to or returned from `extern` functions,
+because it has no C representation.
+
+    extern fn puts <s: String> -> I32
+
+Use types with C representation, like `I32`, `I64` or `F64`.",
+    ),
+    (
+        "semantics::extern_with_body",
+        "`extern` functions are defined in external libraries, so they can't have a body.
+
+    extern fn abs <x: I32> -> I32:
+        return x",
+    ),
+    (
+        "semantics::unknown_intrinsic",
+        "Name, passed to `@intrinsic`, isn't an LLVM intrinsic.
+
+    @intrinsic(\"llvm.sine.f64\")
+    fn sin <x: F64> -> F64",
+    ),
+    (
+        "semantics::intrinsic_with_body",
+        "Intrinsic functions are implemented by LLVM, so they can't have a body.",
+    ),
+    (
+        "semantics::intrinsic_signature_mismatch",
+        "Parameters and return type of intrinsic function must match the ones LLVM expects.
+
+    @intrinsic(\"llvm.sqrt.f64\")
+    fn sqrt <x: I64> -> F64
+
+Intrinsics only accept `Bool`, fixed-size integers, `F64` and references.",
+    ),
+    (
+        "semantics::not_const_evaluable",
+        "Expression can't be evaluated at compile time.
+
+    static assert (read line == \"\") \"reads input\"
+
+Only pure computations on literals may be checked with `static assert`.",
+    ),
+    (
+        "semantics::not_an_assertion",
+        "Only `assert` calls may be marked as `static`.
+
+    static println 1",
+    ),
+    (
+        "semantics::static_assertion_failed",
+        "Condition of `static assert` evaluated to `false` at compile time.
+
+    static assert (1 + 1 == 3) \"math is broken\"",
+    ),
+    (
+        "semantics::division_by_zero",
+        "Integer is divided by constant zero.
+
+    let x = 1 div 0
+
+Division by zero at runtime panics.",
+    ),
+    (
+        "semantics::assignment_to_immutable",
+        "Variables are immutable by default.
+
+    let x = 1
+    x = 2
+
+Declare it as mutable: `let mut x = 1`.",
+    ),
+    (
+        "semantics::type_mismatch",
+        "Expression has different type from the expected one.
+
+    let x: Integer = \"1\"
+
+Convert the value explicitly, e.g. `Integer from \"1\"`.",
+    ),
+    (
+        "semantics::condition_type_mismatch",
+        "Conditions of `if` and `while` must have `Bool` type.
+
+    if 1:
+        println \"yes\"
+
+Compare explicitly: `if x != 0:`.",
+    ),
+    (
+        "semantics::no_unary_operator",
+        "There is no unary operator with this name for operand's type.
+
+    let x = -\"1\"",
+    ),
+    (
+        "semantics::no_function",
+        "No function matches the call. Either there is no function with this name,
+or none of candidates accept arguments of these types.
+
+    println (1 + \"2\")
+
+Candidates and reasons, why they don't fit, are listed after the error.",
+    ),
+    (
+        "semantics::return_outside_function",
+        "`return` may be used only inside of a function.
+
+    return 1",
+    ),
+    (
+        "semantics::missing_return_value",
+        "Function returns a value, but `return` has none.
+
+    fn answer -> Integer:
+        return",
+    ),
+    (
+        "semantics::return_type_mismatch",
+        "Returned value doesn't match function's return type.
+
+    fn answer -> Integer:
+        return \"42\"",
+    ),
+    (
+        "semantics::break_outside_loop",
+        "`break` may be used only inside of a loop.
+
+    break",
+    ),
+    (
+        "semantics::break_with_value_out_of_while",
+        "Only `loop` expressions produce values. `while` loops can't be broken with a value.
+
+    while true:
+        break 1
+
+Use `loop` instead: `let x = loop: break 1`.",
+    ),
+    (
+        "semantics::break_type_mismatch",
+        "All `break`s of a `loop` expression must produce values of the same type.
+
+    let x = loop:
+        if flag:
+            break 1
+        break \"one\"",
+    ),
+    (
+        "semantics::cant_deduce_return_type",
+        "Return type of a function without `->` is deduced only from single-expression bodies.
+
+    fn answer:
+        return 42
+
+Annotate it explicitly: `fn answer -> Integer:` or use `fn answer => 42`.",
+    ),
+    (
+        "semantics::cant_deduce_type",
+        "Type of expression can't be deduced from context. Annotate it explicitly.",
+    ),
+    (
+        "semantics::no_member",
+        "Type has no member with this name.
+
+    type Point:
+        x: Integer
+
+    let p = Point { x: 1 }
+    println p.y",
+    ),
+    (
+        "semantics::private_member",
+        "Members, which names start with `_`, are private.
+They may be used only in module, where their type is declared.
+
+    // point.ppl
+    type Point:
+        _x: Integer
+
+    // main.ppl
+    use point.*
+
+    let p = Point { _x: 1 }",
+    ),
+    (
+        "semantics::multiple_initialization",
+        "Member is initialized more than once in a constructor.
+
+    Point { x: 1, x: 2 }",
+    ),
+    (
+        "semantics::redeclaration",
+        "Function with the same signature, type or member with the same name
+is declared twice in the same module.
+
+    type Point:
+        x: Integer
+        x: Integer",
+    ),
+    (
+        "semantics::missing_fields",
+        "Constructor doesn't initialize all members of a type.
+
+    type Point:
+        x: Integer
+        y: Integer
+
+    Point { x: 1 }
+
+Initialize every member or provide default values for them.",
+    ),
+    (
+        "semantics::wrong_number_of_names",
+        "Tuple destructuring introduces a variable for every value in order,
+so the number of names must match the number of values.
+
+    type Point:
+        x: Integer
+        y: Integer
+
+    let (x, y, z) = Point { x: 1, y: 2 }",
+    ),
+    (
+        "semantics::non_class_constructor",
+        "Constructors may be used only with types, not traits or generic parameters.
+
+    Printable { }",
+    ),
+    (
+        "semantics::not_implemented",
+        "Type is used where trait is expected, but it doesn't implement all trait's functions.
+
+    fn<T: Printable> show <x: T> => println x
+
+    type Point:
+        x: Integer
+
+    show (Point { x: 1 })
+
+Define missing functions, like `fn String from <p: Point> -> String`.",
+    ),
+    (
+        "semantics::reference_mut_to_immutable",
+        "Mutable reference can't point to immutable data.
+
+    let x = 1
+    let r: &mut Integer = x
+
+Declare the variable as mutable: `let mut x = 1`.",
+    ),
+    (
+        "semantics::unresolved_import",
+        "Imported module or its item doesn't exist.
+
+    use maths.*
+
+Check spelling of module's name and location of its file.",
+    ),
+    (
+        "semantics::export_outside_module",
+        "`export` may be used only at module's top level.",
+    ),
+    (
+        "semantics::local_type_declaration",
+        "Types and traits may be declared only at module's top level, not inside functions.
+
+    fn origin:
+        type Point:
+            x: Integer",
+    ),
+    (
+        "semantics::undefined_export",
+        "Exported name isn't declared in this module.
+
+    export foo",
+    ),
+    (
+        "semantics::deprecated",
+        "Called function is marked with `@deprecated`.
+Its message usually suggests a replacement.
+
+Silence with `--allow deprecated`.",
+    ),
+    (
+        "semantics::concatenation_in_loop",
+        "Each `+` on strings copies them, so concatenation in a loop takes quadratic time.
+
+    let mut s = \"\"
+    while i < n:
+        s += \"x\"
+
+Append to `StringBuilder` and `build` the string once.
+Silence with `--allow concatenation_in_loop`.",
+    ),
+    (
+        "semantics::unused_variable",
+        "Variable is declared, but never used.
+
+    let x = 1
+
+Remove it or prefix its name with `_`.
+Silence with `--allow unused_variable`.",
+    ),
+    (
+        "semantics::unused_function",
+        "Function is defined, but never called and not exported.
+
+Remove it or prefix its name with `_`.
+Silence with `--allow unused_function`.",
+    ),
+    (
+        "compilation::exported_variables",
+        "Interface of a module can't declare global variables without their initializers,
+so modules, that export global variables, have no interface.",
+    ),
+    (
+        "compilation::no_source",
+        "Interface is made from source of a module.
+Modules, loaded from interfaces, have no interface themselves.",
+    ),
+    (
+        "compilation::uses_not_exported",
+        "Interface of a module contains only exported items,
+so exported items may use only exported types, traits and functions of the module.
+Bodies of exported generic functions are in interface too.
+
+    type Hidden:
+        x: Integer
+
+    fn hidden => Hidden { x: 1 }
+
+    export hidden
+
+Export used items or stop using them.",
+    ),
+    (
+        "doctor::missing_runtime_symbol",
+        "Builtin module declares a function with `@mangle_as`,
+but runtime library doesn't export a symbol with this name.",
+    ),
+    (
+        "doctor::undeclared_builtin",
+        "Runtime library documents a function, that isn't declared in builtin module.",
+    ),
+    (
+        "ir::value_not_destroyed",
+        "Internal check of `--verify-destructors`: a variable isn't destroyed
+on some execution paths. This is a bug in the compiler.",
+    ),
+    (
+        "ir::value_destroyed_twice",
+        "Internal check of `--verify-destructors`: a variable may be destroyed twice.
+This is a bug in the compiler.",
+    ),
+];
+
+/// Find explanation by full diagnostic code or by its last segment
+fn explanation(code: &str) -> Option<(&'static str, &'static str)> {
+    EXPLANATIONS
+        .iter()
+        .find(|(c, _)| *c == code || c.rsplit("::").next() == Some(code))
+        .copied()
+}
+
+impl Execute for Explain {
+    type Output = miette::Result<()>;
+
+    /// Print detailed explanation of a diagnostic
+    fn execute(&self) -> Self::Output {
+        let Some((code, text)) = explanation(&self.code) else {
+            bail!("no explanation for `{}`", self.code);
+        };
+        println!("{code}\n\n{text}");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup() {
+        assert_eq!(
+            explanation("semantics::no_member").map(|(c, _)| c),
+            Some("semantics::no_member")
+        );
+        assert_eq!(
+            explanation("no_member").map(|(c, _)| c),
+            Some("semantics::no_member")
+        );
+        assert!(explanation("no_such_code").is_none());
+    }
+}
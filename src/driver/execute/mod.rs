@@ -1,6 +1,9 @@
 mod build;
+mod doctor;
+mod explain;
 mod new;
 mod run;
+mod test_corpus;
 
 use super::Command;
 
@@ -21,6 +24,9 @@ impl Execute for Command {
             Command::New(new) => new.execute(),
             Command::Build(build) => build.execute().map(|_| {}),
             Command::Run(run) => run.execute(),
+            Command::TestCorpus(corpus) => corpus.execute(),
+            Command::Doctor(doctor) => doctor.execute(),
+            Command::Explain(explain) => explain.execute(),
         }
     }
 }
@@ -8,7 +8,10 @@ impl Execute for Run {
     /// Build and run the project
     fn execute(&self) -> Self::Output {
         let exe = Build::default().execute()?;
-        std::process::Command::new(exe).status().unwrap();
+        std::process::Command::new(exe)
+            .args(&self.args)
+            .status()
+            .unwrap();
         Ok(())
     }
 }
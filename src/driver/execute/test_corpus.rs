@@ -0,0 +1,118 @@
+use std::{
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+    process::ExitStatus,
+};
+
+use miette::{bail, miette};
+use tempdir::TempDir;
+
+use crate::driver::{commands::TestCorpus, ICE_EXIT_CODE};
+
+use super::Execute;
+
+/// Result of compiling a single file of corpus
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Outcome {
+    /// File compiled successfully
+    Pass,
+    /// Compiler reported errors
+    Fail,
+    /// Compiler crashed
+    ICE,
+}
+
+impl Outcome {
+    /// Classify exit status of compiler.
+    /// Panics exit with [`ICE_EXIT_CODE`], crashes are terminated by signal
+    fn from_status(status: ExitStatus) -> Self {
+        match status.code() {
+            Some(0) => Outcome::Pass,
+            Some(ICE_EXIT_CODE) | None => Outcome::ICE,
+            Some(_) => Outcome::Fail,
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Pass => write!(f, "PASS"),
+            Outcome::Fail => write!(f, "FAIL"),
+            Outcome::ICE => write!(f, "ICE "),
+        }
+    }
+}
+
+/// Recursively collect `.ppl` files in directory
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) -> miette::Result<()> {
+    let entries = fs::read_dir(dir).map_err(|e| miette!("Can't read {}: {e}", dir.display()))?;
+    for entry in entries {
+        let path = entry.map_err(|e| miette!("{e}"))?.path();
+        if path.is_dir() {
+            collect_files(&path, files)?;
+        } else if path.extension().is_some_and(|ext| ext == "ppl") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Compile file as `main.ppl` of a temporary package
+fn compile(file: &Path) -> miette::Result<Outcome> {
+    const PACKAGE: &str = "corpus";
+
+    let temp_dir = TempDir::new("ppl").map_err(|e| miette!("Can't create tmp folder: {e}"))?;
+    let package = temp_dir.path().join(PACKAGE);
+    let src = package.join("src");
+    fs::create_dir_all(&src).map_err(|e| miette!("Can't create {}: {e}", src.display()))?;
+    fs::copy(file, src.join("main.ppl"))
+        .map_err(|e| miette!("Can't copy {}: {e}", file.display()))?;
+
+    let ppl = std::env::current_exe().map_err(|e| miette!("Can't locate ppl: {e}"))?;
+    let output = std::process::Command::new(ppl)
+        .arg("build")
+        .args([
+            "--output-dir",
+            temp_dir.path().join("target").to_str().unwrap(),
+        ])
+        .args(["--emit", "ir"])
+        .current_dir(&package)
+        .output()
+        .map_err(|e| miette!("Can't run ppl: {e}"))?;
+
+    Ok(Outcome::from_status(output.status))
+}
+
+impl Execute for TestCorpus {
+    type Output = miette::Result<()>;
+
+    /// Compile every PPL file in directory and report results
+    fn execute(&self) -> Self::Output {
+        let mut files = vec![];
+        collect_files(&self.dir, &mut files)?;
+        files.sort();
+
+        let mut outcomes = vec![];
+        for file in &files {
+            let outcome = compile(file)?;
+            println!("{outcome} {}", file.display());
+            outcomes.push(outcome);
+        }
+
+        let count = |outcome| outcomes.iter().filter(|o| **o == outcome).count();
+        let (passed, failed, ices) = (
+            count(Outcome::Pass),
+            count(Outcome::Fail),
+            count(Outcome::ICE),
+        );
+        println!();
+        println!("{passed} passed, {failed} failed, {ices} ICEs");
+
+        if failed + ices > 0 {
+            bail!("{} of {} files didn't compile", failed + ices, files.len());
+        }
+        Ok(())
+    }
+}
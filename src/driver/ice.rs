@@ -0,0 +1,39 @@
+use std::backtrace::{Backtrace, BacktraceStatus};
+
+use crate::compilation::file_being_compiled;
+
+/// Exit code of compiler after internal compiler error
+pub const ICE_EXIT_CODE: i32 = 70;
+
+/// Report panics of compiler as internal compiler errors
+/// and exit with [`ICE_EXIT_CODE`]
+pub fn set_ice_hook() {
+    std::panic::set_hook(Box::new(|info| {
+        let payload = info.payload();
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string());
+
+        eprintln!("error: internal compiler error: {message}");
+        if let Some(location) = info.location() {
+            eprintln!("  --> {location}");
+        }
+        if let Some(file) = file_being_compiled() {
+            eprintln!("note: while compiling {}", file.display());
+        }
+        eprintln!("note: ppl {}", env!("CARGO_PKG_VERSION"));
+        eprintln!("note: this is a bug in the compiler, please report it");
+        eprintln!(
+            "help: to minimize reproduction, remove statements from the file while the error persists"
+        );
+
+        let backtrace = Backtrace::capture();
+        if backtrace.status() == BacktraceStatus::Captured {
+            eprintln!("\n{backtrace}");
+        }
+
+        std::process::exit(ICE_EXIT_CODE);
+    }));
+}
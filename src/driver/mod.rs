@@ -4,5 +4,8 @@ pub use cli::Args;
 mod execute;
 pub use execute::Execute;
 
+mod ice;
+pub use ice::*;
+
 pub use cli::commands;
 pub use cli::Command;
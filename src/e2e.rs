@@ -51,7 +51,11 @@ macro_rules! e2es {
 
 #[cfg(test)]
 pub mod internal {
-    use std::{path::Path, process::ExitStatus};
+    use std::{
+        io::Write,
+        path::Path,
+        process::{ExitStatus, Stdio},
+    };
 
     use cmd_lib::run_cmd;
 
@@ -70,6 +74,15 @@ pub mod internal {
         .unwrap();
     }
 
+    pub fn compile_optimized(temp_dir: &Path, dir: &Path, level: u8) {
+        run_cmd! {
+            cd $dir;
+            $PPL build --output-dir $temp_dir -O $level
+        }
+        .map_err(|e| miette!("{e}"))
+        .unwrap();
+    }
+
     pub fn hir(temp_dir: &Path, _name: &str, dir: &Path) -> Result<String, String> {
         let output = std::process::Command::new(PPL)
             .args(&["build"])
@@ -93,25 +106,70 @@ pub mod internal {
         Ok(std::fs::read_to_string(&hir).expect("failed to read HIR"))
     }
 
-    pub fn ir(temp_dir: &Path, name: &str, dir: &Path) -> String {
+    pub fn emit(temp_dir: &Path, dir: &Path, output_type: &str) {
         run_cmd! {
             cd $dir;
-            $PPL build --output-dir $temp_dir --emit ir
+            $PPL build --output-dir $temp_dir --emit $output_type
         }
         .map_err(|e| miette!("{e}"))
         .unwrap();
+    }
 
-        let ir = temp_dir.join(OutputType::IR.named(name));
+    pub fn ir(temp_dir: &Path, _name: &str, dir: &Path) -> String {
+        emit(temp_dir, dir, "ir");
+
+        let mut ir = temp_dir.join(OutputType::IR.named("main"));
+        if !ir.exists() {
+            ir = temp_dir.join(OutputType::IR.named("lib"));
+        }
 
         std::fs::read_to_string(&ir).expect("failed to read IR")
     }
 
+    pub fn run_package(dir: &Path, args: &[&str]) -> String {
+        let output = std::process::Command::new(PPL)
+            .arg("run")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .map_err(|e| miette!("{e}"))
+            .unwrap();
+
+        let stdout = String::from_utf8(output.stdout).expect("stdout is not utf8");
+        let stderr = String::from_utf8(output.stderr).expect("stderr is not utf8");
+
+        format!("{stdout}{stderr}")
+    }
+
     pub fn run(temp_dir: &Path, name: &str, dir: &Path) -> (String, ExitStatus) {
+        run_with_input(temp_dir, name, dir, "")
+    }
+
+    pub fn run_with_input(
+        temp_dir: &Path,
+        name: &str,
+        dir: &Path,
+        input: &str,
+    ) -> (String, ExitStatus) {
         let exe = temp_dir.join(OutputType::Executable.named(name));
 
-        let output = std::process::Command::new(exe)
+        let mut child = std::process::Command::new(exe)
             .current_dir(&dir)
-            .output()
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| miette!("{e}"))
+            .unwrap();
+        // Dropping stdin closes it, so program sees the end of input
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(input.as_bytes())
+            .unwrap();
+        let output = child
+            .wait_with_output()
             .map_err(|e| miette!("{e}"))
             .unwrap();
 
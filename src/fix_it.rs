@@ -0,0 +1,72 @@
+use std::fmt::Display;
+
+use miette::{Diagnostic, LabeledSpan, Severity, SourceSpan};
+use thiserror::Error;
+
+/// Suggested edit of source code, that fixes a diagnostic.
+///
+/// Diagnostics carry fix-its as related advices,
+/// so they are rendered after the main message and captured by
+/// [`MemoryReporter`](crate::MemoryReporter) for editors
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("{message}")]
+pub struct FixIt {
+    /// Description of the fix, like "insert `:`"
+    pub message: String,
+    /// Span to replace. Empty span means insertion
+    pub at: SourceSpan,
+    /// Text to put instead of the span
+    pub replacement: String,
+}
+
+impl FixIt {
+    /// Code of fix-it diagnostics
+    pub const CODE: &'static str = "fix_it";
+
+    /// Suggest to insert `text` at `offset`
+    pub fn insert(text: impl Into<String>, offset: usize) -> Self {
+        let replacement = text.into();
+        Self {
+            message: format!("insert `{replacement}`"),
+            at: offset.into(),
+            replacement,
+        }
+    }
+
+    /// Suggest to replace `at` span with `text`
+    pub fn replace(at: impl Into<SourceSpan>, text: impl Into<String>) -> Self {
+        let replacement = text.into();
+        Self {
+            message: format!("replace with `{replacement}`"),
+            at: at.into(),
+            replacement,
+        }
+    }
+
+    /// Suggest to replace misspelled name at `at` with `name`
+    pub fn did_you_mean(at: impl Into<SourceSpan>, name: impl Into<String>) -> Self {
+        let replacement = name.into();
+        Self {
+            message: format!("did you mean `{replacement}`?"),
+            at: at.into(),
+            replacement,
+        }
+    }
+}
+
+impl Diagnostic for FixIt {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        Some(Box::new(Self::CODE))
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        Some(Severity::Advice)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        Some(Box::new(std::iter::once(LabeledSpan::new_with_span(
+            Some(self.replacement.clone()),
+            self.at,
+        ))))
+    }
+}
@@ -5,4 +5,14 @@ pub enum Annotation {
     MangleAs(String),
     /// Mark type declaration as builtin
     Builtin,
+    /// Always inline function
+    Inline,
+    /// Never inline function
+    NoInline,
+    /// Warn about calls to function with optional message
+    Deprecated(String),
+    /// Bind function to C symbol with optional name
+    Extern(Option<String>),
+    /// Lower calls to function as calls to LLVM intrinsic
+    Intrinsic(String),
 }
@@ -30,6 +30,10 @@ impl DataHolder for Parameter {
         }
     }
 
+    fn from_inner(inner: Arc<RwLock<Self::Data>>) -> Self {
+        Self { inner }
+    }
+
     fn inner(&self) -> &Arc<RwLock<Self::Data>> {
         &self.inner
     }
@@ -37,7 +41,7 @@ impl DataHolder for Parameter {
 
 impl PartialEq for Parameter {
     fn eq(&self, other: &Self) -> bool {
-        *self.read().unwrap() == *other.read().unwrap()
+        self.is_same(other) || *self.read().unwrap() == *other.read().unwrap()
     }
 }
 
@@ -184,6 +188,10 @@ impl DataHolder for Function {
         }
     }
 
+    fn from_inner(inner: Arc<RwLock<Self::Data>>) -> Self {
+        Self { inner }
+    }
+
     fn inner(&self) -> &Arc<RwLock<Self::Data>> {
         &self.inner
     }
@@ -191,13 +199,13 @@ impl DataHolder for Function {
 
 impl Display for Function {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.read().unwrap().fmt(f)
+        self.map(|data| data.fmt(f))
     }
 }
 
 impl PartialEq for Function {
     fn eq(&self, other: &Self) -> bool {
-        *self.read().unwrap() == *other.read().unwrap()
+        self.is_same(other) || *self.read().unwrap() == *other.read().unwrap()
     }
 }
 
@@ -256,6 +264,13 @@ pub struct FunctionData {
     /// Mangled name to use instead of default
     #[drive(skip)]
     pub(crate) mangled_name: Option<String>,
+    /// Hint for inlining of this function
+    #[drive(skip)]
+    pub inline_hint: Option<InlineHint>,
+    /// Message of `@deprecated` annotation, if function is deprecated.
+    /// Empty, if annotation has no message
+    #[drive(skip)]
+    pub deprecated: Option<String>,
     /// Cached format for name of function
     #[drive(skip)]
     pub(crate) name_format: String,
@@ -323,6 +338,21 @@ impl FunctionData {
         name
     }
 
+    /// Name of function without parameters, e.g. `distance from to`
+    /// for `fn distance from <a: Point> to <b: Point>`.
+    ///
+    /// Dots of properties are omitted, so `fn <self: String>.shout` is named `shout`
+    pub fn text_name(&self) -> String {
+        self.name_parts
+            .iter()
+            .filter_map(|part| match part {
+                FunctionNamePart::Text(t) if t.as_str() != "." => Some(t.as_str()),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
     /// Is this a definition of a function?
     pub fn is_definition(&self) -> bool {
         !self.body.is_empty()
@@ -393,6 +423,18 @@ impl Display for FunctionData {
             writeln!(f, "@mangle_as({name:?})")?;
         }
 
+        match self.inline_hint {
+            Some(InlineHint::Always) => writeln!(f, "@inline")?,
+            Some(InlineHint::Never) => writeln!(f, "@noinline")?,
+            None => {}
+        }
+
+        match self.deprecated.as_deref() {
+            Some("") => writeln!(f, "@deprecated")?,
+            Some(message) => writeln!(f, "@deprecated({message:?})")?,
+            None => {}
+        }
+
         let indent = "\t".repeat(f.width().unwrap_or(0));
         write!(f, "{indent}")?;
 
@@ -430,6 +472,15 @@ impl Display for FunctionData {
     }
 }
 
+/// Hint for inlining of function
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum InlineHint {
+    /// Always inline function (`@inline`)
+    Always,
+    /// Never inline function (`@noinline`)
+    Never,
+}
+
 /// Builder for a function declaration
 pub struct FunctionBuilder {
     /// Module where function is defined
@@ -442,6 +493,10 @@ pub struct FunctionBuilder {
     name_parts: Vec<FunctionNamePart>,
     /// Mangled name of function
     mangled_name: Option<String>,
+    /// Hint for inlining of function
+    inline_hint: Option<InlineHint>,
+    /// Message of `@deprecated` annotation
+    deprecated: Option<String>,
     /// Body of a function
     body: Vec<Statement>,
 }
@@ -455,6 +510,8 @@ impl FunctionBuilder {
             generic_types: Vec::new(),
             name_parts: Vec::new(),
             mangled_name: None,
+            inline_hint: None,
+            deprecated: None,
             body: vec![],
         }
     }
@@ -477,6 +534,18 @@ impl FunctionBuilder {
         self
     }
 
+    /// Set hint for inlining of function
+    pub fn with_inline_hint(mut self, inline_hint: Option<InlineHint>) -> Self {
+        self.inline_hint = inline_hint;
+        self
+    }
+
+    /// Mark function as deprecated with message
+    pub fn with_deprecation(mut self, message: Option<String>) -> Self {
+        self.deprecated = message;
+        self
+    }
+
     /// Set body of function
     pub fn with_body(mut self, body: Vec<Statement>) -> Self {
         self.body = body;
@@ -523,8 +592,41 @@ impl FunctionBuilder {
             name_format,
             name,
             mangled_name: self.mangled_name,
+            inline_hint: self.inline_hint,
+            deprecated: self.deprecated,
             body: self.body,
         }
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use crate::compilation::Compiler;
+    use crate::hir::test::{fun, fun_with_param};
+    use crate::hir::ModuleData;
+    use crate::semantics::{Context, ModuleContext};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn text_name() {
+        let mut compiler = Compiler::new();
+        let context = ModuleContext::new(ModuleData::default(), &mut compiler);
+        let types = context.builtin().types();
+
+        // fn distance from <a: String> to <b: String>
+        let distance = fun("distance")
+            .text("from")
+            .param("a", types.string())
+            .text("to")
+            .param("b", types.string())
+            .returns(types.none());
+        assert_eq!(distance.read().unwrap().text_name(), "distance from to");
+
+        // fn <self: String>.shout
+        let shout = fun_with_param("self", types.string())
+            .text(".")
+            .text("shout")
+            .returns(types.none());
+        assert_eq!(shout.read().unwrap().text_name(), "shout");
+    }
+}
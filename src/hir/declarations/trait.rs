@@ -42,6 +42,10 @@ impl DataHolder for Trait {
         }
     }
 
+    fn from_inner(inner: Arc<RwLock<Self::Data>>) -> Self {
+        Self { inner }
+    }
+
     fn inner(&self) -> &Arc<RwLock<Self::Data>> {
         &self.inner
     }
@@ -49,13 +53,13 @@ impl DataHolder for Trait {
 
 impl Display for Trait {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.read().unwrap().fmt(f)
+        self.map(|data| data.fmt(f))
     }
 }
 
 impl PartialEq for Trait {
     fn eq(&self, other: &Self) -> bool {
-        Arc::ptr_eq(&self.inner, &other.inner)
+        self.is_same(other)
     }
 }
 impl Eq for Trait {}
@@ -122,6 +126,13 @@ impl TraitData {
         functions.into_iter()
     }
 
+    /// Iterate over functions, that must be implemented by types.
+    /// Order of functions matches order of slots in virtual table of trait objects
+    pub fn required_functions(&self) -> impl Iterator<Item = Function> + '_ {
+        self.all_functions()
+            .filter(|f| !f.read().unwrap().is_definition())
+    }
+
     /// Iterate over all functions with `n` name parts
     pub fn functions_with_n_name_parts(&self, n: usize) -> impl Iterator<Item = Function> + '_ {
         self.all_functions()
@@ -10,6 +10,7 @@ use std::{
 use derive_visitor::DriveMut;
 
 use crate::{
+    compilation::Module,
     hir::{Basename, Generic, Type, Typed},
     mutability::Mutable,
     named::Named,
@@ -34,6 +35,10 @@ impl DataHolder for Member {
         }
     }
 
+    fn from_inner(inner: Arc<RwLock<Self::Data>>) -> Self {
+        Self { inner }
+    }
+
     fn inner(&self) -> &Arc<RwLock<Self::Data>> {
         &self.inner
     }
@@ -41,7 +46,7 @@ impl DataHolder for Member {
 
 impl PartialEq for Member {
     fn eq(&self, other: &Self) -> bool {
-        *self.read().unwrap() == *other.read().unwrap()
+        self.is_same(other) || *self.read().unwrap() == *other.read().unwrap()
     }
 }
 
@@ -85,6 +90,14 @@ impl Hash for Member {
     }
 }
 
+impl Member {
+    /// Is this member accessible only in module, where its type is declared?
+    /// Names of private members start with `_`
+    pub fn is_private(&self) -> bool {
+        self.name().starts_with('_')
+    }
+}
+
 /// Member of type
 #[derive(Debug, PartialEq, Eq, Hash, Clone, DriveMut)]
 pub struct MemberData {
@@ -154,8 +167,14 @@ macro_rules! builtin_class {
 
 builtin_class! {
     None,
+    Never,
     Bool,
     I32,
+    I64,
+    U8,
+    U32,
+    U64,
+    Byte,
     F64,
     Integer,
     Rational,
@@ -169,13 +188,29 @@ impl BuiltinClass {
     pub fn size_in_bytes(&self) -> usize {
         use BuiltinClass::*;
         match self {
-            None => 0,
-            Bool => 1,
-            I32 => 4,
-            F64 => 8,
+            None | Never => 0,
+            Bool | U8 | Byte => 1,
+            I32 | U32 => 4,
+            I64 | U64 | F64 => 8,
             Integer | Rational | String | Reference | ReferenceMut => POINTER_SIZE,
         }
     }
+
+    /// Get bit width for fixed-width integer types (`I32`, `U8`, etc)
+    pub fn integer_bits(&self) -> Option<u32> {
+        use BuiltinClass::*;
+        match self {
+            U8 | Byte => Some(8),
+            I32 | U32 => Some(32),
+            I64 | U64 => Some(64),
+            _ => Option::None,
+        }
+    }
+
+    /// Is this a signed fixed-width integer type?
+    pub fn is_signed_integer(&self) -> bool {
+        matches!(self, BuiltinClass::I32 | BuiltinClass::I64)
+    }
 }
 
 /// Class data holder
@@ -193,6 +228,10 @@ impl DataHolder for Class {
         }
     }
 
+    fn from_inner(inner: Arc<RwLock<Self::Data>>) -> Self {
+        Self { inner }
+    }
+
     fn inner(&self) -> &Arc<RwLock<Self::Data>> {
         &self.inner
     }
@@ -209,6 +248,11 @@ impl Class {
         self.read().unwrap().is_none()
     }
 
+    /// Is this a builtin "Never" type?
+    pub fn is_never(&self) -> bool {
+        self.read().unwrap().is_never()
+    }
+
     /// Is this a builtin "Bool" type?
     pub fn is_bool(&self) -> bool {
         self.read().unwrap().is_bool()
@@ -219,6 +263,11 @@ impl Class {
         self.read().unwrap().is_i32()
     }
 
+    /// Is this a builtin fixed-width integer type?
+    pub fn is_fixed_width_integer(&self) -> bool {
+        self.read().unwrap().is_fixed_width_integer()
+    }
+
     /// Is this a builtin "Integer" type?
     pub fn is_integer(&self) -> bool {
         self.read().unwrap().is_integer()
@@ -252,7 +301,7 @@ impl Class {
 
 impl PartialEq for Class {
     fn eq(&self, other: &Self) -> bool {
-        *self.read().unwrap() == *other.read().unwrap()
+        self.is_same(other) || *self.read().unwrap() == *other.read().unwrap()
     }
 }
 
@@ -266,7 +315,7 @@ impl Hash for Class {
 
 impl Display for Class {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.read().unwrap().fmt(f)
+        self.map(|data| data.fmt(f))
     }
 }
 
@@ -331,6 +380,9 @@ pub struct ClassData {
     pub builtin: Option<BuiltinClass>,
     /// Members of type
     pub members: Vec<Member>,
+    /// Module this type is declared in
+    #[drive(skip)]
+    pub module: Module,
 }
 
 impl ClassData {
@@ -354,6 +406,11 @@ impl ClassData {
         self.builtin == Some(BuiltinClass::None)
     }
 
+    /// Is this a builtin "Never" type?
+    pub fn is_never(&self) -> bool {
+        self.builtin == Some(BuiltinClass::Never)
+    }
+
     /// Is this a builtin "Bool" type?
     pub fn is_bool(&self) -> bool {
         self.builtin == Some(BuiltinClass::Bool)
@@ -364,7 +421,39 @@ impl ClassData {
         self.builtin == Some(BuiltinClass::I32)
     }
 
-    /// Is this a builtin `I32` type?
+    /// Get bit width, if this is a builtin fixed-width integer type
+    pub fn integer_bits(&self) -> Option<u32> {
+        self.builtin.as_ref().and_then(BuiltinClass::integer_bits)
+    }
+
+    /// Is this a builtin fixed-width integer type (`I32`, `U8`, etc)?
+    pub fn is_fixed_width_integer(&self) -> bool {
+        self.integer_bits().is_some()
+    }
+
+    /// Is this a builtin signed fixed-width integer type?
+    pub fn is_signed_integer(&self) -> bool {
+        self.builtin
+            .as_ref()
+            .is_some_and(BuiltinClass::is_signed_integer)
+    }
+
+    /// Can this integer be represented by this fixed-width integer type?
+    ///
+    /// Always `false` for non fixed-width types
+    pub fn fits_integer(&self, value: &rug::Integer) -> bool {
+        let Some(bits) = self.integer_bits() else {
+            return false;
+        };
+
+        if self.is_signed_integer() {
+            value.signed_bits() <= bits
+        } else {
+            *value >= 0 && value.significant_bits() <= bits
+        }
+    }
+
+    /// Is this a builtin `F64` type?
     pub fn is_f64(&self) -> bool {
         self.builtin == Some(BuiltinClass::F64)
     }
@@ -518,6 +607,7 @@ mod tests {
                 generic_parameters: vec![],
                 builtin: None,
                 members: vec![],
+                module: Module::with_index(0),
             }
         );
     }
@@ -552,6 +642,7 @@ mod tests {
                     }
                     .into(),
                 }),],
+                module: Module::with_index(0),
             }
         );
     }
@@ -586,7 +677,37 @@ mod tests {
                         ty: integer,
                     }),
                 ],
+                module: Module::with_index(0),
             }
         );
     }
+
+    #[test]
+    fn fits_integer() {
+        use rug::Integer as Int;
+
+        let class = |builtin: BuiltinClass| {
+            crate::hir::test::class(&format!("{builtin:?}"))
+                .builtin(builtin)
+                .data()
+        };
+
+        let byte = class(BuiltinClass::U8);
+        assert!(byte.fits_integer(&Int::from(0)));
+        assert!(byte.fits_integer(&Int::from(255)));
+        assert!(!byte.fits_integer(&Int::from(256)));
+        assert!(!byte.fits_integer(&Int::from(-1)));
+        assert!(class(BuiltinClass::Byte).fits_integer(&Int::from(255)));
+
+        let int32 = class(BuiltinClass::I32);
+        assert!(int32.fits_integer(&Int::from(i32::MAX)));
+        assert!(int32.fits_integer(&Int::from(i32::MIN)));
+        assert!(!int32.fits_integer(&Int::from(i32::MAX as i64 + 1)));
+
+        let uint64 = class(BuiltinClass::U64);
+        assert!(uint64.fits_integer(&Int::from(u64::MAX)));
+        assert!(!uint64.fits_integer(&(Int::from(u64::MAX) + 1)));
+
+        assert!(!class(BuiltinClass::Integer).fits_integer(&Int::from(0)));
+    }
 }
@@ -9,7 +9,7 @@ use crate::mutability::{Mutability, Mutable};
 use crate::named::Named;
 use crate::syntax::{Identifier, Keyword, Ranged};
 
-use crate::DataHolder;
+use crate::{DataHolder, SourceFile};
 
 /// Variable data holder
 #[derive(Debug, Clone)]
@@ -26,6 +26,10 @@ impl DataHolder for Variable {
         }
     }
 
+    fn from_inner(inner: Arc<RwLock<Self::Data>>) -> Self {
+        Self { inner }
+    }
+
     fn inner(&self) -> &Arc<RwLock<Self::Data>> {
         &self.inner
     }
@@ -40,13 +44,13 @@ impl Variable {
 
 impl Display for Variable {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.read().unwrap().fmt(f)
+        self.map(|data| data.fmt(f))
     }
 }
 
 impl PartialEq for Variable {
     fn eq(&self, other: &Self) -> bool {
-        *self.read().unwrap() == *other.read().unwrap()
+        self.is_same(other) || *self.read().unwrap() == *other.read().unwrap()
     }
 }
 
@@ -102,6 +106,10 @@ pub struct VariableData {
     /// Variable's name
     #[drive(skip)]
     pub name: Identifier,
+    /// Source file, where variable is declared.
+    /// `None` for temporaries, introduced by compiler
+    #[drive(skip)]
+    pub source_file: Option<SourceFile>,
     /// Type reference for variable
     #[drive(skip)]
     pub type_reference: Option<TypeReference>,
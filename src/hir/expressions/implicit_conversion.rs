@@ -2,7 +2,7 @@ use std::fmt::Display;
 
 use derive_visitor::DriveMut;
 
-use crate::hir::{Generic, Type, Typed};
+use crate::hir::{Function, Generic, Type, Typed};
 use crate::mutability::Mutable;
 use crate::syntax::Ranged;
 
@@ -17,6 +17,22 @@ pub enum ImplicitConversionKind {
     Dereference,
     /// Copy or clone a value
     Copy,
+    /// Erase static type of value, converting it to trait object
+    TraitObject(VirtualFunctions),
+    /// Use diverging expression of `Never` type as a value of another type
+    Never,
+}
+
+/// Functions of erased type, that are called through virtual table of trait object
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct VirtualFunctions {
+    /// Destructor of erased value, if it has one
+    pub destructor: Option<Function>,
+    /// Clone function of erased value, if it has one
+    pub clone: Option<Function>,
+    /// Implementations of trait functions in order of
+    /// [`required_functions`](crate::hir::TraitData::required_functions)
+    pub required: Vec<Function>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, DriveMut)]
@@ -38,6 +54,8 @@ impl Display for ImplicitConversion {
             Reference => "&",
             Dereference => "*",
             Copy => "copy ",
+            TraitObject(_) => "dyn ",
+            Never => "never ",
         };
         write!(
             f,
@@ -28,7 +28,7 @@ use crate::{
 
 use derive_more::Display;
 
-use super::Generic;
+use super::{Generic, Loop};
 
 /// Any PPL expression
 #[enum_dispatch(Ranged, Mutable, Typed)]
@@ -41,6 +41,7 @@ pub enum Expression {
     MemberReference(MemberReference),
     Constructor(Constructor),
     ImplicitConversion(ImplicitConversion),
+    Loop(Loop),
 }
 
 impl Expression {
@@ -65,6 +66,7 @@ impl Generic for Expression {
             Expression::MemberReference(m) => m.is_generic(),
             Expression::Constructor(c) => c.is_generic(),
             Expression::ImplicitConversion(i) => i.is_generic(),
+            Expression::Loop(l) => l.is_generic(),
         }
     }
 }
@@ -21,3 +21,6 @@ pub use specialize::*;
 
 mod generic;
 pub use generic::*;
+
+#[cfg(test)]
+pub mod test;
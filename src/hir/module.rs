@@ -80,6 +80,11 @@ pub struct ModuleData {
 
     /// Statements in this module
     pub statements: Vec<Statement>,
+
+    /// Names, listed in `export` statements of this module.
+    /// Everything is exported, if there are no such statements
+    #[drive(skip)]
+    pub exports: Option<Vec<Name>>,
 }
 
 impl Display for ModuleData {
@@ -113,6 +118,7 @@ impl ModuleData {
             functions: IndexMap::new(),
             monomorphized_functions: vec![],
             statements: vec![],
+            exports: None,
         }
     }
 
@@ -149,6 +155,23 @@ impl ModuleData {
         self.functions.values_mut().flat_map(|m| m.values_mut())
     }
 
+    /// Is item with this name visible to modules, that import this one?
+    pub fn is_exported(&self, name: &str) -> bool {
+        self.exports
+            .as_ref()
+            .is_none_or(|exports| exports.iter().any(|e| e == name))
+    }
+
+    /// Is function visible to modules, that import this one?
+    ///
+    /// Function is exported, if its [`text_name`](crate::hir::FunctionData::text_name) is exported
+    pub fn is_function_exported(&self, function: &Function) -> bool {
+        self.exports.as_ref().is_none_or(|exports| {
+            let name = function.read().unwrap().text_name();
+            exports.iter().any(|e| *e == name)
+        })
+    }
+
     /// Iterate over all functions with `n` name parts
     pub fn functions_with_n_name_parts(&self, n: usize) -> impl Iterator<Item = &Function> + '_ {
         self.iter_functions()
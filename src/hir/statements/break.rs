@@ -0,0 +1,41 @@
+use std::fmt::Display;
+
+use derive_visitor::DriveMut;
+
+use crate::{
+    hir::Expression,
+    syntax::{Keyword, Ranged},
+};
+
+/// Break statement
+#[derive(Debug, PartialEq, Eq, Clone, DriveMut)]
+pub struct Break {
+    /// Keyword `break`
+    #[drive(skip)]
+    pub keyword: Keyword<"break">,
+    /// Value, passed out of loop
+    pub value: Option<Expression>,
+}
+
+impl Display for Break {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let indent = "\t".repeat(f.width().unwrap_or(0));
+        write!(f, "{indent}")?;
+
+        if let Some(value) = &self.value {
+            write!(f, "break {}", value)
+        } else {
+            write!(f, "break")
+        }
+    }
+}
+
+impl Ranged for Break {
+    fn start(&self) -> usize {
+        self.keyword.start()
+    }
+
+    fn end(&self) -> usize {
+        self.value.as_ref().map_or(self.keyword.end(), |v| v.end())
+    }
+}
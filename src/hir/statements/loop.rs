@@ -3,7 +3,8 @@ use std::fmt::Display;
 use derive_visitor::DriveMut;
 
 use crate::{
-    hir::Statement,
+    hir::{Generic, Statement, Type, Typed},
+    mutability::Mutable,
     syntax::{Keyword, Ranged},
 };
 
@@ -15,6 +16,9 @@ pub struct Loop {
     pub keyword: Keyword<"loop">,
     /// Body of a loop
     pub body: Vec<Statement>,
+    /// Type of values, passed out of loop by `break`
+    #[drive(skip)]
+    pub ty: Type,
 }
 
 impl Display for Loop {
@@ -42,3 +46,21 @@ impl Ranged for Loop {
         self.body.last().map_or(self.keyword.end(), |s| s.end())
     }
 }
+
+impl Typed for Loop {
+    fn ty(&self) -> Type {
+        self.ty.clone()
+    }
+}
+
+impl Mutable for Loop {
+    fn is_mutable(&self) -> bool {
+        false
+    }
+}
+
+impl Generic for Loop {
+    fn is_generic(&self) -> bool {
+        self.ty.is_generic()
+    }
+}
@@ -16,6 +16,9 @@ pub use r#loop::*;
 mod r#while;
 pub use r#while::*;
 
+mod r#break;
+pub use r#break::*;
+
 mod r#use;
 pub use r#use::*;
 
@@ -62,6 +65,7 @@ pub enum Statement {
     If(If),
     Loop(Loop),
     While(While),
+    Break(Break),
     Use(Use),
     Block(Block),
 }
@@ -86,6 +90,7 @@ impl Ranged for Statement {
             Statement::If(r#if) => r#if.range(),
             Statement::Loop(r#loop) => r#loop.range(),
             Statement::While(r#while) => r#while.range(),
+            Statement::Break(r#break) => r#break.range(),
             Statement::Use(r#use) => r#use.range(),
             Statement::Block(block) => block.range(),
         }
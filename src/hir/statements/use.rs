@@ -4,6 +4,7 @@ use derive_more::From;
 use derive_visitor::DriveMut;
 
 use crate::{
+    compilation::Module,
     hir::{ClassOrTrait, Function, Variable},
     syntax::{Identifier, Keyword, Ranged},
 };
@@ -13,6 +14,7 @@ use crate::{
 pub enum ImportedItem {
     ClassOrTrait(ClassOrTrait),
     Function(Function),
+    Functions(Vec<Function>),
     Variable(Variable),
     All,
 }
@@ -29,6 +31,9 @@ pub struct Use {
     /// Item, imported by use statement
     #[drive(skip)]
     pub imported_item: ImportedItem,
+    /// Module, item is imported from
+    #[drive(skip)]
+    pub module: Module,
 }
 
 impl Display for Use {
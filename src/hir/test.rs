@@ -0,0 +1,358 @@
+//! Helpers to construct HIR in unit tests.
+//!
+//! Offsets of declarations are computed as if they were written on a single line,
+//! so tests don't have to spell them out by hand.
+//!
+//! # Example
+//! ```ignore
+//! let types = context.builtin().types();
+//! let print = fun("print").param("x", types.integer()).returns(types.none());
+//! ```
+
+use indexmap::IndexMap;
+
+use crate::compilation::Module;
+use crate::hir::{
+    BuiltinClass, Call, Class, ClassData, Expression, Function, FunctionData, FunctionNamePart,
+    Literal, Member, MemberData, Parameter, ParameterData, Statement, Trait, TraitData, Type,
+    TypeReference, Typed, Variable, VariableData, VariableReference,
+};
+use crate::mutability::Mutability;
+use crate::named::Named;
+use crate::syntax::{Identifier, Keyword, Ranged};
+use crate::DataHolder;
+
+/// Start building function, which name starts with `text`
+pub fn fun(text: &str) -> FunctionTestBuilder {
+    FunctionTestBuilder::new().text(text)
+}
+
+/// Start building function, which name starts with parameter (e.g. binary operator)
+pub fn fun_with_param(name: &str, ty: Type) -> FunctionTestBuilder {
+    FunctionTestBuilder::new().param(name, ty)
+}
+
+/// Builder for functions in tests
+pub struct FunctionTestBuilder {
+    /// Module where function is defined
+    module: Module,
+    /// Generic parameters of a function
+    generic_types: Vec<Type>,
+    /// Name parts of a function
+    name_parts: Vec<FunctionNamePart>,
+    /// Mangled name of function
+    mangled_name: Option<String>,
+    /// Body of a function
+    body: Vec<Statement>,
+    /// Offset of next name part
+    offset: usize,
+}
+
+impl FunctionTestBuilder {
+    /// Create builder for function without name
+    fn new() -> Self {
+        Self {
+            module: Module::with_index(0),
+            generic_types: vec![],
+            name_parts: vec![],
+            mangled_name: None,
+            body: vec![],
+            offset: "fn ".len(),
+        }
+    }
+
+    /// Append text part to function's name
+    pub fn text(mut self, text: &str) -> Self {
+        self.name_parts
+            .push(Identifier::from(text).at(self.offset).into());
+        self.offset += text.len() + 1;
+        self
+    }
+
+    /// Append parameter to function's name
+    ///
+    /// `type_for_type` of parameter's type reference is left unknown,
+    /// as it requires builtin module
+    pub fn param(mut self, name: &str, ty: Type) -> Self {
+        let less = self.offset;
+        let name_range = less + 1..less + 1 + name.len();
+        let ty_start = name_range.end + ": ".len();
+        let ty_end = ty_start + ty.name().len();
+        self.name_parts.push(
+            Parameter::new(ParameterData {
+                name: name.to_string(),
+                name_range,
+                ty: TypeReference {
+                    span: ty_start..ty_end,
+                    referenced_type: ty,
+                    type_for_type: Type::Unknown,
+                },
+                range: less..ty_end + 1,
+            })
+            .into(),
+        );
+        self.offset = ty_end + "> ".len();
+        self
+    }
+
+    /// Add generic parameter to function
+    pub fn generic(mut self, ty: Type) -> Self {
+        self.generic_types.push(ty);
+        self
+    }
+
+    /// Set mangled name of function
+    pub fn mangled_as(mut self, name: &str) -> Self {
+        self.mangled_name = Some(name.to_string());
+        self
+    }
+
+    /// Set body of function
+    pub fn body(mut self, body: impl IntoIterator<Item = Statement>) -> Self {
+        self.body = body.into_iter().collect();
+        self
+    }
+
+    /// Set module, where function is defined
+    pub fn module(mut self, module: Module) -> Self {
+        self.module = module;
+        self
+    }
+
+    /// Set return type and build function
+    pub fn returns(self, return_type: Type) -> Function {
+        Function::new(
+            FunctionData::build(self.module, Keyword::<"fn">::at(0))
+                .with_generic_types(self.generic_types)
+                .with_name(self.name_parts)
+                .with_mangled_name(self.mangled_name)
+                .with_body(self.body)
+                .with_return_type(return_type),
+        )
+    }
+}
+
+/// Start building class with specified name
+pub fn class(name: &str) -> ClassTestBuilder {
+    ClassTestBuilder {
+        name: name.to_string(),
+        builtin: None,
+        generic_parameters: vec![],
+        members: vec![],
+    }
+}
+
+/// Builder for classes in tests
+pub struct ClassTestBuilder {
+    /// Name of class
+    name: String,
+    /// Kind of a builtin type, if it is a builtin class
+    builtin: Option<BuiltinClass>,
+    /// Generic parameters of type
+    generic_parameters: Vec<Type>,
+    /// Names and types of members
+    members: Vec<(String, Type)>,
+}
+
+impl ClassTestBuilder {
+    /// Mark class as builtin
+    pub fn builtin(mut self, builtin: BuiltinClass) -> Self {
+        self.builtin = Some(builtin);
+        self
+    }
+
+    /// Add generic parameter to class
+    pub fn generic(mut self, ty: Type) -> Self {
+        self.generic_parameters.push(ty);
+        self
+    }
+
+    /// Add member to class
+    pub fn member(mut self, name: &str, ty: Type) -> Self {
+        self.members.push((name.to_string(), ty));
+        self
+    }
+
+    /// Build class data
+    pub fn data(self) -> ClassData {
+        let basename = Identifier::from(self.name.as_str()).at("type ".len());
+        // Members are placed on the same line: `type Name: x: X, y: Y`
+        let mut offset = basename.range().end + ": ".len();
+        let members = self
+            .members
+            .into_iter()
+            .map(|(name, ty)| {
+                let member = Member::new(MemberData {
+                    name: Identifier::from(name.as_str()).at(offset),
+                    ty: ty.clone(),
+                });
+                offset += name.len() + ": ".len() + ty.name().len() + ", ".len();
+                member
+            })
+            .collect();
+        ClassData {
+            keyword: Keyword::<"type">::at(0),
+            basename,
+            specialization_of: None,
+            generic_parameters: self.generic_parameters,
+            builtin: self.builtin,
+            members,
+            module: Module::with_index(0),
+        }
+    }
+
+    /// Build class
+    pub fn build(self) -> Class {
+        Class::new(self.data())
+    }
+}
+
+/// Build trait with specified name.
+/// Functions of trait are built from its `Self` type
+pub fn tr(name: &str, functions: impl FnOnce(Type) -> Vec<Function>) -> Trait {
+    let tr = Trait::new(TraitData {
+        keyword: Keyword::<"trait">::at(0),
+        name: Identifier::from(name).at("trait ".len()),
+        supertraits: vec![],
+        functions: IndexMap::new(),
+        module: Module::with_index(0),
+    });
+    for f in functions(tr.self_type().into()) {
+        f.write().unwrap().tr = Some(tr.clone());
+        tr.write()
+            .unwrap()
+            .functions
+            .insert(f.name().to_string(), f);
+    }
+    tr
+}
+
+/// Build immutable variable with type of initializer
+pub fn var(name: &str, initializer: impl Into<Expression>) -> Variable {
+    variable(name, Mutability::Immutable, initializer.into())
+}
+
+/// Build mutable variable with type of initializer
+pub fn var_mut(name: &str, initializer: impl Into<Expression>) -> Variable {
+    variable(name, Mutability::Mutable, initializer.into())
+}
+
+/// Build variable with type of initializer
+fn variable(name: &str, mutability: Mutability, initializer: Expression) -> Variable {
+    let name_offset = match mutability {
+        Mutability::Mutable => "let mut ".len(),
+        Mutability::Immutable => "let ".len(),
+    };
+    Variable::new(VariableData {
+        keyword: Keyword::<"let">::at(0),
+        mutability,
+        name: Identifier::from(name).at(name_offset),
+        source_file: None,
+        type_reference: None,
+        ty: initializer.ty(),
+        initializer: Some(initializer),
+    })
+}
+
+/// Reference to variable
+pub fn reference(variable: &Variable) -> Expression {
+    VariableReference {
+        span: variable.read().unwrap().name.range(),
+        variable: variable.clone().into(),
+    }
+    .into()
+}
+
+/// Reference to function parameter
+pub fn param_reference(parameter: &Parameter) -> Expression {
+    VariableReference {
+        span: parameter.read().unwrap().name_range.clone(),
+        variable: parameter.clone().into(),
+    }
+    .into()
+}
+
+/// Integer literal of specified type
+pub fn integer(value: impl Into<rug::Integer>, ty: Type) -> Expression {
+    let value = value.into();
+    Literal::Integer {
+        span: 0..value.to_string().len(),
+        value,
+        ty,
+    }
+    .into()
+}
+
+/// Call to non-generic function
+pub fn call(function: &Function, args: impl IntoIterator<Item = Expression>) -> Expression {
+    let args: Vec<_> = args.into_iter().collect();
+    let end = args.iter().map(|arg| arg.end()).max().unwrap_or_default();
+    Call {
+        range: 0..end,
+        function: function.clone(),
+        generic: None,
+        args,
+    }
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compilation::Compiler;
+    use crate::hir::ModuleData;
+    use crate::semantics::{Context, ModuleContext};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn function() {
+        let mut compiler = Compiler::new();
+        let context = ModuleContext::new(ModuleData::default(), &mut compiler);
+        let types = context.builtin().types();
+
+        let print = fun("print")
+            .param("x", types.integer())
+            .returns(types.none());
+        let print = print.read().unwrap();
+        assert_eq!(print.name(), "print <:Integer>");
+        assert_eq!(print.name_format(), "print <>");
+
+        // fn print <x: Integer>
+        let x = print.parameters().next().unwrap();
+        let x = x.read().unwrap();
+        assert_eq!(x.name_range, 10..11);
+        assert_eq!(x.ty.span, 13..20);
+        assert_eq!(x.range, 9..21);
+    }
+
+    #[test]
+    fn class_members() {
+        let mut compiler = Compiler::new();
+        let context = ModuleContext::new(ModuleData::default(), &mut compiler);
+        let types = context.builtin().types();
+
+        // type Point: x: Integer, y: Integer
+        let point = class("Point")
+            .member("x", types.integer())
+            .member("y", types.integer())
+            .data();
+        let offsets: Vec<_> = point.members().iter().map(|m| m.start()).collect();
+        assert_eq!(offsets, vec![12, 24]);
+    }
+
+    #[test]
+    fn call_to_function() {
+        let mut compiler = Compiler::new();
+        let context = ModuleContext::new(ModuleData::default(), &mut compiler);
+        let types = context.builtin().types();
+
+        let print = fun("print")
+            .param("x", types.integer())
+            .returns(types.none());
+        let x = var("x", integer(42, types.integer()));
+        let print_x = call(&print, [reference(&x)]);
+
+        assert_eq!(print_x.ty(), types.none());
+        assert_eq!(x.ty(), types.integer());
+    }
+}
@@ -273,6 +273,14 @@ impl Type {
         }
     }
 
+    /// Is this a builtin "Never" type?
+    pub fn is_never(&self) -> bool {
+        match self.without_ref() {
+            Type::Class(c) => c.is_never(),
+            _ => false,
+        }
+    }
+
     /// Is this a builtin "Bool" type?
     pub fn is_bool(&self) -> bool {
         match self.without_ref() {
@@ -289,6 +297,14 @@ impl Type {
         }
     }
 
+    /// Is this a builtin fixed-width integer type?
+    pub fn is_fixed_width_integer(&self) -> bool {
+        match self.without_ref() {
+            Type::Class(c) => c.is_fixed_width_integer(),
+            _ => false,
+        }
+    }
+
     /// Is this a builtin "Integer" type?
     pub fn is_integer(&self) -> bool {
         match self.without_ref() {
@@ -305,6 +321,27 @@ impl Type {
         }
     }
 
+    /// Can values of this type be passed to and returned from C functions?
+    pub fn has_c_abi(&self) -> bool {
+        matches!(
+            self.builtin(),
+            Some(
+                BuiltinClass::None
+                    | BuiltinClass::Never
+                    | BuiltinClass::Bool
+                    | BuiltinClass::I32
+                    | BuiltinClass::I64
+                    | BuiltinClass::U8
+                    | BuiltinClass::U32
+                    | BuiltinClass::U64
+                    | BuiltinClass::Byte
+                    | BuiltinClass::F64
+                    | BuiltinClass::Reference
+                    | BuiltinClass::ReferenceMut
+            )
+        )
+    }
+
     /// Is this a builtin `Reference` or `ReferenceMut` type?
     pub fn is_any_reference(&self) -> bool {
         match self {
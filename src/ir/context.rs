@@ -1,15 +1,20 @@
+use std::collections::HashMap;
+
 use indexmap::IndexMap;
 
-use inkwell::basic_block::BasicBlock;
+use inkwell::{basic_block::BasicBlock, module::Linkage};
 
 use crate::{
     compilation,
-    hir::{ParameterOrVariable, Statement},
+    hir::{ParameterOrVariable, Statement, Trait, Type, VirtualFunctions},
     named::Named,
-    SourceFile,
+    DataHolder, SourceFile,
 };
 
-use super::{DebugInfo, Functions, ToIR, Types};
+use super::{
+    to_ir::{build_vtable_clone, build_vtable_drop, build_vtable_entry, VTABLE_HEADER},
+    DebugInfo, Functions, ToIR, Types,
+};
 
 /// Trait for common context methods
 pub trait Context<'llvm> {
@@ -50,6 +55,10 @@ pub struct ModuleContext<'llvm, 's> {
     pub initializers: Vec<Initializer<'llvm>>,
     /// Debug information builder
     pub debug_info: DebugInfo<'llvm, 's>,
+    /// Record call frames of functions for backtraces
+    pub debug: bool,
+    /// Virtual tables of trait objects for each pair of type and trait
+    pub vtables: HashMap<(Type, Trait), inkwell::values::GlobalValue<'llvm>>,
 }
 
 impl<'llvm, 's> ModuleContext<'llvm, 's> {
@@ -58,6 +67,7 @@ impl<'llvm, 's> ModuleContext<'llvm, 's> {
         compilation_module: compilation::Module,
         module: inkwell::module::Module<'llvm>,
         source_file: &'s SourceFile,
+        debug: bool,
     ) -> Self {
         let debug_info = DebugInfo::new(&module, source_file);
         Self {
@@ -65,9 +75,70 @@ impl<'llvm, 's> ModuleContext<'llvm, 's> {
             module,
             initializers: vec![],
             debug_info,
+            debug,
+            vtables: HashMap::new(),
         }
     }
 
+    /// Get virtual table of trait for type of trait objects.
+    /// Table is built on first request and reused afterwards.
+    ///
+    /// Table starts with functions to drop and clone data of trait object,
+    /// followed by adapters for implementations of trait's
+    /// [`required_functions`](crate::hir::TraitData::required_functions)
+    pub fn vtable(
+        &mut self,
+        tr: &Trait,
+        ty: &Type,
+        functions: &VirtualFunctions,
+    ) -> inkwell::values::GlobalValue<'llvm> {
+        let key = (ty.clone(), tr.clone());
+        if let Some(vtable) = self.vtables.get(&key) {
+            return *vtable;
+        }
+
+        let pointer = self.types().pointer();
+        let vtable = self.module.add_global(
+            pointer.array_type((VTABLE_HEADER + functions.required.len()) as u32),
+            None,
+            &format!("{ty} as {tr}"),
+        );
+        vtable.set_linkage(Linkage::Private);
+        vtable.set_constant(true);
+        // Cache table before building its entries,
+        // as bodies of implementations may need it too
+        self.vtables.insert(key, vtable);
+
+        let destructor = functions.destructor.as_ref().map(|f| f.read().unwrap());
+        let clone = functions.clone.as_ref().map(|f| f.read().unwrap());
+        let header = [
+            build_vtable_drop(ty, destructor.as_deref(), self),
+            build_vtable_clone(ty, clone.as_deref(), self),
+        ];
+        let required: Vec<_> = tr.read().unwrap().required_functions().collect();
+        let entries = header
+            .into_iter()
+            .chain(
+                required
+                    .iter()
+                    .zip(&functions.required)
+                    .map(|(f, implementation)| {
+                        build_vtable_entry(
+                            &f.read().unwrap(),
+                            &implementation.read().unwrap(),
+                            tr,
+                            vtable.as_pointer_value(),
+                            self,
+                        )
+                    }),
+            )
+            .map(|f| f.as_global_value().as_pointer_value())
+            .collect::<Vec<_>>();
+        vtable.set_initializer(&pointer.const_array(&entries));
+
+        vtable
+    }
+
     /// Finalize building module
     pub fn take_module(self) -> inkwell::module::Module<'llvm> {
         self.debug_info.finalize();
@@ -93,6 +164,14 @@ impl<'llvm> Context<'llvm> for ModuleContext<'llvm, '_> {
     }
 }
 
+/// Exit of loop, that is being lowered
+pub struct LoopExit<'llvm> {
+    /// Block to continue execution at after loop
+    pub block: BasicBlock<'llvm>,
+    /// Storage for value, passed out of loop by `break`
+    pub value: Option<inkwell::values::PointerValue<'llvm>>,
+}
+
 /// Context for lowering HIR function to LLVM IR
 pub struct FunctionContext<'llvm, 'm, 's> {
     /// Context for lowering HIR module to LLVM IR
@@ -109,6 +188,8 @@ pub struct FunctionContext<'llvm, 'm, 's> {
     pub parameters: IndexMap<String, inkwell::values::PointerValue<'llvm>>,
     /// Local variables
     pub variables: IndexMap<String, inkwell::values::PointerValue<'llvm>>,
+    /// Exits of loops, that are being lowered. Innermost loop is the last one
+    pub loop_exits: Vec<LoopExit<'llvm>>,
 }
 
 impl<'llvm, 'm, 's> FunctionContext<'llvm, 'm, 's> {
@@ -147,6 +228,7 @@ impl<'llvm, 'm, 's> FunctionContext<'llvm, 'm, 's> {
             return_block,
             parameters: IndexMap::new(),
             variables: IndexMap::new(),
+            loop_exits: vec![],
         }
     }
 
@@ -227,6 +309,71 @@ impl<'llvm, 'm, 's> FunctionContext<'llvm, 'm, 's> {
         self.branch_to_return_block()
     }
 
+    /// Record function's call frame for backtraces, if building in debug mode.
+    /// Frame is pushed at current position and popped in return block
+    pub fn record_frame(&mut self, name: &str, at: usize) {
+        if !self.module_context.debug {
+            return;
+        }
+
+        let current_block = self.builder.get_insert_block().unwrap();
+        self.set_debug_location(at);
+
+        let description = format!("{name} at {}", self.debug().source_location(at));
+        let description = self
+            .builder
+            .build_global_string_ptr(&description, "")
+            .unwrap();
+        self.builder
+            .build_call(
+                self.functions().push_frame(),
+                &[description.as_pointer_value().into()],
+                "",
+            )
+            .unwrap();
+
+        let ret = self.return_block.get_terminator().unwrap();
+        self.builder.position_before(&ret);
+        self.builder
+            .build_call(self.functions().pop_frame(), &[], "")
+            .unwrap();
+
+        self.builder.position_at_end(current_block);
+    }
+
+    /// Panic with `message` at location of `at` offset, if `failed` condition is true.
+    /// Code after check is emitted at the end of a new block
+    pub fn build_panic_if(
+        &mut self,
+        failed: inkwell::values::IntValue<'llvm>,
+        message: &str,
+        at: usize,
+    ) {
+        let panic_block = self.llvm().append_basic_block(self.function, "panic");
+        let ok_block = self.llvm().append_basic_block(self.function, "ok");
+        self.builder
+            .build_conditional_branch(failed, panic_block, ok_block)
+            .unwrap();
+
+        self.builder.position_at_end(panic_block);
+        let message = self.builder.build_global_string_ptr(message, "").unwrap();
+        let location = self.debug().source_location(at);
+        let location = self.builder.build_global_string_ptr(&location, "").unwrap();
+        self.builder
+            .build_call(
+                self.functions().panic_at(),
+                &[
+                    message.as_pointer_value().into(),
+                    location.as_pointer_value().into(),
+                ],
+                "",
+            )
+            .unwrap();
+        self.builder.build_unreachable().unwrap();
+
+        self.builder.position_at_end(ok_block);
+    }
+
     /// Set current debug location at specific offset
     pub fn set_debug_location(&mut self, offset: usize) {
         self.builder
@@ -286,3 +433,82 @@ impl<'llvm> Context<'llvm> for FunctionContext<'llvm, '_, '_> {
         self.module_context.debug()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{
+        hir::test::{class, fun, fun_with_param, tr},
+        ir::test::with_contexts,
+        semantics::Context as _,
+    };
+
+    #[test]
+    fn vtable() {
+        with_contexts(|semantics, context| {
+            let string = semantics.builtin().types().string();
+
+            // trait Printable:
+            //     fn describe <x: Self> -> String
+            //     fn <x: Self> twice -> Self
+            let printable = tr("Printable", |this| {
+                vec![
+                    fun("describe")
+                        .param("x", this.clone())
+                        .returns(string.clone()),
+                    fun_with_param("x", this.clone())
+                        .text("twice")
+                        .returns(this),
+                ]
+            });
+            let point: Type = class("Point").build().into();
+            let functions = VirtualFunctions {
+                destructor: None,
+                clone: None,
+                required: vec![
+                    fun("describe")
+                        .param("x", point.clone())
+                        .returns(string.clone()),
+                    fun_with_param("x", point.clone())
+                        .text("twice")
+                        .returns(point.clone()),
+                ],
+            };
+
+            let vtable = context.vtable(&printable, &point, &functions);
+            assert_eq!(vtable.get_name().to_str().unwrap(), "Point as Printable");
+            assert_eq!(
+                vtable.get_value_type(),
+                context.types().pointer().array_type(4).into()
+            );
+            // Functions to drop and clone data go before trait functions
+            assert!(context.functions().get("drop Point").is_some());
+            assert!(context.functions().get("clone Point").is_some());
+            assert!(context
+                .functions()
+                .get("describe <:Point> as Printable")
+                .is_some());
+            assert!(context
+                .functions()
+                .get("<:Point> twice as Printable")
+                .is_some());
+
+            // Same table is reused for the same type and trait
+            assert_eq!(context.vtable(&printable, &point, &functions), vtable);
+            assert_eq!(context.vtables.len(), 1);
+            assert_eq!(context.module.get_globals().count(), 1);
+
+            // Trait object is a pair of pointers to data and to vtable
+            let object = context.types().trait_object();
+            assert_eq!(
+                object.get_field_types(),
+                vec![
+                    context.types().pointer().into(),
+                    context.types().pointer().into()
+                ]
+            );
+        });
+    }
+}
@@ -104,6 +104,16 @@ impl<'llvm, 's> DebugInfo<'llvm, 's> {
         self.source_file.column_number(offset).zero_based() as u32
     }
 
+    /// Get human-readable location of offset, like `main.ppl:1:5`
+    pub fn source_location(&self, offset: usize) -> String {
+        format!(
+            "{}:{}:{}",
+            self.source_file.name(),
+            self.source_file.line_number(offset).one_based(),
+            self.source_file.column_number(offset).one_based()
+        )
+    }
+
     /// Get debug location
     pub fn location(&self, offset: usize) -> DILocation<'llvm> {
         let line = self.line_number(offset);
@@ -56,4 +56,22 @@ impl<'llvm, 'm> Functions<'llvm, 'm> {
     add_builtin_function!(
         string_from_c_string_and_length: (c_string, u64) -> string
     );
+
+    // LLVM IR for entering function's call frame in debug builds
+    add_builtin_function!(push_frame: (c_string) -> none);
+
+    // LLVM IR for leaving function's call frame in debug builds
+    add_builtin_function!(pop_frame: () -> none);
+
+    // LLVM IR for panicking with message of failed check and its location
+    add_builtin_function!(panic_at: (c_string, c_string) -> none);
+
+    // LLVM IR for storing `argc` and `argv` of `main` in runtime
+    add_builtin_function!(set_program_arguments: (i32, pointer) -> none);
+
+    // LLVM IR for adding reference to box of `Integer`, `Rational` or `String`
+    add_builtin_function!(rc_retain: (pointer) -> pointer);
+
+    // LLVM IR for removing reference to box of `Integer`, `Rational` or `String`
+    add_builtin_function!(rc_release: (pointer) -> none);
 }
@@ -13,4 +13,13 @@ pub use functions::*;
 mod context;
 pub use context::*;
 
+mod verify;
+pub use verify::*;
+
+mod target;
+pub use target::*;
+
 pub(crate) mod inkwell;
+
+#[cfg(test)]
+pub mod test;
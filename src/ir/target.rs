@@ -0,0 +1,66 @@
+use inkwell::{
+    module::Module,
+    passes::PassBuilderOptions,
+    targets::{CodeModel, InitializationConfig, RelocMode, Target, TargetMachine},
+    OptimizationLevel,
+};
+use miette::miette;
+
+/// Convert optimization level from command line (`-O0` to `-O3`) to LLVM's one
+pub fn optimization_level(level: u8) -> OptimizationLevel {
+    match level {
+        0 => OptimizationLevel::None,
+        1 => OptimizationLevel::Less,
+        2 => OptimizationLevel::Default,
+        _ => OptimizationLevel::Aggressive,
+    }
+}
+
+/// Target machine of the host, used to optimize modules
+/// and to emit object files and assembly
+pub fn host_target_machine(level: OptimizationLevel) -> miette::Result<TargetMachine> {
+    Target::initialize_native(&InitializationConfig::default())
+        .map_err(|e| miette!("Can't initialize native target: {e}"))?;
+
+    let triple = TargetMachine::get_default_triple();
+    let target = Target::from_triple(&triple).map_err(|e| miette!("{e}"))?;
+    target
+        .create_target_machine(
+            &triple,
+            &TargetMachine::get_host_cpu_name().to_string(),
+            &TargetMachine::get_host_cpu_features().to_string(),
+            level,
+            RelocMode::PIC,
+            CodeModel::Default,
+        )
+        .ok_or_else(|| {
+            miette!(
+                "Can't create target machine for {}",
+                triple.as_str().to_string_lossy()
+            )
+        })
+}
+
+/// Set target of module to `machine`
+pub fn set_target(module: &Module, machine: &TargetMachine) {
+    module.set_triple(&machine.get_triple());
+    module.set_data_layout(&machine.get_target_data().get_data_layout());
+}
+
+/// Run LLVM's default optimization pipeline for `level` over module.
+/// Does nothing for level 0, leaving module target independent
+pub fn optimize(module: &Module, level: u8, machine: &TargetMachine) -> miette::Result<()> {
+    if level == 0 {
+        return Ok(());
+    }
+
+    set_target(module, machine);
+
+    module
+        .run_passes(
+            &format!("default<O{level}>"),
+            machine,
+            PassBuilderOptions::create(),
+        )
+        .map_err(|e| miette!("Can't optimize module: {e}"))
+}
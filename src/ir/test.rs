@@ -0,0 +1,37 @@
+//! Helpers to lower HIR to LLVM IR in unit tests.
+//!
+//! # Example
+//! ```ignore
+//! with_contexts(|semantics, context| {
+//!     let types = semantics.builtin().types();
+//!     let ty = types.integer().to_ir(context);
+//! });
+//! ```
+
+use miette::NamedSource;
+
+use crate::compilation::{self, Compiler};
+use crate::hir::ModuleData;
+use crate::ir::ModuleContext;
+use crate::semantics;
+use crate::SourceFile;
+
+/// Run `test` with semantic context of a module, that imports builtin module,
+/// and with context for lowering in-memory `main.ppl` to LLVM IR
+pub fn with_contexts<R>(
+    test: impl FnOnce(&mut semantics::ModuleContext<'_>, &mut ModuleContext<'_, '_>) -> R,
+) -> R {
+    let mut compiler = Compiler::new();
+    let mut semantics = semantics::ModuleContext::new(ModuleData::default(), &mut compiler);
+
+    let llvm = inkwell::context::Context::create();
+    let source_file = SourceFile::in_memory(NamedSource::new("main.ppl", String::new()));
+    let mut context = ModuleContext::new(
+        compilation::Module::with_index(0),
+        llvm.create_module("main"),
+        &source_file,
+        false,
+    );
+
+    test(&mut semantics, &mut context)
+}
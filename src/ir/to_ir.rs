@@ -1,12 +1,14 @@
+use inkwell::attributes::{Attribute, AttributeLoc};
 use inkwell::module::Linkage;
 use inkwell::types::BasicMetadataTypeEnum;
+use inkwell::IntPredicate;
 
 use inkwell::values::BasicMetadataValueEnum;
 use inkwell::values::CallSiteValue;
 use log::trace;
 
 use super::inkwell::*;
-use crate::compilation;
+use crate::compilation::{self, CompilingFile};
 use crate::hir::*;
 use crate::ir::Initializer;
 use crate::mutability::Mutable;
@@ -16,6 +18,7 @@ use crate::DataHolder;
 
 use super::Context;
 use super::FunctionContext;
+use super::LoopExit;
 use super::ModuleContext;
 
 /// Trait for lowering to IR within some context
@@ -35,7 +38,7 @@ impl<'llvm, C: Context<'llvm>> ToIR<'llvm, C> for Type {
         match self {
             Type::Class(ty) => ty.read().unwrap().to_ir(context).into(),
             Type::SelfType(_) => unreachable!("Self must not be lowered to IR"),
-            Type::Trait(_) => unreachable!("Trait must not be lowered to IR"),
+            Type::Trait(_) => context.types().trait_object().into(),
             Type::Generic(_) => unreachable!("Generic must not be lowered to IR"),
             Type::Function { .. } => unimplemented!("Function type lowering"),
             Type::Unknown => unreachable!("Lowering not-inferred type"),
@@ -237,12 +240,15 @@ impl<'llvm, C: Context<'llvm>> ToIR<'llvm, C> for ClassData {
     fn to_ir(&self, context: &mut C) -> Self::IR {
         trace!(target: "to_ir", "{self}");
 
-        if self.is_none() || self.is_any_reference() && self.generics()[0].is_none() {
+        if self.is_none()
+            || self.is_never()
+            || self.is_any_reference() && self.generics()[0].is_none()
+        {
             return context.types().none().into();
         } else if self.is_bool() {
             return context.types().bool().into();
-        } else if self.is_i32() {
-            return context.types().i32().into();
+        } else if let Some(bits) = self.integer_bits() {
+            return context.types().i(bits).into();
         } else if self.is_f64() {
             return context.types().f64().into();
         }
@@ -268,6 +274,28 @@ impl<'llvm, C: Context<'llvm>> ToIR<'llvm, C> for ClassData {
     }
 }
 
+/// Get index of struct field in LLVM IR for member of class.
+/// Members of types without values, like `None`, have no fields.
+///
+/// Returns `None` for such members
+fn field_index<'llvm>(
+    class: &ClassData,
+    member: usize,
+    context: &mut impl Context<'llvm>,
+) -> Option<u32> {
+    let mut has_field = |m: &Member| m.ty().to_ir(context).try_into_basic_type().is_ok();
+    if !has_field(&class.members[member]) {
+        return None;
+    }
+
+    Some(
+        class.members[..member]
+            .iter()
+            .filter(|m| has_field(m))
+            .count() as u32,
+    )
+}
+
 impl<'llvm> DeclareGlobal<'llvm> for FunctionData {
     type IR = inkwell::values::FunctionValue<'llvm>;
 
@@ -287,7 +315,7 @@ impl<'llvm> DeclareGlobal<'llvm> for FunctionData {
             }
             _ => unreachable!("FunctionDeclaration::ty() returned non-function type"),
         };
-        context.module.add_function(
+        let function = context.module.add_function(
             &self.mangled_name(),
             ty,
             // Private linkage for monomorphized generic functions or functions from traits
@@ -297,7 +325,25 @@ impl<'llvm> DeclareGlobal<'llvm> for FunctionData {
             } else {
                 None
             },
-        )
+        );
+
+        if let Some(hint) = self.inline_hint {
+            let name = match hint {
+                InlineHint::Always => "alwaysinline",
+                InlineHint::Never => "noinline",
+            };
+            let kind = Attribute::get_named_enum_kind_id(name);
+            let attribute = context.llvm().create_enum_attribute(kind, 0);
+            function.add_attribute(AttributeLoc::Function, attribute);
+        }
+
+        if self.return_type.is_never() {
+            let kind = Attribute::get_named_enum_kind_id("noreturn");
+            let attribute = context.llvm().create_enum_attribute(kind, 0);
+            function.add_attribute(AttributeLoc::Function, attribute);
+        }
+
+        function
     }
 }
 
@@ -358,6 +404,7 @@ impl<'llvm> EmitBody<'llvm> for FunctionData {
             .expect("Function was not declared before emitting body");
         if !self.body.is_empty() {
             let mut f_context = FunctionContext::new(context, f, self.start());
+            f_context.record_frame(&self.name(), self.start());
             for (i, p) in self
                 .parameters()
                 .filter(|p| !p.name().is_empty() && !p.ty().is_none())
@@ -394,6 +441,11 @@ impl<'llvm, 'm> ToIR<'llvm, FunctionContext<'llvm, 'm, '_>> for Literal {
                 .bool()
                 .const_int(*value as u64, false)
                 .into(),
+            Literal::Integer { value, ty, .. } if ty.is_fixed_width_integer() => ty
+                .to_ir(context)
+                .into_int_type()
+                .const_int(value.to_u64_wrapping(), false)
+                .into(),
             Literal::Integer { value, .. } => {
                 if let Some(value) = value.to_i64() {
                     return Some(
@@ -505,6 +557,14 @@ impl<'llvm, 'm> ToIR<'llvm, FunctionContext<'llvm, 'm, '_>> for Call {
     fn to_ir(&self, context: &mut FunctionContext<'llvm, 'm, '_>) -> Self::IR {
         trace!(target: "to_ir", "{self}");
 
+        if let Some(call) = self
+            .lower_trait_object_drop_to_ir(context)
+            .or_else(|| self.lower_dynamic_dispatch_to_ir(context))
+            .or_else(|| self.lower_release_to_ir(context))
+        {
+            return call;
+        }
+
         let function = context
             .functions()
             .get(&self.function.read().unwrap().mangled_name())
@@ -547,10 +607,546 @@ impl<'llvm, 'm> ToIR<'llvm, FunctionContext<'llvm, 'm, '_>> for Call {
             })
             .collect::<Vec<BasicMetadataValueEnum>>();
 
-        context
+        let call = context
             .builder
             .build_call(function, &arguments, "")
+            .unwrap();
+
+        if self.function.read().unwrap().return_type.is_never() {
+            // Code after this call is unreachable,
+            // but it's still lowered to a separate block
+            context.builder.build_unreachable().unwrap();
+            let after = context
+                .llvm()
+                .append_basic_block(context.function, "after_never");
+            context.builder.position_at_end(after);
+        }
+
+        call
+    }
+}
+
+impl Call {
+    /// Lower call to builtin `and` or `or` to branches,
+    /// so that right operand is evaluated only when needed.
+    ///
+    /// Returns `None`, if this is a call to any other function
+    fn lower_short_circuit_to_ir<'llvm, 'm>(
+        &self,
+        context: &mut FunctionContext<'llvm, 'm, '_>,
+    ) -> Option<inkwell::values::BasicValueEnum<'llvm>> {
+        let is_and = match self.function.read().unwrap().mangled_name().as_ref() {
+            "<:Bool> and <:Bool>" => true,
+            "<:Bool> or <:Bool>" => false,
+            _ => return None,
+        };
+        let name = if is_and { "and" } else { "or" };
+
+        let lhs = self.args[0].to_ir(context).unwrap().into_int_value();
+        let lhs_block = context.builder.get_insert_block().unwrap();
+
+        let rhs_block = context
+            .llvm()
+            .append_basic_block(context.function, &format!("{name}.rhs"));
+        rhs_block.move_after(lhs_block).unwrap();
+        let merge_block = context
+            .llvm()
+            .append_basic_block(context.function, &format!("{name}.end"));
+
+        let (if_true, if_false) = if is_and {
+            (rhs_block, merge_block)
+        } else {
+            (merge_block, rhs_block)
+        };
+        context
+            .builder
+            .build_conditional_branch(lhs, if_true, if_false)
+            .unwrap();
+
+        context.builder.position_at_end(rhs_block);
+        let rhs = self.args[1].to_ir(context).unwrap().into_int_value();
+        let rhs_block = context.builder.get_insert_block().unwrap();
+        context
+            .builder
+            .build_unconditional_branch(merge_block)
+            .unwrap();
+
+        merge_block.move_after(rhs_block).unwrap();
+        context.builder.position_at_end(merge_block);
+        let phi = context.builder.build_phi(lhs.get_type(), "").unwrap();
+        // Left operand decides the result, when right one is skipped
+        phi.add_incoming(&[(&lhs, lhs_block), (&rhs, rhs_block)]);
+        Some(phi.as_basic_value())
+    }
+
+    /// Lower call to builtin operator on fixed-width integers (`I64`, `U8`, etc)
+    /// directly to LLVM instructions.
+    ///
+    /// Returns `None`, if this is a call to any other function
+    fn lower_fixed_width_integer_op_to_ir<'llvm, 'm>(
+        &self,
+        context: &mut FunctionContext<'llvm, 'm, '_>,
+    ) -> Option<inkwell::values::BasicValueEnum<'llvm>> {
+        let (name_format, signed) = {
+            let f = self.function.read().unwrap();
+            if f.is_definition() || f.mangled_name.is_some() {
+                return None;
+            }
+
+            let Type::Class(class) = f.parameters().next()?.ty() else {
+                return None;
+            };
+            let class = class.read().unwrap();
+            if !class.is_fixed_width_integer() {
+                return None;
+            }
+            (f.name_format().to_string(), class.is_signed_integer())
+        };
+
+        if !matches!(
+            name_format.as_str(),
+            "- <>"
+                | "<> + <>"
+                | "<> - <>"
+                | "<> * <>"
+                | "<> div <>"
+                | "<> mod <>"
+                | "<> rem <>"
+                | "<> == <>"
+                | "<> < <>"
+                | "<> & <>"
+                | "<> | <>"
+                | "<> xor <>"
+                | "<> << <>"
+                | "<> >> <>"
+        ) {
+            return None;
+        }
+
+        let args = self
+            .args
+            .iter()
+            .map(|arg| arg.to_ir(context).unwrap().into_int_value())
+            .collect::<Vec<_>>();
+
+        // Division by zero and `MIN div -1` are undefined behavior in LLVM
+        if let ("<> div <>" | "<> mod <>" | "<> rem <>", [x, y]) = (name_format.as_str(), &args[..])
+        {
+            let ty = x.get_type();
+            let by_zero = context
+                .builder
+                .build_int_compare(IntPredicate::EQ, *y, ty.const_zero(), "")
+                .unwrap();
+            context.build_panic_if(by_zero, "division by zero", self.range.start);
+
+            if signed {
+                let min = ty.const_int(1 << (ty.get_bit_width() - 1), false);
+                let builder = &context.builder;
+                let overflow = builder
+                    .build_and(
+                        builder
+                            .build_int_compare(IntPredicate::EQ, *x, min, "")
+                            .unwrap(),
+                        builder
+                            .build_int_compare(IntPredicate::EQ, *y, ty.const_all_ones(), "")
+                            .unwrap(),
+                        "",
+                    )
+                    .unwrap();
+                context.build_panic_if(overflow, "division overflow", self.range.start);
+            }
+        }
+
+        let builder = &context.builder;
+        if let [x] = args[..] {
+            return Some(builder.build_int_neg(x, "").unwrap().into());
+        }
+
+        let [x, y] = args[..] else {
+            unreachable!("Binary operator with {} arguments", args.len())
+        };
+        let ty = x.get_type();
+        let zero = ty.const_zero();
+        // Shift by bit width or more is poison in LLVM, so wrap it like `I32` does
+        let shift = || {
+            let mask = ty.const_int(ty.get_bit_width() as u64 - 1, false);
+            builder.build_and(y, mask, "").unwrap()
+        };
+        // Remainder is rounded towards zero, while `div` and `mod` round to negative infinity.
+        // This tells, if results of rounding differ
+        let needs_floor_adjustment = |rem: inkwell::values::IntValue<'llvm>| {
+            let not_exact = builder
+                .build_int_compare(IntPredicate::NE, rem, zero, "")
+                .unwrap();
+            let signs_differ = builder
+                .build_int_compare(
+                    IntPredicate::SLT,
+                    builder.build_xor(rem, y, "").unwrap(),
+                    zero,
+                    "",
+                )
+                .unwrap();
+            builder.build_and(not_exact, signs_differ, "").unwrap()
+        };
+        let rem = || {
+            if signed {
+                builder.build_int_signed_rem(x, y, "").unwrap()
+            } else {
+                builder.build_int_unsigned_rem(x, y, "").unwrap()
+            }
+        };
+
+        let value = match name_format.as_str() {
+            "<> + <>" => builder.build_int_add(x, y, "").unwrap(),
+            "<> - <>" => builder.build_int_sub(x, y, "").unwrap(),
+            "<> * <>" => builder.build_int_mul(x, y, "").unwrap(),
+            "<> div <>" if signed => {
+                let quotient = builder.build_int_signed_div(x, y, "").unwrap();
+                let adjust = builder
+                    .build_int_z_extend(needs_floor_adjustment(rem()), ty, "")
+                    .unwrap();
+                builder.build_int_sub(quotient, adjust, "").unwrap()
+            }
+            "<> div <>" => builder.build_int_unsigned_div(x, y, "").unwrap(),
+            "<> mod <>" if signed => {
+                let rem = rem();
+                let adjust = builder
+                    .build_select(needs_floor_adjustment(rem), y, zero, "")
+                    .unwrap()
+                    .into_int_value();
+                builder.build_int_add(rem, adjust, "").unwrap()
+            }
+            "<> mod <>" | "<> rem <>" => rem(),
+            "<> == <>" => builder
+                .build_int_compare(IntPredicate::EQ, x, y, "")
+                .unwrap(),
+            "<> < <>" => {
+                let predicate = if signed {
+                    IntPredicate::SLT
+                } else {
+                    IntPredicate::ULT
+                };
+                builder.build_int_compare(predicate, x, y, "").unwrap()
+            }
+            "<> & <>" => builder.build_and(x, y, "").unwrap(),
+            "<> | <>" => builder.build_or(x, y, "").unwrap(),
+            "<> xor <>" => builder.build_xor(x, y, "").unwrap(),
+            "<> << <>" => builder.build_left_shift(x, shift(), "").unwrap(),
+            "<> >> <>" => builder.build_right_shift(x, shift(), signed, "").unwrap(),
+            _ => unreachable!("Unexpected fixed-width integer operator `{name_format}`"),
+        };
+        Some(value.into())
+    }
+
+    /// Load reference counted box of builtin value (`Integer`, `Rational` or `String`),
+    /// if this is a call to its `clone` or `destroy` function with `prefix`
+    fn refcounted_box<'llvm, 'm>(
+        &self,
+        prefix: &str,
+        context: &mut FunctionContext<'llvm, 'm, '_>,
+    ) -> Option<inkwell::values::PointerValue<'llvm>> {
+        let name = self.function.read().unwrap().mangled_name();
+        let class = name.strip_prefix(prefix)?;
+        if !matches!(class, "integer" | "rational" | "string") {
+            return None;
+        }
+
+        let value = self.args[0]
+            .lower_to_ir_without_load(context)?
+            .into_pointer_value();
+        // Builtin value is a pointer to its box
+        Some(
+            context
+                .builder
+                .build_load(context.types().pointer(), value, "")
+                .unwrap()
+                .into_pointer_value(),
+        )
+    }
+
+    /// Lower `clone` of builtin reference counted value to retain of its box.
+    ///
+    /// Returns `None`, if this is a call to any other function
+    fn lower_retain_to_ir<'llvm, 'm>(
+        &self,
+        context: &mut FunctionContext<'llvm, 'm, '_>,
+    ) -> Option<inkwell::values::BasicValueEnum<'llvm>> {
+        let data = self.refcounted_box("clone_", context)?;
+        let data = context
+            .builder
+            .build_call(context.functions().rc_retain(), &[data.into()], "")
+            .unwrap()
+            .try_as_basic_value()
+            .left()
+            .unwrap();
+
+        let ty = self
+            .function
+            .read()
+            .unwrap()
+            .return_type
+            .to_ir(context)
+            .try_into_basic_type()
+            .unwrap()
+            .into_struct_type();
+        Some(
+            context
+                .builder
+                .build_insert_value(ty.get_undef(), data, 0, "")
+                .unwrap()
+                .into_struct_value()
+                .into(),
+        )
+    }
+
+    /// Lower `destroy` of builtin reference counted value to release of its box.
+    ///
+    /// Returns `None`, if this is a call to any other function
+    fn lower_release_to_ir<'llvm, 'm>(
+        &self,
+        context: &mut FunctionContext<'llvm, 'm, '_>,
+    ) -> Option<CallSiteValue<'llvm>> {
+        let data = self.refcounted_box("destroy_", context)?;
+        Some(
+            context
+                .builder
+                .build_call(context.functions().rc_release(), &[data.into()], "")
+                .unwrap(),
+        )
+    }
+
+    /// Is this a call to function of builtin trait `tr` with trait object in place of `Self`?
+    fn is_trait_object_builtin(&self, tr: &str) -> bool {
+        let f = self.function.read().unwrap();
+        f.tr.as_ref().is_some_and(|t| t.name() == tr)
+            && !f.is_definition()
+            && self
+                .args
+                .first()
+                .is_some_and(|arg| matches!(arg.ty().without_ref(), Type::Trait(_)))
+    }
+
+    /// Load function at `index` from virtual table of trait object.
+    ///
+    /// Returns data of trait object and loaded function
+    fn vtable_function<'llvm>(
+        object: inkwell::values::StructValue<'llvm>,
+        index: u64,
+        context: &mut FunctionContext<'llvm, '_, '_>,
+    ) -> (
+        inkwell::values::PointerValue<'llvm>,
+        inkwell::values::PointerValue<'llvm>,
+    ) {
+        let data = context
+            .builder
+            .build_extract_value(object, 0, "data")
             .unwrap()
+            .into_pointer_value();
+        let vtable = context
+            .builder
+            .build_extract_value(object, 1, "vtable")
+            .unwrap()
+            .into_pointer_value();
+
+        let pointer = context.types().pointer();
+        let entry = unsafe {
+            context.builder.build_in_bounds_gep(
+                pointer,
+                vtable,
+                &[context.types().i64().const_int(index, false)],
+                "",
+            )
+        }
+        .unwrap();
+        let function = context
+            .builder
+            .build_load(pointer, entry, "")
+            .unwrap()
+            .into_pointer_value();
+        (data, function)
+    }
+
+    /// Lower `destroy` of trait object to call of drop function from its virtual table.
+    ///
+    /// Returns `None`, if this is a call to any other function
+    fn lower_trait_object_drop_to_ir<'llvm, 'm>(
+        &self,
+        context: &mut FunctionContext<'llvm, 'm, '_>,
+    ) -> Option<CallSiteValue<'llvm>> {
+        if !self.is_trait_object_builtin("Destructible") {
+            return None;
+        }
+
+        let ptr = self.args[0]
+            .lower_to_ir_without_load(context)?
+            .into_pointer_value();
+        let object = context
+            .builder
+            .build_load(context.types().trait_object(), ptr, "")
+            .unwrap()
+            .into_struct_value();
+        let (data, drop) = Self::vtable_function(object, VTABLE_DROP, context);
+
+        let pointer = context.types().pointer();
+        let ty = context.types().none().fn_type(&[pointer.into()], false);
+        Some(
+            context
+                .builder
+                .build_indirect_call(ty, drop, &[data.into()], "")
+                .unwrap(),
+        )
+    }
+
+    /// Lower `clone` of trait object to call of clone function from its virtual table.
+    /// Cloned trait object shares virtual table with the original one.
+    ///
+    /// Returns `None`, if this is a call to any other function
+    fn lower_trait_object_clone_to_ir<'llvm, 'm>(
+        &self,
+        context: &mut FunctionContext<'llvm, 'm, '_>,
+    ) -> Option<inkwell::values::BasicValueEnum<'llvm>> {
+        if !self.is_trait_object_builtin("Clonnable") {
+            return None;
+        }
+
+        let object = self.args[0].to_ir(context)?.into_struct_value();
+        let (data, clone) = Self::vtable_function(object, VTABLE_CLONE, context);
+
+        let pointer = context.types().pointer();
+        let ty = pointer.fn_type(&[pointer.into()], false);
+        let data = context
+            .builder
+            .build_indirect_call(ty, clone, &[data.into()], "")
+            .unwrap()
+            .try_as_basic_value()
+            .left()
+            .unwrap();
+        Some(
+            context
+                .builder
+                .build_insert_value(object, data, 0, "")
+                .unwrap()
+                .into_struct_value()
+                .into(),
+        )
+    }
+
+    /// Lower call to trait function, that has trait objects in place of `Self`,
+    /// to indirect call through virtual table of those objects.
+    ///
+    /// Returns `None`, if this is a call to any other function
+    fn lower_dynamic_dispatch_to_ir<'llvm, 'm>(
+        &self,
+        context: &mut FunctionContext<'llvm, 'm, '_>,
+    ) -> Option<CallSiteValue<'llvm>> {
+        let f = self.function.read().unwrap();
+        if !f.is_from_trait() || f.is_definition() {
+            return None;
+        }
+
+        let tr = f.parameters().find_map(|p| match p.ty().without_ref() {
+            Type::Trait(tr) => Some(tr),
+            _ => None,
+        })?;
+
+        let mut declaration = self.function.clone();
+        loop {
+            let generic_version = declaration.read().unwrap().generic_version.clone();
+            match generic_version {
+                Some(generic) => declaration = generic,
+                None => break,
+            }
+        }
+        let index = VTABLE_HEADER
+            + tr.read()
+                .unwrap()
+                .required_functions()
+                .position(|f| f.is_same(&declaration))
+                .expect("Trait object's function is not in its virtual table");
+
+        let mut vtable = None;
+        let mut arguments: Vec<BasicMetadataValueEnum> = vec![];
+        for (arg, p) in self.args.iter().zip(f.parameters()) {
+            let ty = p.ty();
+            if !matches!(ty.without_ref(), Type::Trait(_)) {
+                let value = if ty.is_any_reference() {
+                    arg.lower_to_ir_without_load(context)
+                } else {
+                    arg.to_ir(context)
+                };
+                if let Some(value) = value {
+                    arguments.push(value.into());
+                }
+                continue;
+            }
+
+            let object = if ty.is_any_reference() {
+                let ptr = arg.lower_to_ir_without_load(context).unwrap();
+                context
+                    .builder
+                    .build_load(context.types().trait_object(), ptr.into_pointer_value(), "")
+                    .unwrap()
+            } else {
+                arg.to_ir(context).unwrap()
+            }
+            .into_struct_value();
+            let data = context
+                .builder
+                .build_extract_value(object, 0, "data")
+                .unwrap();
+            arguments.push(data.into());
+            let object_vtable = context
+                .builder
+                .build_extract_value(object, 1, "vtable")
+                .unwrap()
+                .into_pointer_value();
+            match vtable {
+                None => vtable = Some(object_vtable),
+                // Implementation expects all `Self` arguments to have the same type
+                Some(vtable) => {
+                    let differs = context
+                        .builder
+                        .build_int_compare(IntPredicate::NE, vtable, object_vtable, "")
+                        .unwrap();
+                    context.build_panic_if(
+                        differs,
+                        "trait objects of different types",
+                        arg.start(),
+                    );
+                }
+            }
+        }
+
+        let pointer = context.types().pointer();
+        let entry = unsafe {
+            context.builder.build_in_bounds_gep(
+                pointer,
+                vtable.unwrap(),
+                &[context.types().i64().const_int(index as u64, false)],
+                "",
+            )
+        }
+        .unwrap();
+        let function = context
+            .builder
+            .build_load(pointer, entry, &f.name())
+            .unwrap()
+            .into_pointer_value();
+
+        let ty = vtable_entry_type(
+            f.parameters().map(|p| match p.ty().without_ref() {
+                Type::Trait(_) => None,
+                _ => Some(p.ty()),
+            }),
+            f.return_type.clone(),
+            context,
+        );
+        Some(
+            context
+                .builder
+                .build_indirect_call(ty, function, &arguments, "")
+                .unwrap(),
+        )
     }
 }
 
@@ -568,13 +1164,17 @@ impl<'llvm, 'm> ToIR<'llvm, FunctionContext<'llvm, 'm, '_>> for Constructor {
             .expect("non-basic type constructor");
         let alloca = context.builder.build_alloca(ty, "").unwrap();
 
+        let class = self.ty.referenced_type.clone().as_class();
         for init in self.initializers.iter().filter(|i| !i.value.ty().is_none()) {
+            let Some(index) = field_index(&class.read().unwrap(), init.index, context) else {
+                continue;
+            };
             let field = context
                 .builder
                 .build_struct_gep(
                     ty,
                     alloca,
-                    init.index as u32,
+                    index,
                     format!("{}.{}", self.ty.referenced_type.name(), init.member.name()).as_str(),
                 )
                 .unwrap();
@@ -607,12 +1207,15 @@ impl<'llvm, 'm> HIRExpressionLoweringWithoutLoad<'llvm, 'm> for MemberReference
             return None;
         }
 
+        let class = self.base.ty().as_class();
+        let index = field_index(&class.read().unwrap(), self.index, context)?;
+
         let base = base.unwrap().into_pointer_value();
         let ty = self.base.ty().to_ir(context).try_into_basic_type().unwrap();
         Some(
             context
                 .builder
-                .build_struct_gep(ty, base, self.index as u32, &self.member.name())
+                .build_struct_gep(ty, base, index, &self.member.name())
                 .unwrap()
                 .into(),
         )
@@ -627,14 +1230,280 @@ impl<'llvm, 'm> HIRExpressionLoweringWithoutLoad<'llvm, 'm> for ImplicitConversi
         trace!(target: "lower_to_ir_without_load", "{self}");
 
         use ImplicitConversionKind::*;
-        match self.kind {
+        match &self.kind {
             Reference => self.expression.lower_to_ir_without_load(context),
             Dereference => self.expression.to_ir(context),
             Copy => self.expression.to_ir(context),
+            TraitObject(functions) => {
+                Some(self.lower_trait_object_to_ir(functions, context).into())
+            }
+            Never => {
+                // Value is never used, as control doesn't return from expression
+                self.expression.to_ir(context);
+                self.ty
+                    .to_ir(context)
+                    .try_into_basic_type()
+                    .ok()
+                    .map(|ty| ty.const_zero())
+            }
         }
     }
 }
 
+impl ImplicitConversion {
+    /// Lower conversion of value to trait object.
+    /// Value is moved to heap and paired with virtual table for its type
+    fn lower_trait_object_to_ir<'llvm, 'm>(
+        &self,
+        functions: &VirtualFunctions,
+        context: &mut FunctionContext<'llvm, 'm, '_>,
+    ) -> inkwell::values::StructValue<'llvm> {
+        let Type::Trait(tr) = &self.ty else {
+            unreachable!("Trait object of non-trait type `{}`", self.ty)
+        };
+
+        let value = self.expression.to_ir(context);
+        let vtable = context
+            .module_context
+            .vtable(tr, &self.expression.ty(), functions)
+            .as_pointer_value();
+        build_trait_object(value, vtable, context)
+    }
+}
+
+/// Build trait object from value of erased type and virtual table for that type
+fn build_trait_object<'llvm>(
+    value: Option<inkwell::values::BasicValueEnum<'llvm>>,
+    vtable: inkwell::values::PointerValue<'llvm>,
+    context: &mut FunctionContext<'llvm, '_, '_>,
+) -> inkwell::values::StructValue<'llvm> {
+    let data = match value {
+        Some(value) => {
+            let data = context
+                .builder
+                .build_malloc(value.get_type(), "data")
+                .unwrap();
+            context.builder.build_store(data, value).unwrap();
+            data
+        }
+        None => context.types().pointer().const_null(),
+    };
+
+    let ty = context.types().trait_object();
+    let object = context
+        .builder
+        .build_insert_value(ty.get_undef(), data, 0, "")
+        .unwrap();
+    context
+        .builder
+        .build_insert_value(object, vtable, 1, "")
+        .unwrap()
+        .into_struct_value()
+}
+
+/// Get LLVM type of function in virtual table.
+/// Parameters of erased `Self` type are passed as pointers to their data
+/// and must be specified as `None`
+fn vtable_entry_type<'llvm>(
+    parameters: impl Iterator<Item = Option<Type>>,
+    return_type: Type,
+    context: &mut impl Context<'llvm>,
+) -> inkwell::types::FunctionType<'llvm> {
+    let parameters = parameters
+        .filter_map(|p| match p {
+            Some(ty) => ty.to_ir(context).try_into().ok(),
+            None => Some(context.types().pointer().into()),
+        })
+        .collect::<Vec<BasicMetadataTypeEnum>>();
+    return_type.to_ir(context).fn_type(&parameters, false)
+}
+
+/// Index of function in virtual table, that destroys value of trait object and frees its data
+const VTABLE_DROP: u64 = 0;
+/// Index of function in virtual table, that copies data of trait object
+const VTABLE_CLONE: u64 = 1;
+/// Number of functions in virtual table before implementations of trait functions
+pub(crate) const VTABLE_HEADER: usize = 2;
+
+/// Get function, that is called from virtual table, declaring it if needed
+fn vtable_callee<'llvm>(
+    f: &FunctionData,
+    context: &mut ModuleContext<'llvm, '_>,
+) -> inkwell::values::FunctionValue<'llvm> {
+    context
+        .functions()
+        .get(&f.mangled_name())
+        .unwrap_or_else(|| {
+            if f.generic_version.is_none() {
+                f.declare_global(context)
+            } else {
+                f.to_ir(context)
+            }
+        })
+}
+
+/// Build function, that destroys value of erased type and frees data of trait object
+pub(crate) fn build_vtable_drop<'llvm>(
+    ty: &Type,
+    destructor: Option<&FunctionData>,
+    context: &mut ModuleContext<'llvm, '_>,
+) -> inkwell::values::FunctionValue<'llvm> {
+    let name = format!("drop {ty}");
+    if let Some(drop) = context.functions().get(&name) {
+        return drop;
+    }
+
+    let destructor = destructor.map(|f| (vtable_callee(f, context), f.start()));
+    let pointer = context.types().pointer();
+    let drop = context.module.add_function(
+        &name,
+        context.types().none().fn_type(&[pointer.into()], false),
+        Some(Linkage::Private),
+    );
+
+    let at = destructor.map_or(0, |(_, at)| at);
+    let mut f_context = FunctionContext::new(context, drop, at);
+    let data = drop.get_first_param().unwrap().into_pointer_value();
+    if let Some((destructor, _)) = destructor {
+        f_context
+            .builder
+            .build_call(destructor, &[data.into()], "")
+            .unwrap();
+    }
+    f_context.builder.build_free(data).unwrap();
+    f_context.load_return_value_and_branch(None);
+
+    drop
+}
+
+/// Build function, that copies value of erased type to new data of trait object
+pub(crate) fn build_vtable_clone<'llvm>(
+    ty: &Type,
+    clone: Option<&FunctionData>,
+    context: &mut ModuleContext<'llvm, '_>,
+) -> inkwell::values::FunctionValue<'llvm> {
+    let name = format!("clone {ty}");
+    if let Some(function) = context.functions().get(&name) {
+        return function;
+    }
+
+    let clone = clone.map(|f| (vtable_callee(f, context), f.start()));
+    let pointer = context.types().pointer();
+    let function = context.module.add_function(
+        &name,
+        pointer.fn_type(&[pointer.into()], false),
+        Some(Linkage::Private),
+    );
+
+    let at = clone.map_or(0, |(_, at)| at);
+    let mut f_context = FunctionContext::new(context, function, at);
+    let data = function.get_first_param().unwrap().into_pointer_value();
+    let copy = match ty.to_ir(&mut f_context).try_into_basic_type() {
+        Ok(value_ty) => {
+            let value = match clone {
+                Some((clone, _)) => f_context
+                    .builder
+                    .build_call(clone, &[data.into()], "")
+                    .unwrap()
+                    .try_as_basic_value()
+                    .left()
+                    .unwrap(),
+                None => f_context.builder.build_load(value_ty, data, "").unwrap(),
+            };
+            let copy = f_context.builder.build_malloc(value_ty, "data").unwrap();
+            f_context.builder.build_store(copy, value).unwrap();
+            copy
+        }
+        Err(_) => pointer.const_null(),
+    };
+    f_context.load_return_value_and_branch(Some(copy.into()));
+
+    function
+}
+
+/// Build adapter for implementation of trait function,
+/// that accepts values of erased `Self` type by pointers to their data
+/// and returns trait objects instead of `Self`.
+///
+/// Trait objects, passed by value, are owned by the callee,
+/// so their values are moved out of data, which is then freed
+pub(crate) fn build_vtable_entry<'llvm>(
+    trait_fn: &FunctionData,
+    implementation: &FunctionData,
+    tr: &Trait,
+    vtable: inkwell::values::PointerValue<'llvm>,
+    context: &mut ModuleContext<'llvm, '_>,
+) -> inkwell::values::FunctionValue<'llvm> {
+    let name = format!("{} as {tr}", implementation.mangled_name());
+    if let Some(adapter) = context.functions().get(&name) {
+        return adapter;
+    }
+
+    let function = vtable_callee(implementation, context);
+
+    let returns_self = matches!(trait_fn.return_type, Type::SelfType(_));
+    let return_type = if returns_self {
+        tr.clone().into()
+    } else {
+        implementation.return_type.clone()
+    };
+    let parameters: Vec<_> = trait_fn
+        .parameters()
+        .zip(implementation.parameters())
+        .map(|(p, imp)| match p.ty().without_ref() {
+            Type::SelfType(_) => None,
+            _ => Some(imp.ty()),
+        })
+        .collect();
+    let ty = vtable_entry_type(parameters.iter().cloned(), return_type, context);
+    let adapter = context
+        .module
+        .add_function(&name, ty, Some(Linkage::Private));
+
+    let mut f_context = FunctionContext::new(context, adapter, implementation.start());
+    let mut params = adapter.get_param_iter();
+    let mut arguments: Vec<BasicMetadataValueEnum> = vec![];
+    for (p, erased) in implementation
+        .parameters()
+        .zip(parameters.iter().map(Option::is_none))
+    {
+        let ty = p.ty().to_ir(&mut f_context).try_into_basic_type().ok();
+        if !erased {
+            if ty.is_some() {
+                arguments.push(params.next().unwrap().into());
+            }
+            continue;
+        }
+
+        let data = params.next().unwrap().into_pointer_value();
+        match ty {
+            Some(_) if p.ty().is_any_reference() => arguments.push(data.into()),
+            Some(ty) => {
+                let value = f_context.builder.build_load(ty, data, "").unwrap();
+                f_context.builder.build_free(data).unwrap();
+                arguments.push(value.into())
+            }
+            None => continue,
+        }
+    }
+
+    f_context.set_debug_location(implementation.start());
+    let value = f_context
+        .builder
+        .build_call(function, &arguments, "")
+        .unwrap()
+        .try_as_basic_value()
+        .left();
+    let value = if returns_self {
+        Some(build_trait_object(value, vtable, &mut f_context).into())
+    } else {
+        value
+    };
+    f_context.load_return_value_and_branch(value);
+
+    adapter
+}
+
 impl<'llvm, 'm> HIRExpressionLoweringWithoutLoad<'llvm, 'm> for Expression {
     /// Lower [`Expression`] to LLVM IR without loading variables
     fn lower_to_ir_without_load(
@@ -648,13 +1517,19 @@ impl<'llvm, 'm> HIRExpressionLoweringWithoutLoad<'llvm, 'm> for Expression {
         match self {
             Expression::VariableReference(var) => var.to_ir(context).map(Into::into),
             Expression::Literal(l) => l.to_ir(context),
-            Expression::Call(call) => call.to_ir(context).try_as_basic_value().left(),
+            Expression::Call(call) => call
+                .lower_short_circuit_to_ir(context)
+                .or_else(|| call.lower_fixed_width_integer_op_to_ir(context))
+                .or_else(|| call.lower_retain_to_ir(context))
+                .or_else(|| call.lower_trait_object_clone_to_ir(context))
+                .or_else(|| call.to_ir(context).try_as_basic_value().left()),
             Expression::TypeReference(_) => {
                 unreachable!("TypeReference should be converted to constructors")
             }
             Expression::MemberReference(m) => m.lower_to_ir_without_load(context),
             Expression::Constructor(c) => Some(c.to_ir(context).into()),
             Expression::ImplicitConversion(i) => i.lower_to_ir_without_load(context),
+            Expression::Loop(l) => l.to_ir(context),
         }
     }
 }
@@ -684,7 +1559,7 @@ impl<'llvm, 'm> ToIR<'llvm, FunctionContext<'llvm, 'm, '_>> for Expression {
                 if cl.is_opaque()
                     && !(cl.is_none()
                         || cl.is_bool()
-                        || cl.is_i32()
+                        || cl.is_fixed_width_integer()
                         || cl.is_f64()
                         || self.is_reference())
                 {
@@ -693,6 +1568,10 @@ impl<'llvm, 'm> ToIR<'llvm, FunctionContext<'llvm, 'm, '_>> for Expression {
                 let ty = cl.to_ir(context).try_into_basic_type().unwrap();
                 return Some(context.builder.build_load(ty, ptr, "").unwrap());
             }
+            Type::Trait(_) => {
+                let ty = context.types().trait_object();
+                return Some(context.builder.build_load(ty, ptr, "").unwrap());
+            }
             ty if ty.is_generic() => unreachable!("Loading reference of generic type `{ty}`"),
             ty => unimplemented!("Load reference of type `{ty}`"),
         };
@@ -768,6 +1647,7 @@ impl<'llvm> ToIR<'llvm, ModuleContext<'llvm, '_>> for Statement {
                 context.load_return_value_and_branch(value);
             }
             Statement::Return(_) => unreachable!("Return statement is not allowed in global scope"),
+            Statement::Break(_) => unreachable!("Break statement is not allowed outside of loop"),
             Statement::Use(_) => {
                 // Use statements are skipped
             }
@@ -801,8 +1681,11 @@ impl<'llvm, 'm> ToIR<'llvm, FunctionContext<'llvm, 'm, '_>> for Statement {
             Statement::If(if_stmt) => {
                 if_stmt.to_ir(context);
             }
-            Statement::Loop(loop_stmt) => loop_stmt.to_ir(context),
+            Statement::Loop(loop_stmt) => {
+                loop_stmt.to_ir(context);
+            }
             Statement::While(while_stmt) => while_stmt.to_ir(context),
+            Statement::Break(break_stmt) => break_stmt.to_ir(context),
             Statement::Use(_) => {
                 // Use statements are skipped
             }
@@ -913,26 +1796,57 @@ impl<'llvm, 'm> ToIR<'llvm, FunctionContext<'llvm, 'm, '_>> for If {
 }
 
 impl<'llvm, 'm> ToIR<'llvm, FunctionContext<'llvm, 'm, '_>> for Loop {
-    type IR = ();
+    type IR = Option<inkwell::values::BasicValueEnum<'llvm>>;
 
-    /// Lower [`Loop`] to LLVM IR
+    /// Lower [`Loop`] to LLVM IR.
+    ///
+    /// Returns value, passed out of loop by `break`, if any
     fn to_ir(&self, context: &mut FunctionContext<'llvm, 'm, '_>) -> Self::IR {
         trace!(target: "to_ir", "{self}");
 
+        let ty = (!self.ty.is_none()).then(|| {
+            self.ty
+                .to_ir(context)
+                .try_into_basic_type()
+                .expect("non-basic type loop value")
+        });
+        let value = ty.map(|ty| context.builder.build_alloca(ty, "loop.value").unwrap());
+
+        // Created before body to not be treated as the last block of the loop
+        let end_block = context
+            .llvm()
+            .append_basic_block(context.function, "loop.end");
+        context.loop_exits.push(LoopExit {
+            block: end_block,
+            value,
+        });
         let loop_block = context.build_block("loop", &self.body, None);
+        context.loop_exits.pop();
 
         context
             .builder
             .build_unconditional_branch(loop_block)
             .unwrap();
 
-        if loop_block.get_terminator().is_none() {
-            context.builder.position_at_end(loop_block);
+        let last_block = context.function.get_last_basic_block().unwrap();
+        if last_block.get_terminator().is_none() {
+            context.builder.position_at_end(last_block);
             context
                 .builder
                 .build_unconditional_branch(loop_block)
                 .unwrap();
         }
+
+        if end_block.get_first_use().is_none() {
+            // Loop without breaks never ends
+            unsafe { end_block.delete() }.unwrap();
+            return None;
+        }
+
+        end_block.move_after(last_block).unwrap();
+        context.builder.position_at_end(end_block);
+        ty.zip(value)
+            .map(|(ty, ptr)| context.builder.build_load(ty, ptr, "").unwrap())
     }
 }
 
@@ -952,9 +1866,17 @@ impl<'llvm, 'm> ToIR<'llvm, FunctionContext<'llvm, 'm, '_>> for While {
             .build_unconditional_branch(condition_block)
             .unwrap();
 
+        let merge_block = context.llvm().append_basic_block(context.function, "");
+
+        context.loop_exits.push(LoopExit {
+            block: merge_block,
+            value: None,
+        });
         let loop_block = context.build_block("while.body", &self.body, Some(condition_block));
+        context.loop_exits.pop();
 
-        let merge_block = context.llvm().append_basic_block(context.function, "");
+        let last_block = context.function.get_last_basic_block().unwrap();
+        merge_block.move_after(last_block).unwrap();
 
         context.builder.position_at_end(condition_block);
         let condition = self.condition.to_ir(context).unwrap().into_int_value();
@@ -967,6 +1889,24 @@ impl<'llvm, 'm> ToIR<'llvm, FunctionContext<'llvm, 'm, '_>> for While {
     }
 }
 
+impl<'llvm, 'm> ToIR<'llvm, FunctionContext<'llvm, 'm, '_>> for Break {
+    type IR = ();
+
+    /// Lower [`Break`] to LLVM IR
+    fn to_ir(&self, context: &mut FunctionContext<'llvm, 'm, '_>) -> Self::IR {
+        trace!(target: "to_ir", "{self}");
+
+        let value = self.value.as_ref().and_then(|expr| expr.to_ir(context));
+
+        let exit = context.loop_exits.last().expect("Break outside of loop");
+        let (block, ptr) = (exit.block, exit.value);
+        if let (Some(ptr), Some(value)) = (ptr, value) {
+            context.builder.build_store(ptr, value).unwrap();
+        }
+        context.builder.build_unconditional_branch(block).unwrap();
+    }
+}
+
 impl<'llvm, 'm> ToIR<'llvm, FunctionContext<'llvm, 'm, '_>> for Initializer<'llvm> {
     type IR = CallSiteValue<'llvm>;
 
@@ -983,6 +1923,7 @@ pub trait HIRModuleLowering<'llvm> {
         &self,
         llvm: &'llvm inkwell::context::Context,
         with_main: bool,
+        debug: bool,
         compilation_module: compilation::Module,
     ) -> inkwell::module::Module<'llvm>;
 }
@@ -993,16 +1934,19 @@ impl<'llvm> HIRModuleLowering<'llvm> for ModuleData {
         &self,
         llvm: &'llvm inkwell::context::Context,
         with_main: bool,
+        debug: bool,
         compilation_module: compilation::Module,
     ) -> inkwell::module::Module<'llvm> {
         trace!(target: "lower_to_ir", "{self}");
 
+        let _compiling = CompilingFile::new(self.source_file.path());
+
         let name = self.name();
 
         let module = llvm.create_module(&name);
         module.set_source_file_name(&self.source_file.path().to_string_lossy());
 
-        let mut context = ModuleContext::new(compilation_module, module, self.source_file());
+        let mut context = ModuleContext::new(compilation_module, module, self.source_file(), debug);
 
         // First emit special variables with type info
         for variable in self
@@ -1021,6 +1965,8 @@ impl<'llvm> HIRModuleLowering<'llvm> for ModuleData {
         let at = self.statements.first().map(|s| s.start()).unwrap_or(0);
 
         FunctionContext::new(&mut context, execute, at).run(|context| {
+            context.record_frame(&name, at);
+
             for init in context.module_context.initializers.clone() {
                 init.to_ir(context);
             }
@@ -1041,7 +1987,13 @@ impl<'llvm> HIRModuleLowering<'llvm> for ModuleData {
         if with_main {
             let main = context.module.add_function(
                 "main",
-                context.types().i32().fn_type(&[], false),
+                context.types().i32().fn_type(
+                    &[
+                        context.types().i32().into(),
+                        context.types().pointer().into(),
+                    ],
+                    false,
+                ),
                 None,
             );
             FunctionContext::new(&mut context, main, at).run(|context| {
@@ -1054,6 +2006,18 @@ impl<'llvm> HIRModuleLowering<'llvm> for ModuleData {
                     )
                     .unwrap();
 
+                // Remember arguments for `program arguments`
+                let argc = main.get_nth_param(0).unwrap();
+                let argv = main.get_nth_param(1).unwrap();
+                context
+                    .builder
+                    .build_call(
+                        context.functions().set_program_arguments(),
+                        &[argc.into(), argv.into()],
+                        "",
+                    )
+                    .unwrap();
+
                 // Call execute
                 context.set_debug_location(at);
                 context.builder.build_call(execute, &[], "").unwrap();
@@ -1071,3 +2035,157 @@ impl<'llvm> HIRModuleLowering<'llvm> for ModuleData {
         module
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{
+        ast,
+        hir::test::class,
+        ir::test::with_contexts,
+        semantics::{Context as _, Declare},
+    };
+
+    #[test]
+    fn field_index_skips_none() {
+        with_contexts(|semantics, context| {
+            let types = semantics.builtin().types();
+
+            // type Point: x: Integer, nothing: None, y: Integer
+            let point = class("Point")
+                .member("x", types.integer())
+                .member("nothing", types.none())
+                .member("y", types.integer())
+                .data();
+
+            let indices: Vec<_> = (0..point.members.len())
+                .map(|i| field_index(&point, i, context))
+                .collect();
+            assert_eq!(indices, vec![Some(0), None, Some(1)]);
+
+            let ty = point.to_ir(context).into_struct_type();
+            assert_eq!(ty.count_fields(), 2);
+        });
+    }
+    #[test]
+    fn fixed_width_division_checks() {
+        with_contexts(|semantics, context| {
+            let f: ast::FunctionDeclaration =
+                "fn <x: I64> over <y: I64> => x div y".parse().unwrap();
+            let declaration = f.declare(semantics).unwrap();
+            let f = f.define(declaration, semantics).unwrap();
+            let f = f.read().unwrap();
+            f.declare_global(context);
+            f.emit_body(context);
+
+            let ir = context.module.print_to_string().to_string();
+            assert!(ir.contains("division by zero"), "{ir}");
+            assert!(ir.contains("division overflow"), "{ir}");
+            assert!(ir.contains("call void @panic_at"), "{ir}");
+            assert!(ir.contains("sdiv i64"), "{ir}");
+        });
+    }
+
+    #[test]
+    fn bitwise_operators_are_inlined() {
+        with_contexts(|semantics, context| {
+            let f: ast::FunctionDeclaration =
+                "fn <x: I32> mixed with <y: I32> => ((x & y) | (x xor y)) << (y >> x)"
+                    .parse()
+                    .unwrap();
+            let declaration = f.declare(semantics).unwrap();
+            let f = f.define(declaration, semantics).unwrap();
+            let f = f.read().unwrap();
+            f.declare_global(context);
+            f.emit_body(context);
+
+            let ir = context.module.print_to_string().to_string();
+            for instruction in ["and i32", "or i32", "xor i32", "shl i32", "ashr i32"] {
+                assert!(ir.contains(instruction), "{ir}");
+            }
+            assert!(!ir.contains("call"), "{ir}");
+        });
+    }
+
+    #[test]
+    fn unsigned_division_checks_only_zero() {
+        with_contexts(|semantics, context| {
+            let f: ast::FunctionDeclaration =
+                "fn <x: U64> over <y: U64> => x rem y".parse().unwrap();
+            let declaration = f.declare(semantics).unwrap();
+            let f = f.define(declaration, semantics).unwrap();
+            let f = f.read().unwrap();
+            f.declare_global(context);
+            f.emit_body(context);
+
+            let ir = context.module.print_to_string().to_string();
+            assert!(ir.contains("division by zero"), "{ir}");
+            assert!(!ir.contains("division overflow"), "{ir}");
+            assert!(ir.contains("urem i64"), "{ir}");
+        });
+    }
+
+    #[test]
+    fn debug_builds_record_frames() {
+        for debug in [false, true] {
+            with_contexts(|semantics, context| {
+                context.debug = debug;
+
+                let f: ast::FunctionDeclaration = "fn answer => 42".parse().unwrap();
+                let declaration = f.declare(semantics).unwrap();
+                let f = f.define(declaration, semantics).unwrap();
+                let f = f.read().unwrap();
+                f.declare_global(context);
+                f.emit_body(context);
+
+                let ir = context.module.print_to_string().to_string();
+                assert_eq!(ir.contains("call void @push_frame"), debug, "{ir}");
+                assert_eq!(ir.contains("call void @pop_frame"), debug, "{ir}");
+            });
+        }
+    }
+
+    #[test]
+    fn inline_hints() {
+        for (annotation, attribute) in [("@inline", "alwaysinline"), ("@noinline", "noinline")] {
+            with_contexts(|semantics, context| {
+                let statement: ast::Statement =
+                    format!("{annotation}\nfn answer => 42").parse().unwrap();
+                let ast::Statement::Declaration(ast::Declaration::Function(f)) = statement else {
+                    unreachable!("not a function declaration");
+                };
+                let declaration = f.declare(semantics).unwrap();
+                let f = f.define(declaration, semantics).unwrap();
+                f.read().unwrap().declare_global(context);
+
+                let ir = context.module.print_to_string().to_string();
+                assert!(ir.contains(attribute), "{ir}");
+            });
+        }
+    }
+
+    #[test]
+    fn refcounting() {
+        with_contexts(|semantics, context| {
+            for source in [
+                "fn duplicate <x: &Integer> => clone x",
+                "fn forget <x: &mut Integer> => destroy x",
+            ] {
+                let f: ast::FunctionDeclaration = source.parse().unwrap();
+                let declaration = f.declare(semantics).unwrap();
+                let f = f.define(declaration, semantics).unwrap();
+                let f = f.read().unwrap();
+                f.declare_global(context);
+                f.emit_body(context);
+            }
+
+            let ir = context.module.print_to_string().to_string();
+            assert!(ir.contains("call ptr @rc_retain"), "{ir}");
+            assert!(ir.contains("call void @rc_release"), "{ir}");
+            assert!(!ir.contains("@clone_integer"), "{ir}");
+            assert!(!ir.contains("@destroy_integer"), "{ir}");
+        });
+    }
+}
@@ -107,6 +107,18 @@ impl<'llvm> Types<'llvm> {
         self.with_impl("String")
     }
 
+    /// LLVM IR for [`Trait`](Type::Trait) object.
+    /// Consists of pointer to data and pointer to virtual table
+    pub fn trait_object(&self) -> StructType<'llvm> {
+        if let Some(ty) = self.llvm.get_struct_type("TraitObject") {
+            return ty;
+        }
+
+        let ty = self.llvm.opaque_struct_type("TraitObject");
+        ty.set_body(&[self.pointer().into(), self.pointer().into()], false);
+        ty
+    }
+
     /// LLVM IR for C string type
     pub fn c_string(&self) -> PointerType<'llvm> {
         self.pointer()
@@ -0,0 +1,458 @@
+use inkwell::{
+    basic_block::BasicBlock,
+    module::Module,
+    types::BasicTypeEnum,
+    values::{BasicValue, BasicValueEnum, FunctionValue, InstructionOpcode, InstructionValue},
+};
+use miette::Diagnostic;
+use thiserror::Error;
+
+/// Diagnostic for values, that are not destroyed on some paths
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq)]
+#[error("`{variable}` in `{function}` is not destroyed on some paths")]
+#[diagnostic(code(ir::value_not_destroyed))]
+pub struct ValueNotDestroyed {
+    /// Name of function
+    pub function: String,
+    /// Name of variable
+    pub variable: String,
+}
+
+/// Diagnostic for values, that may be destroyed twice
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq)]
+#[error("`{variable}` in `{function}` may be destroyed twice")]
+#[diagnostic(code(ir::value_destroyed_twice))]
+pub struct ValueDestroyedTwice {
+    /// Name of function
+    pub function: String,
+    /// Name of variable
+    pub variable: String,
+}
+
+/// Errors found by destructors verification
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq)]
+pub enum DestructorError {
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    ValueNotDestroyed(#[from] ValueNotDestroyed),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    ValueDestroyedTwice(#[from] ValueDestroyedTwice),
+}
+
+/// Event, that changes state of local variable
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Event {
+    /// Value is stored to variable
+    Initialize,
+    /// Variable is passed to destructor
+    Destroy,
+}
+
+/// Check that every runtime value, stored in local variable,
+/// is destroyed exactly once on all paths through function.
+///
+/// Values, that escape the variable (e.g. are returned or stored elsewhere),
+/// are not checked
+pub fn verify_destructors(module: &Module) -> Vec<DestructorError> {
+    let destructible = destructible_types(module);
+
+    module
+        .get_functions()
+        .filter(|f| f.count_basic_blocks() > 0)
+        .flat_map(|f| verify_function(f, &destructible))
+        .collect()
+}
+
+/// Check destructors of a single function
+fn verify_function<'llvm>(
+    function: FunctionValue<'llvm>,
+    destructible: &[BasicTypeEnum<'llvm>],
+) -> Vec<DestructorError> {
+    let name = function.get_name().to_string_lossy().to_string();
+
+    let mut errors = vec![];
+    for variable in instructions(function)
+        .filter(|i| i.get_opcode() == InstructionOpcode::Alloca)
+        .filter(|i| {
+            i.get_allocated_type()
+                .is_ok_and(|ty| destructible.contains(&ty))
+        })
+    {
+        if escapes(function, variable) {
+            continue;
+        }
+
+        let events = events(function, variable);
+        let variable_name = variable
+            .get_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        for (block, index, event) in events.iter().copied() {
+            match event {
+                Event::Initialize => {
+                    if !always_followed_by(&events, block, index, Event::Destroy) {
+                        errors.push(
+                            ValueNotDestroyed {
+                                function: name.clone(),
+                                variable: variable_name.clone(),
+                            }
+                            .into(),
+                        );
+                        break;
+                    }
+                }
+                Event::Destroy => {
+                    if !never_followed_by(&events, block, index, Event::Destroy) {
+                        errors.push(
+                            ValueDestroyedTwice {
+                                function: name.clone(),
+                                variable: variable_name.clone(),
+                            }
+                            .into(),
+                        );
+                        break;
+                    }
+                }
+            }
+        }
+    }
+    errors
+}
+
+/// Types of local variables, that are passed to destructors somewhere in module
+fn destructible_types<'llvm>(module: &Module<'llvm>) -> Vec<BasicTypeEnum<'llvm>> {
+    let mut types = vec![];
+    for function in module.get_functions() {
+        for call in instructions(function).filter(|i| is_destructor_call(*i)) {
+            let Some(ty) = destroyed(call)
+                .and_then(|arg| arg.as_instruction_value())
+                .filter(|arg| arg.get_opcode() == InstructionOpcode::Alloca)
+                .and_then(|arg| arg.get_allocated_type().ok())
+            else {
+                continue;
+            };
+            // Only named structs identify runtime types unambiguously
+            if let BasicTypeEnum::StructType(s) = ty
+                && s.get_name().is_some()
+                && !types.contains(&ty)
+            {
+                types.push(ty);
+            }
+        }
+    }
+    types
+}
+
+/// Iterate over all instructions of function
+fn instructions<'llvm>(
+    function: FunctionValue<'llvm>,
+) -> impl Iterator<Item = InstructionValue<'llvm>> {
+    function
+        .get_basic_blocks()
+        .into_iter()
+        .flat_map(block_instructions)
+}
+
+/// Iterate over instructions of basic block
+fn block_instructions<'llvm>(
+    block: BasicBlock<'llvm>,
+) -> impl Iterator<Item = InstructionValue<'llvm>> {
+    std::iter::successors(block.get_first_instruction(), |i| i.get_next_instruction())
+}
+
+/// Get name of called function, if instruction is a call
+fn callee_name(call: InstructionValue) -> Option<String> {
+    if call.get_opcode() != InstructionOpcode::Call {
+        return None;
+    }
+
+    let callee = call
+        .get_operand(call.get_num_operands() - 1)
+        .and_then(|op| op.left())?;
+    match callee {
+        BasicValueEnum::PointerValue(p) => Some(p.get_name().to_string_lossy().to_string()),
+        _ => None,
+    }
+}
+
+/// Is this instruction a call to destructor?
+fn is_destructor_call(call: InstructionValue) -> bool {
+    callee_name(call).is_some_and(|name| name.starts_with("destroy") || name == "rc_release")
+}
+
+/// Get the first argument of call
+fn argument<'llvm>(call: InstructionValue<'llvm>) -> Option<BasicValueEnum<'llvm>> {
+    call.get_operand(0).and_then(|op| op.left())
+}
+
+/// Get pointer to value, destroyed by destructor call
+fn destroyed<'llvm>(call: InstructionValue<'llvm>) -> Option<BasicValueEnum<'llvm>> {
+    let argument = argument(call)?;
+    if callee_name(call).is_none_or(|name| name != "rc_release") {
+        return Some(argument);
+    }
+
+    // Box of builtin value is loaded from the value itself
+    argument
+        .as_instruction_value()
+        .filter(|arg| arg.get_opcode() == InstructionOpcode::Load)
+        .and_then(|arg| arg.get_operand(0))
+        .and_then(|op| op.left())
+}
+
+/// Is this value a pointer to variable?
+fn is_variable(value: Option<BasicValueEnum>, variable: InstructionValue) -> bool {
+    value.and_then(|v| v.as_instruction_value()) == Some(variable)
+}
+
+/// Is this value the variable, or loaded from it?
+fn is_value_of(value: BasicValueEnum, variable: InstructionValue) -> bool {
+    let Some(instruction) = value.as_instruction_value() else {
+        return false;
+    };
+    instruction == variable
+        || (instruction.get_opcode() == InstructionOpcode::Load
+            && is_variable(
+                instruction.get_operand(0).and_then(|op| op.left()),
+                variable,
+            ))
+}
+
+/// Does variable or its value escape the function, so its destruction can't be checked?
+fn escapes(function: FunctionValue, variable: InstructionValue) -> bool {
+    instructions(function)
+        .filter(|i| {
+            matches!(
+                i.get_opcode(),
+                InstructionOpcode::Store | InstructionOpcode::Return
+            )
+        })
+        .filter_map(|i| i.get_operand(0).and_then(|op| op.left()))
+        .any(|value| is_value_of(value, variable))
+}
+
+/// Events of variable in order of instructions for each block
+fn events<'llvm>(
+    function: FunctionValue<'llvm>,
+    variable: InstructionValue<'llvm>,
+) -> Vec<(BasicBlock<'llvm>, usize, Event)> {
+    let mut events = vec![];
+    for block in function.get_basic_blocks() {
+        for (index, i) in block_instructions(block).enumerate() {
+            let event = match i.get_opcode() {
+                InstructionOpcode::Store
+                    if is_variable(i.get_operand(1).and_then(|op| op.left()), variable) =>
+                {
+                    Event::Initialize
+                }
+                InstructionOpcode::Call
+                    if is_destructor_call(i) && is_variable(destroyed(i), variable) =>
+                {
+                    Event::Destroy
+                }
+                _ => continue,
+            };
+            events.push((block, index, event));
+        }
+    }
+    events
+}
+
+/// Get the first event after instruction with `index` in `block`, if any
+fn next_event(
+    events: &[(BasicBlock, usize, Event)],
+    block: BasicBlock,
+    index: Option<usize>,
+) -> Option<Event> {
+    events
+        .iter()
+        .filter(|(b, i, _)| *b == block && index.is_none_or(|index| *i > index))
+        .min_by_key(|(_, i, _)| *i)
+        .map(|(_, _, event)| *event)
+}
+
+/// Get successors of block
+fn successors(block: BasicBlock) -> Vec<BasicBlock> {
+    let Some(terminator) = block.get_terminator() else {
+        return vec![];
+    };
+    (0..terminator.get_num_operands())
+        .filter_map(|i| terminator.get_operand(i).and_then(|op| op.right()))
+        .collect()
+}
+
+/// Get the first event on each path from instruction with `index` in `block`.
+/// `None` is returned for paths, that reach function's exit without events
+fn first_events_on_paths(
+    events: &[(BasicBlock, usize, Event)],
+    block: BasicBlock,
+    index: usize,
+) -> Vec<Option<Event>> {
+    if let Some(event) = next_event(events, block, Some(index)) {
+        return vec![Some(event)];
+    }
+
+    let mut result = vec![];
+    let mut visited = vec![];
+    let mut queue = successors(block);
+    while let Some(block) = queue.pop() {
+        if visited.contains(&block) {
+            continue;
+        }
+        visited.push(block);
+
+        if let Some(event) = next_event(events, block, None) {
+            result.push(Some(event));
+            continue;
+        }
+
+        let next = successors(block);
+        if next.is_empty() {
+            result.push(None);
+        }
+        queue.extend(next);
+    }
+    result
+}
+
+/// Is instruction followed by `event` on all paths?
+fn always_followed_by(
+    events: &[(BasicBlock, usize, Event)],
+    block: BasicBlock,
+    index: usize,
+    event: Event,
+) -> bool {
+    first_events_on_paths(events, block, index)
+        .into_iter()
+        .all(|e| e == Some(event))
+}
+
+/// Is instruction not followed by `event` on any path?
+fn never_followed_by(
+    events: &[(BasicBlock, usize, Event)],
+    block: BasicBlock,
+    index: usize,
+    event: Event,
+) -> bool {
+    first_events_on_paths(events, block, index)
+        .into_iter()
+        .all(|e| e != Some(event))
+}
+
+#[cfg(test)]
+mod tests {
+    use inkwell::{context::Context, memory_buffer::MemoryBuffer};
+
+    use super::*;
+
+    /// Verify destructors in module, parsed from LLVM IR
+    fn verify(ir: &str) -> Vec<DestructorError> {
+        let llvm = Context::create();
+        let buffer = MemoryBuffer::create_from_memory_range_copy(ir.as_bytes(), "test");
+        let module = llvm.create_module_from_ir(buffer).unwrap();
+        verify_destructors(&module)
+    }
+
+    const DECLARATIONS: &str = "
+%Integer = type { ptr }
+
+declare %Integer @integer_from_i64(i64)
+
+declare void @destroy_integer(ptr)
+
+declare void @rc_release(ptr)
+";
+
+    #[test]
+    fn destroyed_once() {
+        let ir = format!(
+            "{DECLARATIONS}
+define void @f(i1 %c) {{
+  %x = alloca %Integer, align 8
+  %1 = call %Integer @integer_from_i64(i64 1)
+  store %Integer %1, ptr %x, align 8
+  br i1 %c, label %then, label %else
+
+then:
+  call void @destroy_integer(ptr %x)
+  br label %return
+
+else:
+  call void @destroy_integer(ptr %x)
+  br label %return
+
+return:
+  ret void
+}}"
+        );
+        assert_eq!(verify(&ir), vec![]);
+    }
+
+    #[test]
+    fn not_destroyed_on_some_paths() {
+        let ir = format!(
+            "{DECLARATIONS}
+define void @f(i1 %c) {{
+  %x = alloca %Integer, align 8
+  %1 = call %Integer @integer_from_i64(i64 1)
+  store %Integer %1, ptr %x, align 8
+  br i1 %c, label %then, label %return
+
+then:
+  call void @destroy_integer(ptr %x)
+  br label %return
+
+return:
+  ret void
+}}"
+        );
+        assert_eq!(
+            verify(&ir),
+            vec![ValueNotDestroyed {
+                function: "f".to_string(),
+                variable: "x".to_string(),
+            }
+            .into()]
+        );
+    }
+
+    #[test]
+    fn destroyed_twice() {
+        let ir = format!(
+            "{DECLARATIONS}
+define void @f() {{
+  %x = alloca %Integer, align 8
+  %1 = call %Integer @integer_from_i64(i64 1)
+  store %Integer %1, ptr %x, align 8
+  call void @destroy_integer(ptr %x)
+  call void @destroy_integer(ptr %x)
+  ret void
+}}"
+        );
+        assert_eq!(
+            verify(&ir),
+            vec![ValueDestroyedTwice {
+                function: "f".to_string(),
+                variable: "x".to_string(),
+            }
+            .into()]
+        );
+    }
+
+    #[test]
+    fn released_once() {
+        let ir = format!(
+            "{DECLARATIONS}
+define void @f() {{
+  %x = alloca %Integer, align 8
+  %1 = call %Integer @integer_from_i64(i64 1)
+  store %Integer %1, ptr %x, align 8
+  %2 = load ptr, ptr %x, align 8
+  call void @rc_release(ptr %2)
+  ret void
+}}"
+        );
+        assert_eq!(verify(&ir), vec![]);
+    }
+}
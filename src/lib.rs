@@ -36,6 +36,9 @@ pub(crate) mod e2e;
 mod reporter;
 pub use reporter::*;
 
+mod fix_it;
+pub use fix_it::*;
+
 mod err_vec;
 pub use err_vec::*;
 
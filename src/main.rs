@@ -1,46 +1,93 @@
 #![feature(anonymous_lifetime_in_impl_trait)]
 
 use std::cell::Cell;
+use std::collections::HashMap;
 use std::io::Write;
 use std::path::Path;
 
 use clap::Parser;
-use inkwell::OptimizationLevel;
+use inkwell::values::FunctionValue;
 use log::debug;
 use miette::NamedSource;
 use ppl::compilation::Compiler;
 use ppl::driver::commands::compile::OutputType;
 use ppl::driver::{self, commands, Execute};
 use ppl::hir;
-use ppl::ir::HIRModuleLowering;
+use ppl::ir::{optimization_level, HIRModuleLowering};
 use ppl::semantics::{Context, ModuleContext, Monomorphize, ToHIR};
-use ppl::syntax::{InteractiveLexer, Lexer, Parse};
+use ppl::syntax::{FullSourceLexer, InteractiveLexer, Lexer, Parse, Ranged};
 use ppl::Reporter;
-use ppl::{ast::*, SourceFile};
+use ppl::{ast::*, SourceFile, StatementSource};
 use tempdir::TempDir;
 
 extern crate runtime;
 
+/// Compiled `main` functions of previously entered statements, keyed by their tokens
+type StatementCache<'llvm> = HashMap<Vec<String>, FunctionValue<'llvm>>;
+
+/// Key of statement in cache.
+/// Statements are the same, if they consist of the same tokens
+fn cache_key(source: &str) -> Vec<String> {
+    let mut lexer = FullSourceLexer::new(source);
+    let mut key = vec![];
+    while lexer.next().is_some() {
+        key.push(lexer.slice().to_string());
+    }
+    key
+}
+
 /// Parse and compile single statement
 fn process_single_statement<'llvm>(
     parse_context: &mut ppl::syntax::Context<impl Lexer>,
     ast_lowering_context: &mut ModuleContext,
-    llvm: &inkwell::context::Context,
+    llvm: &'llvm inkwell::context::Context,
     engine: &mut inkwell::execution_engine::ExecutionEngine<'llvm>,
+    cache: &mut StatementCache<'llvm>,
 ) -> miette::Result<()> {
+    let start = parse_context.lexer.span().end;
     let ast = Statement::parse(parse_context)?;
     debug!(target: "ast", "{:#?}", ast);
 
-    let mut hir = ast.to_hir(ast_lowering_context)?;
+    // Declarations may change meaning of cached statements
+    let declares = matches!(
+        ast,
+        Statement::Declaration(_) | Statement::Destructuring(_) | Statement::Use(_)
+    );
+    if declares {
+        cache.clear();
+    }
+
+    let source = &parse_context.lexer.source()[ast.range()];
+    let key = cache_key(source);
+    if let Some(main) = cache.get(&key) {
+        debug!(target: "repl-cache", "reusing compiled `{source}`");
+        unsafe { engine.run_function_as_main(*main, &[]) };
+        return Ok(());
+    }
+
+    let hir = ast.to_hir(ast_lowering_context);
+    for warning in ast_lowering_context.compiler_mut().warnings.drain(..) {
+        println!(
+            "{:?}",
+            warning.with_source_code(StatementSource::new(
+                "stdin",
+                parse_context.lexer.source(),
+                start
+            ))
+        );
+    }
+    let mut hir = hir?;
     hir.monomorphize(ast_lowering_context);
     debug!(target: "hir", "{:#}", hir);
 
     ast_lowering_context.module.statements = vec![hir];
 
     let with_main = true;
+    let debug = false;
     let module = ast_lowering_context.module.to_ir(
         llvm,
         with_main,
+        debug,
         ast_lowering_context.compiler().current_module(),
     );
     debug!(target: "ir", "{}", module.to_string());
@@ -50,6 +97,9 @@ fn process_single_statement<'llvm>(
     engine.add_module(&module).unwrap();
 
     if let Some(f) = module.get_function("main") {
+        if !declares {
+            cache.insert(key, f);
+        }
         unsafe { engine.run_function_as_main(f, &[]) };
     }
 
@@ -57,7 +107,7 @@ fn process_single_statement<'llvm>(
 }
 
 /// Read-Evaluate-Print Loop
-fn repl() {
+fn repl(optimization: u8) {
     let mut compiler = Compiler::new();
     let mut ast_context = ModuleContext::new(
         hir::ModuleData::new(SourceFile::in_memory(NamedSource::new(
@@ -68,10 +118,9 @@ fn repl() {
     );
 
     let llvm = inkwell::context::Context::create();
-    /* TODO: settings (Optimization, etc) */
     let mut engine = llvm
         .create_module("")
-        .create_jit_execution_engine(OptimizationLevel::None)
+        .create_jit_execution_engine(optimization_level(optimization))
         .unwrap();
 
     let tmp = TempDir::new("ppl").unwrap();
@@ -81,7 +130,8 @@ fn repl() {
 
     commands::Build {
         output_dir: tmp.path().to_path_buf(),
-        output_type: None,
+        optimization,
+        ..Default::default()
     }
     .execute()
     .unwrap();
@@ -115,15 +165,22 @@ fn repl() {
     };
 
     let mut parse_context = ppl::syntax::Context::new(InteractiveLexer::new(get_line));
+    let mut cache = StatementCache::new();
     loop {
-        if let Err(err) =
-            process_single_statement(&mut parse_context, &mut ast_context, &llvm, &mut engine)
-        {
+        let start = parse_context.lexer.span().end;
+        if let Err(err) = process_single_statement(
+            &mut parse_context,
+            &mut ast_context,
+            &llvm,
+            &mut engine,
+            &mut cache,
+        ) {
             println!(
                 "{:?}",
-                err.with_source_code(miette::NamedSource::new(
+                err.with_source_code(StatementSource::new(
                     "stdin",
-                    String::from(parse_context.lexer.source())
+                    parse_context.lexer.source(),
+                    start
                 ))
             );
             parse_context.lexer.go_to_end();
@@ -134,7 +191,7 @@ fn repl() {
 }
 
 fn main() -> miette::Result<()> {
-    miette::set_panic_hook();
+    driver::set_ice_hook();
     miette::set_hook(Box::new(|_| Box::new(Reporter::default())))?;
     pretty_env_logger::init();
 
@@ -142,7 +199,25 @@ fn main() -> miette::Result<()> {
     if let Some(cmd) = args.command {
         cmd.execute()
     } else {
-        repl();
+        repl(args.optimization);
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::cache_key;
+
+    #[test]
+    fn cache_key_ignores_spaces_between_tokens() {
+        assert_eq!(
+            cache_key("println  (1 +   2)"),
+            cache_key("println (1 + 2)")
+        );
+    }
+
+    #[test]
+    fn cache_key_keeps_string_literals() {
+        assert_ne!(cache_key("println \"a  b\""), cache_key("println \"a b\""));
+    }
+}
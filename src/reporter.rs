@@ -1,6 +1,13 @@
-use std::fmt::{self, Display};
+use std::{
+    fmt::{self, Display},
+    sync::{Arc, Mutex},
+};
 
-use miette::{Diagnostic, LabeledSpan, MietteHandler, ReportHandler, SourceCode};
+use miette::{
+    Diagnostic, LabeledSpan, MietteHandler, ReportHandler, Severity, SourceCode, SourceSpan,
+};
+
+use crate::FixIt;
 
 /// Struct to report errors
 pub struct Reporter;
@@ -43,6 +50,103 @@ impl ReportHandler for Reporter {
     }
 }
 
+/// Diagnostic, captured by [`MemoryReporter`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CapturedDiagnostic {
+    /// Code of diagnostic, like `semantics::no_function`
+    pub code: Option<String>,
+    /// Severity of diagnostic
+    pub severity: Severity,
+    /// Main message of diagnostic
+    pub message: String,
+    /// Spans of diagnostic with their labels
+    pub labels: Vec<(SourceSpan, Option<String>)>,
+    /// Suggested replacements of spans, that fix this diagnostic
+    pub fix_its: Vec<(SourceSpan, String)>,
+    /// Diagnostic, rendered the same way [`Reporter`] prints it
+    pub rendered: String,
+}
+
+impl CapturedDiagnostic {
+    /// Capture structure and rendering of diagnostic
+    fn new(diagnostic: &dyn Diagnostic) -> Self {
+        Self {
+            code: diagnostic.code().map(|code| code.to_string()),
+            severity: diagnostic.severity().unwrap_or(Severity::Error),
+            message: diagnostic.to_string(),
+            labels: diagnostic
+                .labels()
+                .into_iter()
+                .flatten()
+                .map(|label| (*label.inner(), label.label().map(str::to_string)))
+                .collect(),
+            fix_its: diagnostic
+                .related()
+                .into_iter()
+                .flatten()
+                .filter(|d| d.code().is_some_and(|code| code.to_string() == FixIt::CODE))
+                .flat_map(|d| d.labels().into_iter().flatten())
+                .map(|label| {
+                    (
+                        *label.inner(),
+                        label.label().unwrap_or_default().to_string(),
+                    )
+                })
+                .collect(),
+            rendered: format!("{:?}", Rendered(diagnostic)),
+        }
+    }
+}
+
+/// Reporter that collects diagnostics into memory instead of printing them.
+///
+/// Clones share the same storage
+#[derive(Debug, Default, Clone)]
+pub struct MemoryReporter {
+    diagnostics: Arc<Mutex<Vec<CapturedDiagnostic>>>,
+}
+
+impl MemoryReporter {
+    /// Capture diagnostic. Error vectors are captured as separate diagnostics
+    pub fn report(&self, diagnostic: &dyn Diagnostic) {
+        let mut diagnostics = self.diagnostics.lock().unwrap();
+        if diagnostic.to_string().is_empty()
+            && let Some(related) = diagnostic.related()
+        {
+            for e in related {
+                diagnostics.push(match diagnostic.source_code() {
+                    Some(source_code) => CapturedDiagnostic::new(&WithSourceCode {
+                        diagnostic: e,
+                        source_code,
+                    }),
+                    None => CapturedDiagnostic::new(e),
+                });
+            }
+        } else {
+            diagnostics.push(CapturedDiagnostic::new(diagnostic));
+        }
+    }
+
+    /// Get captured diagnostics
+    pub fn diagnostics(&self) -> Vec<CapturedDiagnostic> {
+        self.diagnostics.lock().unwrap().clone()
+    }
+
+    /// Take captured diagnostics, leaving reporter empty
+    pub fn take(&self) -> Vec<CapturedDiagnostic> {
+        std::mem::take(&mut *self.diagnostics.lock().unwrap())
+    }
+}
+
+/// Helper to render diagnostic with [`Reporter`]
+struct Rendered<'d>(&'d dyn Diagnostic);
+
+impl fmt::Debug for Rendered<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Reporter.debug(self.0, f)
+    }
+}
+
 struct WithSourceCode<'d, 's> {
     diagnostic: &'d dyn Diagnostic,
     source_code: &'s dyn SourceCode,
@@ -99,3 +203,42 @@ impl Diagnostic for WithSourceCode<'_, '_> {
         self.diagnostic.source_code().or(Some(self.source_code))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use miette::miette;
+
+    use super::*;
+
+    #[test]
+    fn memory_reporter() {
+        let reporter = MemoryReporter::default();
+        reporter.clone().report(miette!("something went wrong").as_ref());
+
+        let diagnostics = reporter.take();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, "something went wrong");
+        assert_eq!(diagnostics[0].severity, Severity::Error);
+        assert!(diagnostics[0].rendered.contains("something went wrong"));
+
+        assert!(reporter.diagnostics().is_empty());
+    }
+
+    #[test]
+    fn fix_its() {
+        #[derive(thiserror::Error, Diagnostic, Debug)]
+        #[error("missing `:`")]
+        struct MissingColon {
+            #[related]
+            fix_its: Vec<FixIt>,
+        }
+
+        let reporter = MemoryReporter::default();
+        reporter.report(&MissingColon {
+            fix_its: vec![FixIt::insert(":", 4)],
+        });
+
+        let diagnostics = reporter.take();
+        assert_eq!(diagnostics[0].fix_its, vec![(4.into(), ":".to_string())]);
+    }
+}
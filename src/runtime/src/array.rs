@@ -0,0 +1,185 @@
+use libc::{c_void, memcpy, realloc};
+
+use crate::{integer_from_u64, Integer, MemoryAddress, Type};
+
+/// Minimal capacity of non-empty array
+const MIN_CAPACITY: usize = 8;
+
+/// PPL's Array type.
+/// Layout of elements is known only to the compiler,
+/// so runtime works with raw bytes, using size of element type
+///
+/// # PPL
+/// ```no_run
+/// type Array<T>:
+///     size: Integer
+///     capacity: Integer
+///     data: MemoryAddress
+/// ```
+#[repr(C)]
+pub struct Array {
+    pub size: Integer,
+    pub capacity: Integer,
+    pub data: MemoryAddress,
+}
+
+impl Array {
+    /// Number of elements in array
+    pub fn size(&self) -> usize {
+        self.size.as_ref().to_usize().unwrap()
+    }
+
+    /// Number of elements array can hold without reallocation
+    pub fn capacity(&self) -> usize {
+        self.capacity.as_ref().to_usize().unwrap()
+    }
+
+    /// Pointer to the first element
+    pub fn data(&self) -> *mut u8 {
+        self.data.value.as_ref().to_u64().unwrap() as *mut u8
+    }
+
+    /// Pointer to the `i`-th element of `element_size` bytes
+    fn element(&self, i: usize, element_size: usize) -> *mut c_void {
+        unsafe { self.data().add(i * element_size) as *mut c_void }
+    }
+
+    /// Reference to the `i`-th element.
+    /// `T` must have the same layout as array's element type
+    pub fn get<T>(&self, i: usize) -> &T {
+        unsafe { &*(self.element(i, std::mem::size_of::<T>()) as *const T) }
+    }
+
+    /// Bounds-checked index of element
+    fn index(&self, i: &Integer) -> usize {
+        let size = self.size();
+        match i.as_ref().to_usize() {
+            Some(index) if index < size => index,
+            _ => panic!(
+                "index out of bounds: the size is {size} but the index is {}",
+                i.as_ref()
+            ),
+        }
+    }
+
+    /// Set number of elements in array
+    fn set_size(&mut self, size: usize) {
+        replace(&mut self.size, size as u64)
+    }
+
+    /// Ensure there is enough space for `additional` elements of `element_size` bytes
+    fn reserve(&mut self, additional: usize, element_size: usize) {
+        let required = self.size() + additional;
+        let capacity = self.capacity();
+        if required <= capacity {
+            return;
+        }
+
+        let new_capacity = required.max(capacity * 2).max(MIN_CAPACITY);
+        let bytes = new_capacity
+            .checked_mul(element_size)
+            .expect("array capacity overflow");
+        let data = unsafe { realloc(self.data() as *mut c_void, bytes) };
+        assert!(
+            !data.is_null(),
+            "failed to allocate {bytes} bytes for array"
+        );
+
+        replace(&mut self.data.value, data as u64);
+        replace(&mut self.capacity, new_capacity as u64);
+    }
+
+    /// Copy `element_size` bytes from `src` to the end of array
+    fn push_bytes(&mut self, src: *const c_void, element_size: usize) {
+        self.reserve(1, element_size);
+
+        let size = self.size();
+        unsafe { memcpy(self.element(size, element_size), src, element_size) };
+        self.set_size(size + 1);
+    }
+
+    /// Move value to the end of array.
+    /// `T` must have the same layout as array's element type
+    pub fn push<T>(&mut self, value: T) {
+        let value = std::mem::ManuallyDrop::new(value);
+        self.push_bytes(
+            &*value as *const T as *const c_void,
+            std::mem::size_of::<T>(),
+        );
+    }
+}
+
+/// Size of values of type in bytes
+fn element_size(ty: &Type) -> usize {
+    ty.size.as_ref().to_usize().unwrap()
+}
+
+/// Replace integer with new value, releasing the old one
+fn replace(integer: &mut Integer, value: u64) {
+    *integer = integer_from_u64(value);
+}
+
+/// # PPL
+/// ```no_run
+/// /// Number of elements in an array
+/// @mangle_as("array_length")
+/// fn<T> length of <array: &Array<T>> -> Integer
+/// ```
+#[no_mangle]
+pub extern "C" fn array_length(array: &Array) -> Integer {
+    integer_from_u64(array.size() as u64)
+}
+
+/// # PPL
+/// ```no_run
+/// /// Number of elements an array can hold without reallocation
+/// @mangle_as("array_capacity")
+/// fn<T> capacity of <array: &Array<T>> -> Integer
+/// ```
+#[no_mangle]
+pub extern "C" fn array_capacity(array: &Array) -> Integer {
+    integer_from_u64(array.capacity() as u64)
+}
+
+/// # PPL
+/// ```no_run
+/// /// Get reference to `i`-th element of an array.
+/// /// Panics, if index is out of bounds
+/// @mangle_as("array_get")
+/// fn<T> <ty: Type<T>> at <i: &Integer> in <array: &Array<T>> -> Reference<T>
+/// ```
+#[no_mangle]
+pub extern "C" fn array_get(ty: Type, i: &Integer, array: &Array) -> *mut c_void {
+    let index = array.index(i);
+    array.element(index, element_size(&ty))
+}
+
+/// # PPL
+/// ```no_run
+/// /// Move value of type `T` from `src` to the end of an array,
+/// /// growing it, if needed
+/// @mangle_as("array_push")
+/// fn<T> push <ty: Type<T>> from <src: &MemoryAddress> to <array: &mut Array<T>>
+/// ```
+#[no_mangle]
+pub extern "C" fn array_push(ty: Type, src: &MemoryAddress, array: &mut Array) {
+    let src = src.value.as_ref().to_u64().unwrap() as *const c_void;
+    array.push_bytes(src, element_size(&ty));
+}
+
+/// # PPL
+/// ```no_run
+/// /// Remove last element of an array, returning reference to its memory.
+/// /// Reference stays valid until next push.
+/// /// Panics, if array is empty
+/// @mangle_as("array_pop")
+/// fn<T> pop <ty: Type<T>> from <array: &mut Array<T>> -> Reference<T>
+/// ```
+#[no_mangle]
+pub extern "C" fn array_pop(ty: Type, array: &mut Array) -> *mut c_void {
+    let size = array.size();
+    assert!(size > 0, "pop from empty array");
+
+    array.set_size(size - 1);
+    array.element(size - 1, element_size(&ty))
+}
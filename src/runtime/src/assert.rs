@@ -7,9 +7,23 @@ use crate::String;
 /// ```
 #[no_mangle]
 pub extern "C" fn assert(condition: bool, message: &String) {
-    let message = unsafe { message.data.as_ref().unwrap() };
+    let message = message.as_ref();
     if !condition {
         println!("Assertion failed: {message}");
     }
     assert!(condition, "{message}");
 }
+
+/// # PPL
+/// ```no_run
+/// /// Panic, if condition doesn't hold
+/// @mangle_as("assert_condition")
+/// fn assert <condition: Bool>
+/// ```
+#[no_mangle]
+pub extern "C" fn assert_condition(condition: bool) {
+    if !condition {
+        println!("Assertion failed");
+    }
+    assert!(condition);
+}
@@ -0,0 +1,131 @@
+use std::{
+    cell::RefCell,
+    ffi::{c_char, CStr},
+    sync::Once,
+};
+
+use crate::{integer_from_u64, Integer, String};
+
+thread_local! {
+    /// Descriptions of PPL functions, that are being executed.
+    /// Innermost frame is the last one
+    static FRAMES: RefCell<Vec<*const c_char>> = RefCell::new(vec![]);
+}
+
+/// Guard to install panic hook only once
+static PANIC_HOOK: Once = Once::new();
+
+/// Descriptions of frames of current thread, starting from innermost one
+fn frames() -> Vec<std::string::String> {
+    FRAMES.with_borrow(|frames| {
+        frames
+            .iter()
+            .rev()
+            .map(|f| unsafe { CStr::from_ptr(*f) }.to_string_lossy().into_owned())
+            .collect()
+    })
+}
+
+/// Print PPL backtrace after default panic message
+fn install_panic_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        default_hook(info);
+
+        let frames = frames();
+        if frames.is_empty() {
+            return;
+        }
+
+        eprintln!("PPL backtrace:");
+        for (i, frame) in frames.iter().enumerate() {
+            eprintln!("{i:>4}: {frame}");
+        }
+    }));
+}
+
+/// Enter PPL function.
+/// Called at the start of every function in debug builds
+///
+/// `description` must be a static string, like `fn at main.ppl:1:1`
+#[no_mangle]
+pub extern "C" fn push_frame(description: *const c_char) {
+    PANIC_HOOK.call_once(install_panic_hook);
+    FRAMES.with_borrow_mut(|frames| frames.push(description));
+}
+
+/// Leave PPL function.
+/// Called before every return from function in debug builds
+#[no_mangle]
+pub extern "C" fn pop_frame() {
+    FRAMES.with_borrow_mut(|frames| frames.pop());
+}
+
+/// Abort with message of failed check, like division by zero,
+/// pointing at PPL code, that failed it.
+/// Called by code, that compiler emits for such checks
+///
+/// `message` and `location` must be static strings,
+/// like `division by zero` and `main.ppl:1:5`
+#[no_mangle]
+pub extern "C" fn panic_at(message: *const c_char, location: *const c_char) -> ! {
+    let message = unsafe { CStr::from_ptr(message) }.to_string_lossy();
+    let location = unsafe { CStr::from_ptr(location) }.to_string_lossy();
+    panic!("{message} at {location}")
+}
+
+/// # PPL
+/// ```no_run
+/// /// Number of frames in current backtrace
+/// @mangle_as("backtrace_depth")
+/// fn backtrace depth -> Integer
+/// ```
+#[no_mangle]
+pub extern "C" fn backtrace_depth() -> Integer {
+    integer_from_u64(FRAMES.with_borrow(|frames| frames.len()) as u64)
+}
+
+/// # PPL
+/// ```no_run
+/// /// Description of i-th frame of current backtrace, starting from innermost one
+/// @mangle_as("backtrace_frame")
+/// fn backtrace frame <:&Integer> -> String
+/// ```
+#[no_mangle]
+pub extern "C" fn backtrace_frame(i: &Integer) -> String {
+    let i = i.as_ref();
+    let frames = frames();
+    i.to_usize()
+        .and_then(|i| frames.get(i).cloned())
+        .expect(&format!(
+            "Frame `{i}` is out of bounds for backtrace of depth {}",
+            frames.len()
+        ))
+        .into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{backtrace_depth, backtrace_frame, pop_frame, push_frame};
+    use crate::integer_from_u64;
+
+    #[test]
+    fn frames() {
+        push_frame(c"execute at main.ppl:1:1".as_ptr());
+        push_frame(c"answer at main.ppl:3:1".as_ptr());
+        assert_eq!(*backtrace_depth().as_ref(), 2);
+        assert_eq!(
+            backtrace_frame(&integer_from_u64(0)).as_ref(),
+            "answer at main.ppl:3:1"
+        );
+        assert_eq!(
+            backtrace_frame(&integer_from_u64(1)).as_ref(),
+            "execute at main.ppl:1:1"
+        );
+
+        pop_frame();
+        assert_eq!(*backtrace_depth().as_ref(), 1);
+        pop_frame();
+        assert_eq!(*backtrace_depth().as_ref(), 0);
+    }
+}
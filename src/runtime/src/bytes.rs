@@ -0,0 +1,131 @@
+use crate::{integer_from_u64, Integer, String, Type};
+
+/// PPL's Bytes type.
+/// Wrapper around pointer to [`Vec<u8>`].
+///
+/// # PPL
+/// ```no_run
+/// type BytesImpl
+///
+/// type Bytes:
+///     impl: Reference<BytesImpl>
+/// ```
+#[repr(C)]
+pub struct Bytes {
+    pub data: *mut Vec<u8>,
+}
+
+impl Bytes {
+    /// Get the inner value
+    pub fn as_ref(&self) -> &Vec<u8> {
+        unsafe { &*self.data }
+    }
+
+    /// Get the inner value
+    pub fn as_mut(&mut self) -> &mut Vec<u8> {
+        unsafe { &mut *self.data }
+    }
+}
+
+impl From<Vec<u8>> for Bytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self {
+            data: Box::into_raw(Box::new(bytes)),
+        }
+    }
+}
+
+/// # PPL
+/// ```no_run
+/// @mangle_as("default_bytes")
+/// fn default <:Type<Bytes>> -> Bytes
+/// ```
+#[no_mangle]
+pub extern "C" fn default_bytes(_ty: Type) -> Bytes {
+    Vec::new().into()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Get UTF-8 bytes of a string
+/// @mangle_as("bytes_from_string")
+/// fn Bytes from <:&String> -> Bytes
+/// ```
+#[no_mangle]
+pub extern "C" fn bytes_from_string(str: &String) -> Bytes {
+    str.as_ref().as_bytes().to_vec().into()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Decode bytes as UTF-8 string.
+/// /// Invalid sequences are replaced with `�`
+/// @mangle_as("bytes_as_string")
+/// fn String from <:&Bytes> -> String
+/// ```
+#[no_mangle]
+pub extern "C" fn bytes_as_string(bytes: &Bytes) -> String {
+    std::string::String::from_utf8_lossy(bytes.as_ref())
+        .into_owned()
+        .into()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Append byte to the end of buffer
+/// @mangle_as("append_byte_to_bytes")
+/// fn append <:Byte> to <:&mut Bytes>
+/// ```
+#[no_mangle]
+pub extern "C" fn append_byte_to_bytes(byte: u8, bytes: &mut Bytes) {
+    bytes.as_mut().push(byte);
+}
+
+/// # PPL
+/// ```no_run
+/// /// Get i-th byte of buffer.
+/// /// Panics, if index is out of bounds
+/// @mangle_as("bytes_index_integer")
+/// fn <:&Bytes> [ <:&Integer> ] -> Byte
+/// ```
+#[no_mangle]
+pub extern "C" fn bytes_index_integer(bytes: &Bytes, i: &Integer) -> u8 {
+    let bytes = bytes.as_ref();
+    let i = i.as_ref();
+    i.to_usize()
+        .and_then(|i| bytes.get(i).copied())
+        .expect(&format!(
+            "Index `{i}` is out of bounds for bytes of size {}",
+            bytes.len()
+        ))
+}
+
+/// # PPL
+/// ```no_run
+/// /// Amount of bytes in buffer
+/// @mangle_as("bytes_size")
+/// fn <:&Bytes>.size -> Integer
+/// ```
+#[no_mangle]
+pub extern "C" fn bytes_size(bytes: &Bytes) -> Integer {
+    integer_from_u64(bytes.as_ref().len() as u64)
+}
+
+/// # PPL
+/// ```no_run
+/// fn destroy <:&mut Bytes>
+/// ```
+#[no_mangle]
+pub extern "C" fn destroy_bytes(x: &mut Bytes) {
+    let _ = unsafe { Box::from_raw(x.data) };
+}
+
+/// # PPL
+/// ```no_run
+/// @mangle_as("clone_bytes")
+/// fn clone <:&Bytes> -> Bytes
+/// ```
+#[no_mangle]
+pub extern "C" fn clone_bytes(x: &Bytes) -> Bytes {
+    x.as_ref().clone().into()
+}
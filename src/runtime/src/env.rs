@@ -1,4 +1,9 @@
-use crate::String;
+use std::{ffi::c_char, sync::OnceLock};
+
+use crate::{Array, String};
+
+/// Arguments, passed to the program
+static ARGUMENTS: OnceLock<Vec<std::string::String>> = OnceLock::new();
 
 /// # PPL
 /// ```no_run
@@ -9,3 +14,35 @@ use crate::String;
 pub extern "C" fn env(name: &String) -> String {
     std::env::var(name.as_ref()).unwrap_or_default().into()
 }
+
+/// Remember arguments of `main` for [`append_program_arguments`].
+/// Called by compiled `main` before executing the program
+#[no_mangle]
+pub extern "C" fn set_program_arguments(argc: i32, argv: *const *const c_char) {
+    let arguments = (0..usize::try_from(argc).unwrap_or(0))
+        .map(|i| unsafe { *argv.add(i) })
+        .take_while(|arg| !arg.is_null())
+        .map(|arg| {
+            unsafe { core::ffi::CStr::from_ptr(arg) }
+                .to_string_lossy()
+                .into_owned()
+        })
+        .collect();
+    let _ = ARGUMENTS.set(arguments);
+}
+
+/// # PPL
+/// ```no_run
+/// /// Append arguments, passed to the program, to `arguments`.
+/// /// The first one is the name of the program
+/// @mangle_as("append_program_arguments")
+/// fn append program arguments to <arguments: &mut Array<String>>
+/// ```
+#[no_mangle]
+pub extern "C" fn append_program_arguments(arguments: &mut Array) {
+    ARGUMENTS
+        .get()
+        .into_iter()
+        .flatten()
+        .for_each(|arg| arguments.push(String::from(arg.as_str())));
+}
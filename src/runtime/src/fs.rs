@@ -0,0 +1,76 @@
+use std::cell::RefCell;
+
+use crate::String;
+
+thread_local! {
+    /// Error of the last failed file operation in current thread.
+    /// Stands in for proper error handling, until PPL has one
+    static LAST_ERROR: RefCell<std::string::String> = const { RefCell::new(std::string::String::new()) };
+}
+
+/// Remember result of file operation, returning its value or default one
+fn record<T: Default>(result: std::io::Result<T>) -> T {
+    LAST_ERROR.with_borrow_mut(|error| {
+        error.clear();
+        if let Err(err) = &result {
+            *error = err.to_string();
+        }
+    });
+    result.unwrap_or_default()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Read whole file as UTF-8 text.
+/// /// On failure returns empty string and sets `last file error`
+/// @mangle_as("read_file")
+/// fn read file <path: &String> -> String
+/// ```
+#[no_mangle]
+pub extern "C" fn read_file(path: &String) -> String {
+    record(std::fs::read_to_string(path.as_ref())).into()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Write text to file, replacing its contents.
+/// /// Returns `false` and sets `last file error` on failure
+/// @mangle_as("write_to_file")
+/// fn write <text: &String> to file <path: &String> -> Bool
+/// ```
+#[no_mangle]
+pub extern "C" fn write_to_file(text: &String, path: &String) -> bool {
+    let result = std::fs::write(path.as_ref(), text.as_ref()).map(|_| true);
+    record(result)
+}
+
+/// # PPL
+/// ```no_run
+/// /// Description of error of the last failed file operation.
+/// /// Empty, if it succeeded
+/// @mangle_as("last_file_error")
+/// fn last file error -> String
+/// ```
+#[no_mangle]
+pub extern "C" fn last_file_error() -> String {
+    LAST_ERROR.with_borrow(|error| error.as_str().into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_and_read() {
+        let path = std::env::temp_dir().join("ppl_runtime_fs_test.txt");
+        let path: String = path.to_str().unwrap().into();
+
+        assert!(write_to_file(&"héllo".into(), &path));
+        assert_eq!(read_file(&path).as_ref(), "héllo");
+        assert_eq!(last_file_error().as_ref(), "");
+
+        std::fs::remove_file(path.as_ref()).unwrap();
+        assert_eq!(read_file(&path).as_ref(), "");
+        assert_ne!(last_file_error().as_ref(), "");
+    }
+}
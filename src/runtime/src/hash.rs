@@ -0,0 +1,195 @@
+use std::{
+    collections::HashMap,
+    hash::{DefaultHasher, Hash, Hasher},
+};
+
+use crate::{integer_from_u64, Integer, String, Type};
+
+/// Hash value with the same hasher for all types
+fn hash_of(value: &impl Hash) -> Integer {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    integer_from_u64(hasher.finish())
+}
+
+/// # PPL
+/// ```no_run
+/// @mangle_as("integer_hash")
+/// fn hash of <:&Integer> -> Integer
+/// ```
+#[no_mangle]
+pub extern "C" fn integer_hash(x: &Integer) -> Integer {
+    hash_of(x.as_ref())
+}
+
+/// # PPL
+/// ```no_run
+/// @mangle_as("string_hash")
+/// fn hash of <:&String> -> Integer
+/// ```
+#[no_mangle]
+pub extern "C" fn string_hash(x: &String) -> Integer {
+    hash_of(x.as_ref())
+}
+
+/// PPL's HashIndex type.
+/// Positions of elements in array, grouped by hashes of elements.
+/// Runtime doesn't know layout of elements,
+/// so compiled code hashes and compares them itself
+///
+/// # PPL
+/// ```no_run
+/// type HashIndexImpl
+///
+/// type HashIndex:
+///     impl: Reference<HashIndexImpl>
+/// ```
+#[repr(C)]
+pub struct HashIndex {
+    pub data: *mut HashMap<u64, Vec<usize>>,
+}
+
+impl HashIndex {
+    /// Get the inner value
+    pub fn as_ref(&self) -> &HashMap<u64, Vec<usize>> {
+        unsafe { &*self.data }
+    }
+
+    /// Get the inner value
+    pub fn as_mut(&mut self) -> &mut HashMap<u64, Vec<usize>> {
+        unsafe { &mut *self.data }
+    }
+
+    /// Positions of elements with this hash
+    fn positions(&self, hash: &Integer) -> &[usize] {
+        self.as_ref()
+            .get(&hash.as_ref().to_u64_wrapping())
+            .map_or(&[], |positions| positions.as_slice())
+    }
+}
+
+impl From<HashMap<u64, Vec<usize>>> for HashIndex {
+    fn from(map: HashMap<u64, Vec<usize>>) -> Self {
+        Self {
+            data: Box::into_raw(Box::new(map)),
+        }
+    }
+}
+
+/// # PPL
+/// ```no_run
+/// /// Create an empty index
+/// @mangle_as("default_hash_index")
+/// fn default <:Type<HashIndex>> -> HashIndex
+/// ```
+#[no_mangle]
+pub extern "C" fn default_hash_index(_ty: Type) -> HashIndex {
+    HashMap::new().into()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Number of positions with this hash
+/// @mangle_as("hash_index_count")
+/// fn count of <hash: &Integer> in <index: &HashIndex> -> Integer
+/// ```
+#[no_mangle]
+pub extern "C" fn hash_index_count(hash: &Integer, index: &HashIndex) -> Integer {
+    integer_from_u64(index.positions(hash).len() as u64)
+}
+
+/// # PPL
+/// ```no_run
+/// /// `i`-th position with this hash
+/// @mangle_as("hash_index_position")
+/// fn position <i: Integer> of <hash: &Integer> in <index: &HashIndex> -> Integer
+/// ```
+#[no_mangle]
+pub extern "C" fn hash_index_position(i: Integer, hash: &Integer, index: &HashIndex) -> Integer {
+    let positions = index.positions(hash);
+    let i = i.as_ref().to_usize().unwrap();
+    integer_from_u64(positions[i] as u64)
+}
+
+/// # PPL
+/// ```no_run
+/// /// Add position of element with this hash
+/// @mangle_as("hash_index_add")
+/// fn add <position: Integer> with <hash: &Integer> to <index: &mut HashIndex>
+/// ```
+#[no_mangle]
+pub extern "C" fn hash_index_add(position: Integer, hash: &Integer, index: &mut HashIndex) {
+    let position = position.as_ref().to_usize().unwrap();
+    index
+        .as_mut()
+        .entry(hash.as_ref().to_u64_wrapping())
+        .or_default()
+        .push(position);
+}
+
+/// # PPL
+/// ```no_run
+/// /// Remove position of element with this hash
+/// @mangle_as("hash_index_remove")
+/// fn remove <position: Integer> with <hash: &Integer> from <index: &mut HashIndex>
+/// ```
+#[no_mangle]
+pub extern "C" fn hash_index_remove(position: Integer, hash: &Integer, index: &mut HashIndex) {
+    let position = position.as_ref().to_usize().unwrap();
+    let hash = hash.as_ref().to_u64_wrapping();
+    let Some(positions) = index.as_mut().get_mut(&hash) else {
+        return;
+    };
+    positions.retain(|p| *p != position);
+    if positions.is_empty() {
+        index.as_mut().remove(&hash);
+    }
+}
+
+/// # PPL
+/// ```no_run
+/// fn destroy <:&mut HashIndex>
+/// ```
+#[no_mangle]
+pub extern "C" fn destroy_hash_index(x: &mut HashIndex) {
+    let _ = unsafe { Box::from_raw(x.data) };
+}
+
+/// # PPL
+/// ```no_run
+/// @mangle_as("clone_hash_index")
+/// fn clone <:&HashIndex> -> HashIndex
+/// ```
+#[no_mangle]
+pub extern "C" fn clone_hash_index(x: &HashIndex) -> HashIndex {
+    x.as_ref().clone().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn index() {
+        let mut index = HashIndex::from(HashMap::new());
+        let hash = integer_hash(&Integer::from(42));
+        assert_eq!(hash.as_ref(), integer_hash(&Integer::from(42)).as_ref());
+
+        hash_index_add(Integer::from(0), &hash, &mut index);
+        hash_index_add(Integer::from(3), &hash, &mut index);
+        assert_eq!(*hash_index_count(&hash, &index).as_ref(), 2);
+        assert_eq!(
+            *hash_index_position(Integer::from(1), &hash, &index).as_ref(),
+            3
+        );
+
+        hash_index_remove(Integer::from(0), &hash, &mut index);
+        assert_eq!(*hash_index_count(&hash, &index).as_ref(), 1);
+        assert_eq!(
+            *hash_index_position(Integer::from(0), &hash, &index).as_ref(),
+            3
+        );
+
+        destroy_hash_index(&mut index);
+    }
+}
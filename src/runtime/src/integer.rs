@@ -2,10 +2,10 @@ use std::ffi::c_char;
 
 use rug::ops::Pow;
 
-use crate::{Rational, String};
+use crate::{Rational, RcBox, String};
 
 /// Big integer number.
-/// Wrapper around pointer to [`rug::Integer`].
+/// Wrapper around reference counted pointer to [`rug::Integer`].
 ///
 /// # PPL
 /// ```no_run
@@ -17,25 +17,27 @@ use crate::{Rational, String};
 /// ```
 #[repr(C)]
 pub struct Integer {
-    pub data: *mut rug::Integer,
+    pub data: *mut RcBox<rug::Integer>,
 }
 
 impl Clone for Integer {
     fn clone(&self) -> Self {
-        self.as_ref().into()
+        Self {
+            data: unsafe { RcBox::retain(self.data) },
+        }
     }
 }
 
 impl Drop for Integer {
     fn drop(&mut self) {
-        // let _ = unsafe { Box::from_raw(self.data) };
+        unsafe { RcBox::release(self.data) }
     }
 }
 
 impl Integer {
     /// Get the inner value
     pub fn as_ref(&self) -> &rug::Integer {
-        unsafe { &*self.data }
+        unsafe { RcBox::get(self.data) }
     }
 }
 
@@ -44,9 +46,8 @@ where
     rug::Integer: From<T>,
 {
     fn from(x: T) -> Self {
-        let this = Box::new(rug::Integer::from(x));
         Self {
-            data: Box::into_raw(this),
+            data: RcBox::new(rug::Integer::from(x)),
         }
     }
 }
@@ -69,6 +70,18 @@ pub extern "C" fn integer_from_u64(value: u64) -> Integer {
     rug::Integer::from(value).into()
 }
 
+/// Construct [`Integer`] from [`u32`]
+#[no_mangle]
+pub extern "C" fn integer_from_u32(value: u32) -> Integer {
+    integer_from_u64(value as u64)
+}
+
+/// Construct [`Integer`] from [`u8`]
+#[no_mangle]
+pub extern "C" fn integer_from_u8(value: u8) -> Integer {
+    integer_from_u64(value as u64)
+}
+
 /// Construct [`Integer`](ppl::semantics::Type::Integer) from a C string
 #[no_mangle]
 pub extern "C" fn integer_from_c_string(str: *const c_char) -> Integer {
@@ -91,6 +104,33 @@ pub extern "C" fn integer_as_string(i: Integer) -> String {
     str.into()
 }
 
+/// Converts [`Integer`] to [`String`], grouping digits by thousands
+///
+/// # PPL
+/// ```no_run
+/// /// Convert `Integer` to `String`, separating thousands with `separator`
+/// @mangle_as("integer_format_with_separator")
+/// fn format <:Integer> with separator <separator: &String> -> String
+/// ```
+#[no_mangle]
+pub extern "C" fn integer_format_with_separator(i: Integer, separator: &String) -> String {
+    let i = i.as_ref();
+    let separator = separator.as_ref();
+
+    let digits = i.clone().abs().to_string();
+    let mut str = std::string::String::new();
+    if *i < 0 {
+        str.push('-');
+    }
+    for (n, digit) in digits.chars().enumerate() {
+        if n > 0 && (digits.len() - n) % 3 == 0 {
+            str.push_str(separator);
+        }
+        str.push(digit);
+    }
+    str.into()
+}
+
 /// Negates integer
 ///
 /// # PPL
@@ -213,13 +253,139 @@ pub extern "C" fn integer_mod_integer(x: Integer, y: Integer) -> Integer {
     res.into()
 }
 
+/// Bitwise and of 2 integers
+///
+/// # PPL
+/// ```no_run
+/// fn <:Integer> & <:Integer> -> Integer
+/// ```
+#[no_mangle]
+pub extern "C" fn integer_and_integer(x: Integer, y: Integer) -> Integer {
+    let x = x.as_ref();
+    let y = y.as_ref();
+
+    rug::Integer::from(x & y).into()
+}
+
+/// Bitwise or of 2 integers
+///
+/// # PPL
+/// ```no_run
+/// fn <:Integer> | <:Integer> -> Integer
+/// ```
+#[no_mangle]
+pub extern "C" fn integer_or_integer(x: Integer, y: Integer) -> Integer {
+    let x = x.as_ref();
+    let y = y.as_ref();
+
+    rug::Integer::from(x | y).into()
+}
+
+/// Bitwise exclusive or of 2 integers
+///
+/// # PPL
+/// ```no_run
+/// fn <:Integer> xor <:Integer> -> Integer
+/// ```
+#[no_mangle]
+pub extern "C" fn integer_xor_integer(x: Integer, y: Integer) -> Integer {
+    let x = x.as_ref();
+    let y = y.as_ref();
+
+    rug::Integer::from(x ^ y).into()
+}
+
+/// Shift integer to the left
+///
+/// # PPL
+/// ```no_run
+/// fn <x: Integer> << <n: Integer> -> Integer
+/// ```
+#[no_mangle]
+pub extern "C" fn integer_shl_integer(x: Integer, n: Integer) -> Integer {
+    let x = x.as_ref();
+    let n = n.as_ref();
+
+    let n = n
+        .to_u32()
+        .expect(&format!("Shift `{n}` is negative or too big"));
+    rug::Integer::from(x << n).into()
+}
+
+/// Shift integer to the right
+///
+/// # PPL
+/// ```no_run
+/// fn <x: Integer> >> <n: Integer> -> Integer
+/// ```
+#[no_mangle]
+pub extern "C" fn integer_shr_integer(x: Integer, n: Integer) -> Integer {
+    let x = x.as_ref();
+    let n = n.as_ref();
+
+    let n = n
+        .to_u32()
+        .expect(&format!("Shift `{n}` is negative or too big"));
+    rug::Integer::from(x >> n).into()
+}
+
+/// Divide integers, rounding towards negative infinity
+///
+/// # PPL
+/// ```no_run
+/// fn <x: Integer> div <y: Integer> -> Integer
+/// ```
+#[no_mangle]
+pub extern "C" fn integer_div_integer(x: Integer, y: Integer) -> Integer {
+    let x = x.as_ref();
+    let y = y.as_ref();
+    assert!(*y != 0, "division by zero");
+
+    let res = x.clone().div_floor(y);
+    res.into()
+}
+
+/// Remainder of division, rounding towards negative infinity.
+/// Result has the same sign as divisor
+///
+/// # PPL
+/// ```no_run
+/// fn <x: Integer> mod <y: Integer> -> Integer
+/// ```
+#[no_mangle]
+pub extern "C" fn integer_modulo_integer(x: Integer, y: Integer) -> Integer {
+    let x = x.as_ref();
+    let y = y.as_ref();
+    assert!(*y != 0, "division by zero");
+
+    let res = x.clone().rem_floor(y);
+    res.into()
+}
+
+/// Remainder of division, rounding towards zero.
+/// Result has the same sign as dividend
+///
+/// # PPL
+/// ```no_run
+/// fn <x: Integer> rem <y: Integer> -> Integer
+/// ```
+#[no_mangle]
+pub extern "C" fn integer_rem_integer(x: Integer, y: Integer) -> Integer {
+    let x = x.as_ref();
+    let y = y.as_ref();
+    assert!(*y != 0, "division by zero");
+
+    let res = x.clone().rem_trunc(y);
+    res.into()
+}
+
 /// # PPL
 /// ```no_run
 /// fn destroy <:&mut Integer>
 /// ```
 #[no_mangle]
 pub extern "C" fn destroy_integer(x: &mut Integer) {
-    let _ = unsafe { Box::from_raw(x.data) };
+    unsafe { RcBox::release(x.data) }
 }
 
 /// # PPL
@@ -274,6 +440,458 @@ pub extern "C" fn integer_as_i32(x: Integer) -> i32 {
         .expect(&format!("Integer `{integer}` is too big to fit into i32"))
 }
 
+/// # PPL
+/// ```no_run
+/// /// Convert `Integer` to `I64`.
+/// /// Panics, if value doesn't fit
+/// @mangle_as("integer_as_i64")
+/// fn <:Integer> as I64 -> I64
+/// ```
+#[no_mangle]
+pub extern "C" fn integer_as_i64(x: Integer) -> i64 {
+    let integer = x.as_ref();
+    integer
+        .to_i64()
+        .expect(&format!("Integer `{integer}` doesn't fit into i64"))
+}
+
+/// # PPL
+/// ```no_run
+/// /// Convert `Integer` to `U64`.
+/// /// Panics, if value doesn't fit
+/// @mangle_as("integer_as_u64")
+/// fn <:Integer> as U64 -> U64
+/// ```
+#[no_mangle]
+pub extern "C" fn integer_as_u64(x: Integer) -> u64 {
+    let integer = x.as_ref();
+    integer
+        .to_u64()
+        .expect(&format!("Integer `{integer}` doesn't fit into u64"))
+}
+
+/// # PPL
+/// ```no_run
+/// /// Convert `Integer` to `U32`.
+/// /// Panics, if value doesn't fit
+/// @mangle_as("integer_as_u32")
+/// fn <:Integer> as U32 -> U32
+/// ```
+#[no_mangle]
+pub extern "C" fn integer_as_u32(x: Integer) -> u32 {
+    let integer = x.as_ref();
+    integer
+        .to_u32()
+        .expect(&format!("Integer `{integer}` doesn't fit into u32"))
+}
+
+/// # PPL
+/// ```no_run
+/// /// Convert `Integer` to `U8`.
+/// /// Panics, if value doesn't fit
+/// @mangle_as("integer_as_u8")
+/// fn <:Integer> as U8 -> U8
+/// ```
+#[no_mangle]
+pub extern "C" fn integer_as_u8(x: Integer) -> u8 {
+    let integer = x.as_ref();
+    integer
+        .to_u8()
+        .expect(&format!("Integer `{integer}` doesn't fit into u8"))
+}
+
+/// # PPL
+/// ```no_run
+/// /// Convert `Integer` to `I32`.
+/// /// Wraps around, if value doesn't fit
+/// @mangle_as("integer_wrapping_as_i32")
+/// fn <:Integer> wrapping as I32 -> I32
+/// ```
+#[no_mangle]
+pub extern "C" fn integer_wrapping_as_i32(x: Integer) -> i32 {
+    x.as_ref().to_i32_wrapping()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Check that `Integer` fits into `I32`
+/// @mangle_as("integer_fits_i32")
+/// fn <:Integer> fits I32 -> Bool
+/// ```
+#[no_mangle]
+pub extern "C" fn integer_fits_i32(x: Integer) -> bool {
+    x.as_ref().to_i32().is_some()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Convert `Integer` to `I64`.
+/// /// Wraps around, if value doesn't fit
+/// @mangle_as("integer_wrapping_as_i64")
+/// fn <:Integer> wrapping as I64 -> I64
+/// ```
+#[no_mangle]
+pub extern "C" fn integer_wrapping_as_i64(x: Integer) -> i64 {
+    x.as_ref().to_i64_wrapping()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Check that `Integer` fits into `I64`
+/// @mangle_as("integer_fits_i64")
+/// fn <:Integer> fits I64 -> Bool
+/// ```
+#[no_mangle]
+pub extern "C" fn integer_fits_i64(x: Integer) -> bool {
+    x.as_ref().to_i64().is_some()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Convert `Integer` to `U64`.
+/// /// Wraps around, if value doesn't fit
+/// @mangle_as("integer_wrapping_as_u64")
+/// fn <:Integer> wrapping as U64 -> U64
+/// ```
+#[no_mangle]
+pub extern "C" fn integer_wrapping_as_u64(x: Integer) -> u64 {
+    x.as_ref().to_u64_wrapping()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Check that `Integer` fits into `U64`
+/// @mangle_as("integer_fits_u64")
+/// fn <:Integer> fits U64 -> Bool
+/// ```
+#[no_mangle]
+pub extern "C" fn integer_fits_u64(x: Integer) -> bool {
+    x.as_ref().to_u64().is_some()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Convert `Integer` to `U32`.
+/// /// Wraps around, if value doesn't fit
+/// @mangle_as("integer_wrapping_as_u32")
+/// fn <:Integer> wrapping as U32 -> U32
+/// ```
+#[no_mangle]
+pub extern "C" fn integer_wrapping_as_u32(x: Integer) -> u32 {
+    x.as_ref().to_u32_wrapping()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Check that `Integer` fits into `U32`
+/// @mangle_as("integer_fits_u32")
+/// fn <:Integer> fits U32 -> Bool
+/// ```
+#[no_mangle]
+pub extern "C" fn integer_fits_u32(x: Integer) -> bool {
+    x.as_ref().to_u32().is_some()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Convert `Integer` to `U8`.
+/// /// Wraps around, if value doesn't fit
+/// @mangle_as("integer_wrapping_as_u8")
+/// fn <:Integer> wrapping as U8 -> U8
+/// ```
+#[no_mangle]
+pub extern "C" fn integer_wrapping_as_u8(x: Integer) -> u8 {
+    x.as_ref().to_u8_wrapping()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Check that `Integer` fits into `U8`
+/// @mangle_as("integer_fits_u8")
+/// fn <:Integer> fits U8 -> Bool
+/// ```
+#[no_mangle]
+pub extern "C" fn integer_fits_u8(x: Integer) -> bool {
+    x.as_ref().to_u8().is_some()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Convert `U8` to `String`
+/// @mangle_as("u8_as_string")
+/// fn String from <:U8> -> String
+/// ```
+#[no_mangle]
+pub extern "C" fn u8_as_string(x: u8) -> String {
+    x.to_string().into()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Convert `U32` to `String`
+/// @mangle_as("u32_as_string")
+/// fn String from <:U32> -> String
+/// ```
+#[no_mangle]
+pub extern "C" fn u32_as_string(x: u32) -> String {
+    x.to_string().into()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Convert `U64` to `String`
+/// @mangle_as("u64_as_string")
+/// fn String from <:U64> -> String
+/// ```
+#[no_mangle]
+pub extern "C" fn u64_as_string(x: u64) -> String {
+    x.to_string().into()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Convert `I64` to `String`
+/// @mangle_as("i64_as_string")
+/// fn String from <:I64> -> String
+/// ```
+#[no_mangle]
+pub extern "C" fn i64_as_string(x: i64) -> String {
+    x.to_string().into()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Convert `I32` to `U8`.
+/// /// Panics, if value doesn't fit
+/// @mangle_as("i32_as_u8")
+/// fn <:I32> as U8 -> U8
+/// ```
+#[no_mangle]
+pub extern "C" fn i32_as_u8(x: i32) -> u8 {
+    u8::try_from(x).expect(&format!("`{x}` doesn't fit into u8"))
+}
+
+/// # PPL
+/// ```no_run
+/// /// Convert `U8` to `I32`
+/// @mangle_as("u8_as_i32")
+/// fn <:U8> as I32 -> I32
+/// ```
+#[no_mangle]
+pub extern "C" fn u8_as_i32(x: u8) -> i32 {
+    x.into()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Convert `I32` to `U32`.
+/// /// Panics, if value doesn't fit
+/// @mangle_as("i32_as_u32")
+/// fn <:I32> as U32 -> U32
+/// ```
+#[no_mangle]
+pub extern "C" fn i32_as_u32(x: i32) -> u32 {
+    u32::try_from(x).expect(&format!("`{x}` doesn't fit into u32"))
+}
+
+/// # PPL
+/// ```no_run
+/// /// Convert `U32` to `I32`.
+/// /// Panics, if value doesn't fit
+/// @mangle_as("u32_as_i32")
+/// fn <:U32> as I32 -> I32
+/// ```
+#[no_mangle]
+pub extern "C" fn u32_as_i32(x: u32) -> i32 {
+    i32::try_from(x).expect(&format!("`{x}` doesn't fit into i32"))
+}
+
+/// # PPL
+/// ```no_run
+/// /// Convert `U8` to `U32`
+/// @mangle_as("u8_as_u32")
+/// fn <:U8> as U32 -> U32
+/// ```
+#[no_mangle]
+pub extern "C" fn u8_as_u32(x: u8) -> u32 {
+    x.into()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Convert `U32` to `U8`.
+/// /// Panics, if value doesn't fit
+/// @mangle_as("u32_as_u8")
+/// fn <:U32> as U8 -> U8
+/// ```
+#[no_mangle]
+pub extern "C" fn u32_as_u8(x: u32) -> u8 {
+    u8::try_from(x).expect(&format!("`{x}` doesn't fit into u8"))
+}
+
+/// # PPL
+/// ```no_run
+/// /// Convert `I32` to `U64`.
+/// /// Panics, if value doesn't fit
+/// @mangle_as("i32_as_u64")
+/// fn <:I32> as U64 -> U64
+/// ```
+#[no_mangle]
+pub extern "C" fn i32_as_u64(x: i32) -> u64 {
+    u64::try_from(x).expect(&format!("`{x}` doesn't fit into u64"))
+}
+
+/// # PPL
+/// ```no_run
+/// /// Convert `U64` to `I32`.
+/// /// Panics, if value doesn't fit
+/// @mangle_as("u64_as_i32")
+/// fn <:U64> as I32 -> I32
+/// ```
+#[no_mangle]
+pub extern "C" fn u64_as_i32(x: u64) -> i32 {
+    i32::try_from(x).expect(&format!("`{x}` doesn't fit into i32"))
+}
+
+/// # PPL
+/// ```no_run
+/// /// Convert `U8` to `U64`
+/// @mangle_as("u8_as_u64")
+/// fn <:U8> as U64 -> U64
+/// ```
+#[no_mangle]
+pub extern "C" fn u8_as_u64(x: u8) -> u64 {
+    x.into()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Convert `U64` to `U8`.
+/// /// Panics, if value doesn't fit
+/// @mangle_as("u64_as_u8")
+/// fn <:U64> as U8 -> U8
+/// ```
+#[no_mangle]
+pub extern "C" fn u64_as_u8(x: u64) -> u8 {
+    u8::try_from(x).expect(&format!("`{x}` doesn't fit into u8"))
+}
+
+/// # PPL
+/// ```no_run
+/// /// Convert `U32` to `U64`
+/// @mangle_as("u32_as_u64")
+/// fn <:U32> as U64 -> U64
+/// ```
+#[no_mangle]
+pub extern "C" fn u32_as_u64(x: u32) -> u64 {
+    x.into()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Convert `U64` to `U32`.
+/// /// Panics, if value doesn't fit
+/// @mangle_as("u64_as_u32")
+/// fn <:U64> as U32 -> U32
+/// ```
+#[no_mangle]
+pub extern "C" fn u64_as_u32(x: u64) -> u32 {
+    u32::try_from(x).expect(&format!("`{x}` doesn't fit into u32"))
+}
+
+/// # PPL
+/// ```no_run
+/// /// Convert `I32` to `I64`
+/// @mangle_as("i32_as_i64")
+/// fn <:I32> as I64 -> I64
+/// ```
+#[no_mangle]
+pub extern "C" fn i32_as_i64(x: i32) -> i64 {
+    x.into()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Convert `I64` to `I32`.
+/// /// Panics, if value doesn't fit
+/// @mangle_as("i64_as_i32")
+/// fn <:I64> as I32 -> I32
+/// ```
+#[no_mangle]
+pub extern "C" fn i64_as_i32(x: i64) -> i32 {
+    i32::try_from(x).expect(&format!("`{x}` doesn't fit into i32"))
+}
+
+/// # PPL
+/// ```no_run
+/// /// Convert `U8` to `I64`
+/// @mangle_as("u8_as_i64")
+/// fn <:U8> as I64 -> I64
+/// ```
+#[no_mangle]
+pub extern "C" fn u8_as_i64(x: u8) -> i64 {
+    x.into()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Convert `I64` to `U8`.
+/// /// Panics, if value doesn't fit
+/// @mangle_as("i64_as_u8")
+/// fn <:I64> as U8 -> U8
+/// ```
+#[no_mangle]
+pub extern "C" fn i64_as_u8(x: i64) -> u8 {
+    u8::try_from(x).expect(&format!("`{x}` doesn't fit into u8"))
+}
+
+/// # PPL
+/// ```no_run
+/// /// Convert `U32` to `I64`
+/// @mangle_as("u32_as_i64")
+/// fn <:U32> as I64 -> I64
+/// ```
+#[no_mangle]
+pub extern "C" fn u32_as_i64(x: u32) -> i64 {
+    x.into()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Convert `I64` to `U32`.
+/// /// Panics, if value doesn't fit
+/// @mangle_as("i64_as_u32")
+/// fn <:I64> as U32 -> U32
+/// ```
+#[no_mangle]
+pub extern "C" fn i64_as_u32(x: i64) -> u32 {
+    u32::try_from(x).expect(&format!("`{x}` doesn't fit into u32"))
+}
+
+/// # PPL
+/// ```no_run
+/// /// Convert `U64` to `I64`.
+/// /// Panics, if value doesn't fit
+/// @mangle_as("u64_as_i64")
+/// fn <:U64> as I64 -> I64
+/// ```
+#[no_mangle]
+pub extern "C" fn u64_as_i64(x: u64) -> i64 {
+    i64::try_from(x).expect(&format!("`{x}` doesn't fit into i64"))
+}
+
+/// # PPL
+/// ```no_run
+/// /// Convert `I64` to `U64`.
+/// /// Panics, if value doesn't fit
+/// @mangle_as("i64_as_u64")
+/// fn <:I64> as U64 -> U64
+/// ```
+#[no_mangle]
+pub extern "C" fn i64_as_u64(x: i64) -> u64 {
+    u64::try_from(x).expect(&format!("`{x}` doesn't fit into u64"))
+}
+
 /// # PPL
 /// ```no_run
 /// /// Parse `Integer` from `String`
@@ -283,5 +901,92 @@ pub extern "C" fn integer_as_i32(x: Integer) -> i32 {
 #[no_mangle]
 pub extern "C" fn integer_from_string(str: &String) -> Integer {
     let str = str.as_ref();
-    str.parse::<rug::Integer>().unwrap().into()
+    str.parse::<rug::Integer>()
+        .unwrap_or_else(|_| panic!("`{str}` is not an integer"))
+        .into()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Check that `Integer from str` won't fail
+/// @mangle_as("string_is_integer")
+/// fn <str: &String> is integer -> Bool
+/// ```
+#[no_mangle]
+pub extern "C" fn string_is_integer(str: &String) -> bool {
+    rug::Integer::parse(str.as_ref()).is_ok()
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn format_with_separator() {
+        use super::{integer_format_with_separator, integer_from_i64};
+
+        let format = |i: i64| {
+            integer_format_with_separator(integer_from_i64(i), &",".into())
+                .as_ref()
+                .clone()
+        };
+        assert_eq!(format(0), "0");
+        assert_eq!(format(123), "123");
+        assert_eq!(format(1234), "1,234");
+        assert_eq!(format(123456), "123,456");
+        assert_eq!(format(-1234567), "-1,234,567");
+    }
+
+    #[test]
+    #[should_panic(expected = "division by zero")]
+    fn division_by_zero() {
+        use super::{integer_div_integer, integer_from_i64};
+
+        integer_div_integer(integer_from_i64(1), integer_from_i64(0));
+    }
+
+    #[test]
+    fn is_integer() {
+        use super::string_is_integer;
+
+        assert!(string_is_integer(&"-42".into()));
+        assert!(!string_is_integer(&"4two".into()));
+        assert!(!string_is_integer(&"".into()));
+    }
+
+    #[test]
+    fn wrapping_conversions() {
+        use super::{
+            integer_fits_i32, integer_fits_u8, integer_from_i64, integer_wrapping_as_i32,
+            integer_wrapping_as_u64, integer_wrapping_as_u8,
+        };
+
+        assert_eq!(integer_wrapping_as_u8(integer_from_i64(256)), 0);
+        assert_eq!(integer_wrapping_as_u8(integer_from_i64(-1)), 255);
+        assert_eq!(integer_wrapping_as_u64(integer_from_i64(-1)), u64::MAX);
+        assert_eq!(
+            integer_wrapping_as_i32(integer_from_i64(i32::MAX as i64 + 1)),
+            i32::MIN
+        );
+
+        assert!(integer_fits_u8(integer_from_i64(255)));
+        assert!(!integer_fits_u8(integer_from_i64(256)));
+        assert!(!integer_fits_u8(integer_from_i64(-1)));
+        assert!(integer_fits_i32(integer_from_i64(i32::MIN as i64)));
+        assert!(!integer_fits_i32(integer_from_i64(i32::MIN as i64 - 1)));
+    }
+
+    #[test]
+    fn clone_and_destroy() {
+        use super::{clone_integer, destroy_integer, integer_from_i64};
+        use crate::RcBox;
+
+        let x = integer_from_i64(42);
+        let mut y = clone_integer(&x);
+        assert_eq!(x.data, y.data);
+        assert_eq!(unsafe { RcBox::count(x.data) }, 2);
+
+        // Compiled code doesn't use values after their destruction
+        destroy_integer(&mut y);
+        std::mem::forget(y);
+        assert_eq!(unsafe { RcBox::count(x.data) }, 1);
+    }
 }
@@ -1,3 +1,6 @@
+mod rc;
+pub use rc::*;
+
 mod r#f64;
 pub use r#f64::*;
 
@@ -10,6 +13,21 @@ pub use rational::*;
 mod string;
 pub use string::*;
 
+mod string_builder;
+pub use string_builder::*;
+
+mod string_iterator;
+pub use string_iterator::*;
+
+mod array;
+pub use array::*;
+
+mod hash;
+pub use hash::*;
+
+mod bytes;
+pub use bytes::*;
+
 mod memory;
 pub use memory::*;
 
@@ -24,3 +42,21 @@ pub use assert::*;
 
 mod env;
 pub use env::*;
+
+mod backtrace;
+pub use backtrace::*;
+
+mod sync;
+pub use sync::*;
+
+mod random;
+pub use random::*;
+
+mod time;
+pub use time::*;
+
+mod fs;
+pub use fs::*;
+
+mod process;
+pub use process::*;
@@ -1,10 +1,22 @@
+use std::mem::ManuallyDrop;
+
 use libc::{c_void, malloc, memcpy, size_t};
 
 use crate::{integer_from_i64, integer_from_u64, Integer, String, Type};
 
+/// Address in memory.
+///
+/// Addresses are copied bitwise by compiled code,
+/// so value is released only by the owner of memory, e.g. [`Array`](crate::Array)
+///
+/// # PPL
+/// ```no_run
+/// type MemoryAddress:
+///     value: Integer
+/// ```
 #[repr(C)]
 pub struct MemoryAddress {
-    pub value: Integer,
+    pub value: ManuallyDrop<Integer>,
 }
 
 /// # PPL
@@ -30,7 +42,7 @@ pub extern "C" fn allocate_n_bytes(n: Integer) -> MemoryAddress {
     let n = n.to_usize();
     if n.is_none() {
         return MemoryAddress {
-            value: integer_from_i64(0),
+            value: ManuallyDrop::new(integer_from_i64(0)),
         };
     }
     let n = n.unwrap();
@@ -38,7 +50,7 @@ pub extern "C" fn allocate_n_bytes(n: Integer) -> MemoryAddress {
     let address = unsafe { malloc(n) } as u64;
 
     MemoryAddress {
-        value: integer_from_u64(address),
+        value: ManuallyDrop::new(integer_from_u64(address)),
     }
 }
 
@@ -89,7 +101,7 @@ pub extern "C" fn address_of(ptr: *const c_void) -> MemoryAddress {
     let address = ptr as usize;
 
     MemoryAddress {
-        value: integer_from_u64(address as u64),
+        value: ManuallyDrop::new(integer_from_u64(address as u64)),
     }
 }
 
@@ -106,3 +118,16 @@ pub extern "C" fn copy_bytes(n: &Integer, src: &MemoryAddress, dst: &MemoryAddre
     let n = n.as_ref().to_usize().unwrap() as size_t;
     unsafe { memcpy(dest, src, n) };
 }
+
+/// # PPL
+/// ```no_run
+/// /// Destroy value, if its type has a destructor.
+/// /// Does nothing for other types
+/// @mangle_as("drop_value")
+/// fn<T> drop <value: &mut T>
+/// ```
+///
+/// Compiler replaces calls to this function with destructors,
+/// so only values without destructors get here
+#[no_mangle]
+pub extern "C" fn drop_value(_value: *mut c_void) {}
@@ -0,0 +1,16 @@
+use std::io::Write;
+
+use crate::Integer;
+
+/// # PPL
+/// ```no_run
+/// /// Flush stdout and terminate program with status `code`.
+/// /// Codes, that don't fit into 32 bits, are replaced with 1
+/// @mangle_as("exit_with_code")
+/// fn exit with code <code: &Integer> -> Never
+/// ```
+#[no_mangle]
+pub extern "C" fn exit_with_code(code: &Integer) -> ! {
+    let _ = std::io::stdout().flush();
+    std::process::exit(code.as_ref().to_i32().unwrap_or(1))
+}
@@ -0,0 +1,97 @@
+use std::{
+    cell::RefCell,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use rug::rand::RandState;
+
+use crate::{Integer, Rational};
+
+/// Amount of random bits in generated rationals
+const RATIONAL_BITS: u32 = 64;
+
+thread_local! {
+    /// Generator of random numbers for current thread.
+    /// Seeded with current time, unless seeded explicitly
+    static GENERATOR: RefCell<RandState<'static>> = RefCell::new({
+        let mut state = RandState::new();
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos());
+        state.seed(&rug::Integer::from(nanos));
+        state
+    });
+}
+
+/// # PPL
+/// ```no_run
+/// /// Seed generator of random numbers to get reproducible sequence of them
+/// @mangle_as("seed_random")
+/// fn seed random with <seed: &Integer>
+/// ```
+#[no_mangle]
+pub extern "C" fn seed_random(seed: &Integer) {
+    GENERATOR.with_borrow_mut(|state| state.seed(seed.as_ref()))
+}
+
+/// # PPL
+/// ```no_run
+/// /// Random `Integer` in range `[a, b]`.
+/// /// Panics, if `a` is greater than `b`
+/// @mangle_as("random_integer_from_to")
+/// fn random integer from <a: &Integer> to <b: &Integer> -> Integer
+/// ```
+#[no_mangle]
+pub extern "C" fn random_integer_from_to(a: &Integer, b: &Integer) -> Integer {
+    let a = a.as_ref();
+    let b = b.as_ref();
+    if a > b {
+        panic!("can't generate random integer from {a} to {b}: range is empty");
+    }
+
+    let bound = rug::Integer::from(b - a) + 1;
+    let offset = GENERATOR.with_borrow_mut(|state| bound.random_below(state));
+    (offset + a).into()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Random `Rational` in range `[0, 1)`
+/// @mangle_as("random_rational")
+/// fn random rational -> Rational
+/// ```
+#[no_mangle]
+pub extern "C" fn random_rational() -> Rational {
+    let numer = GENERATOR.with_borrow_mut(|state| {
+        rug::Integer::from(rug::Integer::random_bits(RATIONAL_BITS, state))
+    });
+    let denom = rug::Integer::from(1) << RATIONAL_BITS;
+    rug::Rational::from((numer, denom)).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeded() {
+        let a: Integer = 1.into();
+        let b: Integer = 6.into();
+
+        let rolls = |seed: i32| {
+            seed_random(&seed.into());
+            (0..10)
+                .map(|_| random_integer_from_to(&a, &b).as_ref().clone())
+                .collect::<Vec<_>>()
+        };
+        let first = rolls(42);
+        assert_eq!(first, rolls(42));
+        assert!(first.iter().all(|x| (1..=6).contains(&x.to_i32().unwrap())));
+    }
+
+    #[test]
+    fn rational() {
+        let r = random_rational();
+        assert!(*r.as_ref() >= 0 && *r.as_ref() < 1);
+    }
+}
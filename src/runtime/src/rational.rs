@@ -2,10 +2,10 @@ use std::ffi::c_char;
 
 use rug::{ops::Pow, Integer};
 
-use crate::String;
+use crate::{RcBox, String};
 
 /// Rational number.
-/// Wrapper around pointer to [`rug::Rational`].
+/// Wrapper around reference counted pointer to [`rug::Rational`].
 ///
 /// # PPL
 /// ```no_run
@@ -17,25 +17,27 @@ use crate::String;
 /// ```
 #[repr(C)]
 pub struct Rational {
-    pub data: *mut rug::Rational,
+    pub data: *mut RcBox<rug::Rational>,
 }
 
 impl Clone for Rational {
     fn clone(&self) -> Self {
-        self.as_ref().into()
+        Self {
+            data: unsafe { RcBox::retain(self.data) },
+        }
     }
 }
 
 impl Drop for Rational {
     fn drop(&mut self) {
-        // let _ = unsafe { Box::from_raw(self.data) };
+        unsafe { RcBox::release(self.data) }
     }
 }
 
 impl Rational {
     /// Get the inner value
     pub fn as_ref(&self) -> &rug::Rational {
-        unsafe { &*self.data }
+        unsafe { RcBox::get(self.data) }
     }
 }
 
@@ -44,9 +46,8 @@ where
     rug::Rational: From<T>,
 {
     fn from(x: T) -> Self {
-        let this = Box::new(rug::Rational::from(x));
         Self {
-            data: Box::into_raw(this),
+            data: RcBox::new(rug::Rational::from(x)),
         }
     }
 }
@@ -159,7 +160,7 @@ pub extern "C" fn rational_less_rational(x: Rational, y: Rational) -> bool {
 /// ```
 #[no_mangle]
 pub extern "C" fn destroy_rational(x: &mut Rational) {
-    let _ = unsafe { Box::from_raw(x.data) };
+    unsafe { RcBox::release(x.data) }
 }
 
 /// # PPL
@@ -172,6 +173,40 @@ pub extern "C" fn clone_rational(x: &Rational) -> Rational {
     x.clone()
 }
 
+/// # PPL
+/// ```no_run
+/// /// Convert `Rational` to fraction in lowest terms, e.g. `3/4`.
+/// /// Denominator is omitted for whole numbers
+/// @mangle_as("rational_as_fraction")
+/// fn fraction from <:&Rational> -> String
+/// ```
+#[no_mangle]
+pub extern "C" fn rational_as_fraction(r: &Rational) -> String {
+    r.as_ref().to_string().into()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Numerator of `Rational` in lowest terms
+/// @mangle_as("rational_numerator")
+/// fn numerator of <:&Rational> -> Integer
+/// ```
+#[no_mangle]
+pub extern "C" fn rational_numerator(r: &Rational) -> crate::Integer {
+    r.as_ref().numer().clone().into()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Denominator of `Rational` in lowest terms. Always positive
+/// @mangle_as("rational_denominator")
+/// fn denominator of <:&Rational> -> Integer
+/// ```
+#[no_mangle]
+pub extern "C" fn rational_denominator(r: &Rational) -> crate::Integer {
+    r.as_ref().denom().clone().into()
+}
+
 pub fn maybe_to_decimal_string(r: &rug::Rational) -> std::string::String {
     let mut denom = r.denom().clone();
     let pow2 = denom.remove_factor_mut(&Integer::from(2));
@@ -227,4 +262,15 @@ mod test {
         let r = rug::Rational::from((1, 16));
         assert_eq!(maybe_to_decimal_string(&r), "0.0625");
     }
+
+    #[test]
+    fn as_fraction() {
+        use super::rational_as_fraction;
+
+        let r = rug::Rational::from((6, 8)).into();
+        assert_eq!(rational_as_fraction(&r).as_ref(), "3/4");
+
+        let r = rug::Rational::from((-10, 2)).into();
+        assert_eq!(rational_as_fraction(&r).as_ref(), "-5");
+    }
 }
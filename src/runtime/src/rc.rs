@@ -0,0 +1,151 @@
+use std::{
+    ffi::c_void,
+    sync::atomic::{fence, AtomicUsize, Ordering},
+};
+
+/// Header of [`RcBox`], that doesn't depend on type of stored value.
+///
+/// Compiled code only sees pointers to boxes,
+/// so it retains and releases them through this header
+#[repr(C)]
+struct RcHeader {
+    /// Number of references to this box
+    count: AtomicUsize,
+    /// Free box, when the last reference is released
+    drop: unsafe fn(*mut RcHeader),
+}
+
+/// Heap-allocated value of builtin type with reference counter header.
+///
+/// Values of builtin types are immutable,
+/// so cloning them only increments reference counter
+#[repr(C)]
+pub struct RcBox<T> {
+    /// Type-erased part of the box
+    header: RcHeader,
+    /// Stored value
+    value: T,
+}
+
+impl<T> RcBox<T> {
+    /// Allocate new box with single reference to `value`
+    pub fn new(value: T) -> *mut Self {
+        Box::into_raw(Box::new(Self {
+            header: RcHeader {
+                count: AtomicUsize::new(1),
+                drop: Self::drop_box,
+            },
+            value,
+        }))
+    }
+
+    /// Free box together with its value
+    unsafe fn drop_box(header: *mut RcHeader) {
+        let _ = Box::from_raw(header as *mut Self);
+    }
+
+    /// Get the stored value
+    ///
+    /// # Safety
+    /// `this` must point to a live box
+    pub unsafe fn get<'a>(this: *const Self) -> &'a T {
+        &(*this).value
+    }
+
+    /// Get current number of references
+    ///
+    /// # Safety
+    /// `this` must point to a live box
+    pub unsafe fn count(this: *const Self) -> usize {
+        (*this).header.count.load(Ordering::Acquire)
+    }
+
+    /// Add reference to the box
+    ///
+    /// # Safety
+    /// `this` must point to a live box
+    pub unsafe fn retain(this: *mut Self) -> *mut Self {
+        rc_retain(this as *mut c_void) as *mut Self
+    }
+
+    /// Remove reference to the box, freeing it, if it was the last one
+    ///
+    /// # Safety
+    /// `this` must point to a live box and must not be used after this call
+    pub unsafe fn release(this: *mut Self) {
+        rc_release(this as *mut c_void)
+    }
+}
+
+/// Add reference to box, returned by runtime for builtin type
+///
+/// # Safety
+/// `data` must point to a live [`RcBox`]
+#[no_mangle]
+pub unsafe extern "C" fn rc_retain(data: *mut c_void) -> *mut c_void {
+    let header = data as *mut RcHeader;
+    (*header).count.fetch_add(1, Ordering::Relaxed);
+    data
+}
+
+/// Remove reference to box, returned by runtime for builtin type,
+/// freeing it, if it was the last one
+///
+/// # Safety
+/// `data` must point to a live [`RcBox`] and must not be used after this call
+#[no_mangle]
+pub unsafe extern "C" fn rc_release(data: *mut c_void) {
+    let header = data as *mut RcHeader;
+    if (*header).count.fetch_sub(1, Ordering::Release) != 1 {
+        return;
+    }
+
+    fence(Ordering::Acquire);
+    ((*header).drop)(header)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        ffi::c_void,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    use super::{rc_release, rc_retain, RcBox};
+
+    #[test]
+    fn retain_release() {
+        let rc = RcBox::new(42);
+        unsafe {
+            assert_eq!(RcBox::count(rc), 1);
+            assert_eq!(RcBox::retain(rc), rc);
+            assert_eq!(RcBox::count(rc), 2);
+            assert_eq!(*RcBox::get(rc), 42);
+
+            RcBox::release(rc);
+            assert_eq!(RcBox::count(rc), 1);
+            RcBox::release(rc);
+        }
+    }
+
+    #[test]
+    fn type_erased_release() {
+        static DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+        struct Value;
+        impl Drop for Value {
+            fn drop(&mut self) {
+                DROPPED.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        let rc = RcBox::new(Value) as *mut c_void;
+        unsafe {
+            assert_eq!(rc_retain(rc), rc);
+            rc_release(rc);
+            assert_eq!(DROPPED.load(Ordering::Relaxed), 0);
+            rc_release(rc);
+        }
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 1);
+    }
+}
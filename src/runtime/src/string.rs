@@ -1,7 +1,9 @@
 use std::{ffi::c_char, io::Write};
 
+use crate::{integer_from_i64, integer_from_u64, Array, Integer, RcBox};
+
 /// PPL's String type.
-/// Wrapper around pointer to [`std::string::String`].
+/// Wrapper around reference counted pointer to [`std::string::String`].
 ///
 /// # PPL
 /// ```no_run
@@ -13,25 +15,27 @@ use std::{ffi::c_char, io::Write};
 /// ```
 #[repr(C)]
 pub struct String {
-    pub data: *mut std::string::String,
+    pub data: *mut RcBox<std::string::String>,
 }
 
 impl Clone for String {
     fn clone(&self) -> Self {
-        self.as_ref().into()
+        Self {
+            data: unsafe { RcBox::retain(self.data) },
+        }
     }
 }
 
 impl Drop for String {
     fn drop(&mut self) {
-        // let _ = unsafe { Box::from_raw(self.data) };
+        unsafe { RcBox::release(self.data) }
     }
 }
 
 impl String {
     /// Get the inner value
     pub fn as_ref(&self) -> &std::string::String {
-        unsafe { &*self.data }
+        unsafe { RcBox::get(self.data) }
     }
 }
 
@@ -40,9 +44,8 @@ where
     std::string::String: From<T>,
 {
     fn from(x: T) -> Self {
-        let this = Box::new(std::string::String::from(x));
         Self {
-            data: Box::into_raw(this),
+            data: RcBox::new(std::string::String::from(x)),
         }
     }
 }
@@ -56,18 +59,22 @@ pub extern "C" fn string_from_c_string_and_length(str: *const c_char, _len: u64)
     str.to_string().into()
 }
 
-/// Concatenate 2 string
+/// Concatenate 2 strings
 ///
 /// # PPL
 /// ```no_run
-/// fn <:String> + <:String> -> None
+/// @mangle_as("string_plus_string")
+/// fn <:String> + <:String> -> String
 /// ```
 #[no_mangle]
 pub extern "C" fn string_plus_string(x: String, y: String) -> String {
     let x = x.as_ref();
     let y = y.as_ref();
 
-    format!("{x}{y}").into()
+    let mut result = std::string::String::with_capacity(x.len() + y.len());
+    result.push_str(x);
+    result.push_str(y);
+    result.into()
 }
 
 /// Print string to stdout
@@ -85,13 +92,221 @@ pub extern "C" fn print_string(str: &String) {
     std::io::stdout().flush().unwrap();
 }
 
+/// # PPL
+/// ```no_run
+/// /// Print string to stderr
+/// @mangle_as("eprint_string")
+/// fn eprint <str: &String>
+/// ```
+#[no_mangle]
+pub extern "C" fn eprint_string(str: &String) {
+    let str = str.as_ref();
+
+    eprint!("{str}");
+}
+
+/// # PPL
+/// ```no_run
+/// /// Read line from stdin without trailing newline.
+/// /// Returns empty string at the end of input
+/// @mangle_as("read_line")
+/// fn read line -> String
+/// ```
+#[no_mangle]
+pub extern "C" fn read_line() -> String {
+    let mut line = std::string::String::new();
+    std::io::stdin().read_line(&mut line).unwrap();
+
+    let len = line.trim_end_matches(['\n', '\r']).len();
+    line.truncate(len);
+    line.into()
+}
+
+/// Pad string to `width` characters, using `fill` character
+fn pad(str: &String, width: i32, fill: &String, left: bool) -> String {
+    let str = str.as_ref();
+    let fill = fill.as_ref();
+
+    let len = str.chars().count();
+    let width = usize::try_from(width).unwrap_or(0);
+    if len >= width {
+        return str.clone().into();
+    }
+
+    let padding: std::string::String = fill
+        .chars()
+        .next()
+        .map_or(std::string::String::new(), |c| c.to_string())
+        .repeat(width - len);
+    if left {
+        format!("{padding}{str}").into()
+    } else {
+        format!("{str}{padding}").into()
+    }
+}
+
+/// # PPL
+/// ```no_run
+/// /// Pad string on the left with `fill` to be at least `width` characters long
+/// @mangle_as("string_pad_left")
+/// fn pad left <str: &String> to <width: I32> with <fill: &String> -> String
+/// ```
+#[no_mangle]
+pub extern "C" fn string_pad_left(str: &String, width: i32, fill: &String) -> String {
+    pad(str, width, fill, true)
+}
+
+/// # PPL
+/// ```no_run
+/// /// Pad string on the right with `fill` to be at least `width` characters long
+/// @mangle_as("string_pad_right")
+/// fn pad right <str: &String> to <width: I32> with <fill: &String> -> String
+/// ```
+#[no_mangle]
+pub extern "C" fn string_pad_right(str: &String, width: i32, fill: &String) -> String {
+    pad(str, width, fill, false)
+}
+
+/// Convert character index to byte offset in string.
+/// Panics, if index is out of bounds
+fn byte_offset(str: &str, index: &Integer) -> usize {
+    let chars = str.chars().count();
+    match index.as_ref().to_usize() {
+        Some(i) if i < chars => str.char_indices().nth(i).unwrap().0,
+        Some(i) if i == chars => str.len(),
+        _ => panic!(
+            "index out of bounds: the length is {chars} but the index is {}",
+            index.as_ref()
+        ),
+    }
+}
+
+/// # PPL
+/// ```no_run
+/// /// Number of characters in a string
+/// @mangle_as("string_length")
+/// fn length of <str: &String> -> Integer
+/// ```
+#[no_mangle]
+pub extern "C" fn string_length(str: &String) -> Integer {
+    integer_from_u64(str.as_ref().chars().count() as u64)
+}
+
+/// # PPL
+/// ```no_run
+/// /// Characters of a string with indices in `[start, end)`.
+/// /// Panics, if indices are out of bounds
+/// @mangle_as("string_substring")
+/// fn substring of <str: &String> from <start: &Integer> to <end: &Integer> -> String
+/// ```
+#[no_mangle]
+pub extern "C" fn string_substring(str: &String, start: &Integer, end: &Integer) -> String {
+    let str = str.as_ref();
+
+    let start_offset = byte_offset(str, start);
+    let end_offset = byte_offset(str, end);
+    if start_offset > end_offset {
+        panic!(
+            "substring starts at {} but ends at {}",
+            start.as_ref(),
+            end.as_ref()
+        );
+    }
+
+    str[start_offset..end_offset].into()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Index of the first character of `pattern`'s first occurrence in a string,
+/// /// or -1, if string doesn't contain it
+/// @mangle_as("string_find")
+/// fn find <pattern: &String> in <str: &String> -> Integer
+/// ```
+#[no_mangle]
+pub extern "C" fn string_find(pattern: &String, str: &String) -> Integer {
+    let str = str.as_ref();
+
+    match str.find(pattern.as_ref().as_str()) {
+        Some(offset) => integer_from_u64(str[..offset].chars().count() as u64),
+        None => integer_from_i64(-1),
+    }
+}
+
+/// # PPL
+/// ```no_run
+/// /// Append parts of a string, separated by `separator`, to `parts`
+/// @mangle_as("string_split_into")
+/// fn split <str: &String> by <separator: &String> into <parts: &mut Array<String>>
+/// ```
+#[no_mangle]
+pub extern "C" fn string_split_into(str: &String, separator: &String, parts: &mut Array) {
+    let str = str.as_ref();
+    let separator = separator.as_ref();
+
+    str.split(separator.as_str())
+        .for_each(|part| parts.push(String::from(part)));
+}
+
+/// # PPL
+/// ```no_run
+/// /// Replace `{}` placeholders in `format` with arguments in order.
+/// /// Panics, if number of placeholders and arguments differ
+/// @mangle_as("string_format")
+/// fn format <format: &String> with <arguments: &Array<String>> -> String
+/// ```
+#[no_mangle]
+pub extern "C" fn string_format(format: &String, arguments: &Array) -> String {
+    let format = format.as_ref();
+
+    let placeholders = format.matches("{}").count();
+    if placeholders != arguments.size() {
+        panic!(
+            "format string {format:?} has {placeholders} placeholders, but {} arguments were given",
+            arguments.size()
+        );
+    }
+
+    let mut parts = format.split("{}");
+    let mut result = std::string::String::from(parts.next().unwrap());
+    for (i, part) in parts.enumerate() {
+        result.push_str(arguments.get::<String>(i).as_ref());
+        result.push_str(part);
+    }
+    result.into()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Replace all occurrences of `from` in a string with `to`
+/// @mangle_as("string_replace")
+/// fn replace <from: &String> with <to: &String> in <str: &String> -> String
+/// ```
+#[no_mangle]
+pub extern "C" fn string_replace(from: &String, to: &String, str: &String) -> String {
+    str.as_ref()
+        .replace(from.as_ref().as_str(), to.as_ref())
+        .into()
+}
+
+/// # PPL
+/// ```no_run
+/// /// String without leading and trailing whitespace
+/// @mangle_as("string_trim")
+/// fn trim <str: &String> -> String
+/// ```
+#[no_mangle]
+pub extern "C" fn string_trim(str: &String) -> String {
+    str.as_ref().trim().into()
+}
+
 /// # PPL
 /// ```no_run
 /// fn destroy <:&mut String>
 /// ```
 #[no_mangle]
 pub extern "C" fn destroy_string(x: &mut String) {
-    let _ = unsafe { Box::from_raw(x.data) };
+    unsafe { RcBox::release(x.data) }
 }
 
 /// # PPL
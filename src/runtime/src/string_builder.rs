@@ -0,0 +1,88 @@
+use crate::{String, Type};
+
+/// PPL's StringBuilder type.
+/// Wrapper around pointer to [`std::string::String`], that grows in place
+///
+/// # PPL
+/// ```no_run
+/// type StringBuilderImpl
+///
+/// type StringBuilder:
+///     impl: Reference<StringBuilderImpl>
+/// ```
+#[repr(C)]
+pub struct StringBuilder {
+    pub data: *mut std::string::String,
+}
+
+impl StringBuilder {
+    /// Get the inner value
+    pub fn as_ref(&self) -> &std::string::String {
+        unsafe { &*self.data }
+    }
+
+    /// Get the inner value
+    pub fn as_mut(&mut self) -> &mut std::string::String {
+        unsafe { &mut *self.data }
+    }
+}
+
+impl From<std::string::String> for StringBuilder {
+    fn from(str: std::string::String) -> Self {
+        Self {
+            data: Box::into_raw(Box::new(str)),
+        }
+    }
+}
+
+/// # PPL
+/// ```no_run
+/// /// Create an empty builder
+/// @mangle_as("default_string_builder")
+/// fn default <:Type<StringBuilder>> -> StringBuilder
+/// ```
+#[no_mangle]
+pub extern "C" fn default_string_builder(_ty: Type) -> StringBuilder {
+    std::string::String::new().into()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Append string to the end of builder
+/// @mangle_as("string_builder_append")
+/// fn append <str: &String> to <builder: &mut StringBuilder>
+/// ```
+#[no_mangle]
+pub extern "C" fn string_builder_append(str: &String, builder: &mut StringBuilder) {
+    builder.as_mut().push_str(str.as_ref());
+}
+
+/// # PPL
+/// ```no_run
+/// /// Get string with all the appended parts
+/// @mangle_as("string_builder_build")
+/// fn build <builder: &StringBuilder> -> String
+/// ```
+#[no_mangle]
+pub extern "C" fn string_builder_build(builder: &StringBuilder) -> String {
+    builder.as_ref().as_str().into()
+}
+
+/// # PPL
+/// ```no_run
+/// fn destroy <:&mut StringBuilder>
+/// ```
+#[no_mangle]
+pub extern "C" fn destroy_string_builder(x: &mut StringBuilder) {
+    let _ = unsafe { Box::from_raw(x.data) };
+}
+
+/// # PPL
+/// ```no_run
+/// @mangle_as("clone_string_builder")
+/// fn clone <:&StringBuilder> -> StringBuilder
+/// ```
+#[no_mangle]
+pub extern "C" fn clone_string_builder(x: &StringBuilder) -> StringBuilder {
+    x.as_ref().clone().into()
+}
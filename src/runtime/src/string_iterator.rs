@@ -0,0 +1,205 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::String;
+
+/// Grapheme clusters of a string
+///
+/// # PPL
+/// ```no_run
+/// type Graphemes:
+///     str: String
+/// ```
+#[repr(C)]
+pub struct Graphemes {
+    pub str: String,
+}
+
+/// State of [`StringIterator`]
+#[derive(Clone)]
+pub struct StringIteratorImpl {
+    /// Iterated string
+    str: String,
+    /// Byte offset of current value
+    start: usize,
+    /// Byte offset right after current value
+    end: usize,
+    /// Iterate over grapheme clusters instead of characters
+    graphemes: bool,
+}
+
+impl StringIteratorImpl {
+    /// Create iterator, pointing to the first value of string
+    fn new(str: &String, graphemes: bool) -> Self {
+        let mut this = Self {
+            str: str.clone(),
+            start: 0,
+            end: 0,
+            graphemes,
+        };
+        this.end = this.next_end();
+        this
+    }
+
+    /// Byte offset right after value, that starts at `self.start`
+    fn next_end(&self) -> usize {
+        let rest = &self.str.as_ref()[self.start..];
+        let len = if self.graphemes {
+            rest.graphemes(true).next().map_or(0, str::len)
+        } else {
+            rest.chars().next().map_or(0, char::len_utf8)
+        };
+        self.start + len
+    }
+
+    /// Is there current value?
+    fn exists(&self) -> bool {
+        self.start < self.end
+    }
+
+    /// Move to the next value
+    fn advance(&mut self) {
+        self.start = self.end;
+        self.end = self.next_end();
+    }
+
+    /// Current value
+    fn value(&self) -> &str {
+        &self.str.as_ref()[self.start..self.end]
+    }
+}
+
+/// PPL's StringIterator type.
+/// Iterates over characters (unicode scalar values)
+/// or grapheme clusters of a string
+///
+/// # PPL
+/// ```no_run
+/// type StringIteratorImpl
+///
+/// type StringIterator:
+///     impl: Reference<StringIteratorImpl>
+/// ```
+#[repr(C)]
+pub struct StringIterator {
+    pub data: *mut StringIteratorImpl,
+}
+
+impl StringIterator {
+    /// Get the inner value
+    pub fn as_ref(&self) -> &StringIteratorImpl {
+        unsafe { &*self.data }
+    }
+
+    /// Get the inner value
+    pub fn as_mut(&mut self) -> &mut StringIteratorImpl {
+        unsafe { &mut *self.data }
+    }
+}
+
+impl From<StringIteratorImpl> for StringIterator {
+    fn from(it: StringIteratorImpl) -> Self {
+        Self {
+            data: Box::into_raw(Box::new(it)),
+        }
+    }
+}
+
+/// # PPL
+/// ```no_run
+/// /// Iterate over characters (unicode scalar values) of a string
+/// @mangle_as("string_characters_iterator")
+/// fn iterator for <str: &String> -> StringIterator
+/// ```
+#[no_mangle]
+pub extern "C" fn string_characters_iterator(str: &String) -> StringIterator {
+    StringIteratorImpl::new(str, false).into()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Iterate over grapheme clusters of a string
+/// @mangle_as("string_graphemes_iterator")
+/// fn iterator for <graphemes: &Graphemes> -> StringIterator
+/// ```
+#[no_mangle]
+pub extern "C" fn string_graphemes_iterator(graphemes: &Graphemes) -> StringIterator {
+    StringIteratorImpl::new(&graphemes.str, true).into()
+}
+
+/// # PPL
+/// ```no_run
+/// @mangle_as("string_iterator_exists")
+/// fn <it: &StringIterator> exists -> Bool
+/// ```
+#[no_mangle]
+pub extern "C" fn string_iterator_exists(it: &StringIterator) -> bool {
+    it.as_ref().exists()
+}
+
+/// # PPL
+/// ```no_run
+/// @mangle_as("string_iterator_advance")
+/// fn advance <it: &mut StringIterator>
+/// ```
+#[no_mangle]
+pub extern "C" fn string_iterator_advance(it: &mut StringIterator) {
+    it.as_mut().advance()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Current character or grapheme cluster
+/// @mangle_as("string_iterator_value")
+/// fn value from <it: &StringIterator> -> String
+/// ```
+#[no_mangle]
+pub extern "C" fn string_iterator_value(it: &StringIterator) -> String {
+    it.as_ref().value().into()
+}
+
+/// # PPL
+/// ```no_run
+/// fn destroy <:&mut StringIterator>
+/// ```
+#[no_mangle]
+pub extern "C" fn destroy_string_iterator(x: &mut StringIterator) {
+    let _ = unsafe { Box::from_raw(x.data) };
+}
+
+/// # PPL
+/// ```no_run
+/// @mangle_as("clone_string_iterator")
+/// fn clone <:&StringIterator> -> StringIterator
+/// ```
+#[no_mangle]
+pub extern "C" fn clone_string_iterator(x: &StringIterator) -> StringIterator {
+    x.as_ref().clone().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(str: &str, graphemes: bool) -> Vec<std::string::String> {
+        let str = String::from(str);
+        let mut it = StringIteratorImpl::new(&str, graphemes);
+        let mut values = vec![];
+        while it.exists() {
+            values.push(it.value().to_string());
+            it.advance();
+        }
+        values
+    }
+
+    #[test]
+    fn characters() {
+        assert_eq!(values("héllo", false), ["h", "é", "l", "l", "o"]);
+        assert_eq!(values("", false), Vec::<std::string::String>::new());
+    }
+
+    #[test]
+    fn graphemes() {
+        assert_eq!(values("e\u{301}!", false), ["e", "\u{301}", "!"]);
+        assert_eq!(values("e\u{301}!", true), ["e\u{301}", "!"]);
+    }
+}
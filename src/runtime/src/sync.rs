@@ -0,0 +1,225 @@
+use std::sync::{
+    atomic::{AtomicI64, Ordering},
+    Condvar, Mutex,
+};
+
+use crate::{integer_from_i64, Integer, Type};
+
+/// State of [`Lock`]
+pub struct LockImpl {
+    /// Is lock acquired?
+    locked: Mutex<bool>,
+    /// Notified, when lock is released
+    released: Condvar,
+}
+
+/// PPL's Lock type.
+/// Unlike [`std::sync::Mutex`], may be released by a separate call
+///
+/// # PPL
+/// ```no_run
+/// type LockImpl
+///
+/// type Lock:
+///     impl: Reference<LockImpl>
+/// ```
+#[repr(C)]
+pub struct Lock {
+    pub data: *mut LockImpl,
+}
+
+impl Lock {
+    /// Get the inner value
+    pub fn as_ref(&self) -> &LockImpl {
+        unsafe { &*self.data }
+    }
+}
+
+impl Default for Lock {
+    fn default() -> Self {
+        let lock = LockImpl {
+            locked: Mutex::new(false),
+            released: Condvar::new(),
+        };
+        Self {
+            data: Box::into_raw(Box::new(lock)),
+        }
+    }
+}
+
+/// # PPL
+/// ```no_run
+/// /// Create a released lock
+/// @mangle_as("default_lock")
+/// fn default <:Type<Lock>> -> Lock
+/// ```
+#[no_mangle]
+pub extern "C" fn default_lock(_ty: Type) -> Lock {
+    Lock::default()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Wait until lock is released and acquire it
+/// @mangle_as("acquire_lock")
+/// fn acquire <:&Lock>
+/// ```
+#[no_mangle]
+pub extern "C" fn acquire_lock(lock: &Lock) {
+    let lock = lock.as_ref();
+    let mut locked = lock.locked.lock().unwrap();
+    while *locked {
+        locked = lock.released.wait(locked).unwrap();
+    }
+    *locked = true;
+}
+
+/// # PPL
+/// ```no_run
+/// /// Release previously acquired lock.
+/// /// Panics, if lock isn't acquired
+/// @mangle_as("release_lock")
+/// fn release <:&Lock>
+/// ```
+#[no_mangle]
+pub extern "C" fn release_lock(lock: &Lock) {
+    let lock = lock.as_ref();
+    let mut locked = lock.locked.lock().unwrap();
+    assert!(*locked, "Releasing lock, that isn't acquired");
+    *locked = false;
+    lock.released.notify_one();
+}
+
+/// # PPL
+/// ```no_run
+/// fn destroy <:&mut Lock>
+/// ```
+#[no_mangle]
+pub extern "C" fn destroy_lock(x: &mut Lock) {
+    let _ = unsafe { Box::from_raw(x.data) };
+}
+
+/// # PPL
+/// ```no_run
+/// /// Clones are independent released locks
+/// @mangle_as("clone_lock")
+/// fn clone <:&Lock> -> Lock
+/// ```
+#[no_mangle]
+pub extern "C" fn clone_lock(_x: &Lock) -> Lock {
+    Lock::default()
+}
+
+/// PPL's AtomicInteger type.
+/// Wrapper around pointer to [`AtomicI64`].
+///
+/// # PPL
+/// ```no_run
+/// type AtomicIntegerImpl
+///
+/// type AtomicInteger:
+///     impl: Reference<AtomicIntegerImpl>
+/// ```
+#[repr(C)]
+pub struct AtomicInteger {
+    pub data: *mut AtomicI64,
+}
+
+impl AtomicInteger {
+    /// Get the inner value
+    pub fn as_ref(&self) -> &AtomicI64 {
+        unsafe { &*self.data }
+    }
+}
+
+impl From<i64> for AtomicInteger {
+    fn from(value: i64) -> Self {
+        Self {
+            data: Box::into_raw(Box::new(AtomicI64::new(value))),
+        }
+    }
+}
+
+/// Convert [`Integer`] to [`i64`], that may be stored in [`AtomicInteger`]
+fn to_i64(value: Integer) -> i64 {
+    let value = value.as_ref();
+    value
+        .to_i64()
+        .expect(&format!("`{value}` doesn't fit into atomic integer"))
+}
+
+/// # PPL
+/// ```no_run
+/// @mangle_as("default_atomic_integer")
+/// fn default <:Type<AtomicInteger>> -> AtomicInteger
+/// ```
+#[no_mangle]
+pub extern "C" fn default_atomic_integer(_ty: Type) -> AtomicInteger {
+    0.into()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Create atomic integer with initial value.
+/// /// Panics, if value doesn't fit into `I64`
+/// @mangle_as("atomic_integer_from_integer")
+/// fn AtomicInteger from <:Integer> -> AtomicInteger
+/// ```
+#[no_mangle]
+pub extern "C" fn atomic_integer_from_integer(value: Integer) -> AtomicInteger {
+    to_i64(value).into()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Get current value of atomic integer
+/// @mangle_as("atomic_integer_load")
+/// fn load <:&AtomicInteger> -> Integer
+/// ```
+#[no_mangle]
+pub extern "C" fn atomic_integer_load(x: &AtomicInteger) -> Integer {
+    integer_from_i64(x.as_ref().load(Ordering::SeqCst))
+}
+
+/// # PPL
+/// ```no_run
+/// /// Set value of atomic integer.
+/// /// Panics, if value doesn't fit into `I64`
+/// @mangle_as("atomic_integer_store")
+/// fn store <:Integer> to <:&AtomicInteger>
+/// ```
+#[no_mangle]
+pub extern "C" fn atomic_integer_store(value: Integer, x: &AtomicInteger) {
+    x.as_ref().store(to_i64(value), Ordering::SeqCst);
+}
+
+/// # PPL
+/// ```no_run
+/// /// Atomically add value to atomic integer and return the previous value.
+/// /// Wraps around on overflow
+/// @mangle_as("atomic_integer_fetch_add")
+/// fn add <:Integer> to <:&AtomicInteger> -> Integer
+/// ```
+#[no_mangle]
+pub extern "C" fn atomic_integer_fetch_add(value: Integer, x: &AtomicInteger) -> Integer {
+    integer_from_i64(x.as_ref().fetch_add(to_i64(value), Ordering::SeqCst))
+}
+
+/// # PPL
+/// ```no_run
+/// fn destroy <:&mut AtomicInteger>
+/// ```
+#[no_mangle]
+pub extern "C" fn destroy_atomic_integer(x: &mut AtomicInteger) {
+    let _ = unsafe { Box::from_raw(x.data) };
+}
+
+/// # PPL
+/// ```no_run
+/// @mangle_as("clone_atomic_integer")
+/// fn clone <:&AtomicInteger> -> AtomicInteger
+/// ```
+#[no_mangle]
+pub extern "C" fn clone_atomic_integer(x: &AtomicInteger) -> AtomicInteger {
+    x.as_ref().load(Ordering::SeqCst).into()
+}
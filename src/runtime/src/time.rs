@@ -0,0 +1,140 @@
+use std::{
+    sync::OnceLock,
+    time::{Instant, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{integer_from_i64, integer_from_u64, Integer};
+
+/// Seconds in a day
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// Moment, from which monotonic time is measured
+static START: OnceLock<Instant> = OnceLock::new();
+
+/// # PPL
+/// ```no_run
+/// /// Nanoseconds, elapsed since some fixed moment in the past.
+/// /// Never decreases, so it's suitable for measuring durations
+/// @mangle_as("monotonic_nanoseconds")
+/// fn now -> Integer
+/// ```
+#[no_mangle]
+pub extern "C" fn monotonic_nanoseconds() -> Integer {
+    let start = START.get_or_init(Instant::now);
+    rug::Integer::from(start.elapsed().as_nanos()).into()
+}
+
+/// # PPL
+/// ```no_run
+/// /// Seconds, elapsed since Unix epoch (1970-01-01 00:00:00 UTC)
+/// @mangle_as("unix_time")
+/// fn unix time -> Integer
+/// ```
+#[no_mangle]
+pub extern "C" fn unix_time() -> Integer {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    integer_from_u64(seconds)
+}
+
+/// UTC date and time components of moment `seconds` after Unix epoch:
+/// `[year, month, day, hour, minute, second]`.
+///
+/// Uses days-to-civil algorithm by Howard Hinnant
+fn date_time(seconds: &Integer) -> [i64; 6] {
+    let seconds = seconds
+        .as_ref()
+        .to_i64()
+        .expect("unix time doesn't fit into 64 bits");
+
+    let days = seconds.div_euclid(SECONDS_PER_DAY);
+    let time = seconds.rem_euclid(SECONDS_PER_DAY);
+
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let day_of_era = z.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    [year, month, day, time / 3600, time % 3600 / 60, time % 60]
+}
+
+/// # PPL
+/// ```no_run
+/// @mangle_as("unix_time_year")
+/// fn year of unix time <seconds: &Integer> -> Integer
+/// ```
+#[no_mangle]
+pub extern "C" fn unix_time_year(seconds: &Integer) -> Integer {
+    integer_from_i64(date_time(seconds)[0])
+}
+
+/// # PPL
+/// ```no_run
+/// @mangle_as("unix_time_month")
+/// fn month of unix time <seconds: &Integer> -> Integer
+/// ```
+#[no_mangle]
+pub extern "C" fn unix_time_month(seconds: &Integer) -> Integer {
+    integer_from_i64(date_time(seconds)[1])
+}
+
+/// # PPL
+/// ```no_run
+/// @mangle_as("unix_time_day")
+/// fn day of unix time <seconds: &Integer> -> Integer
+/// ```
+#[no_mangle]
+pub extern "C" fn unix_time_day(seconds: &Integer) -> Integer {
+    integer_from_i64(date_time(seconds)[2])
+}
+
+/// # PPL
+/// ```no_run
+/// @mangle_as("unix_time_hour")
+/// fn hour of unix time <seconds: &Integer> -> Integer
+/// ```
+#[no_mangle]
+pub extern "C" fn unix_time_hour(seconds: &Integer) -> Integer {
+    integer_from_i64(date_time(seconds)[3])
+}
+
+/// # PPL
+/// ```no_run
+/// @mangle_as("unix_time_minute")
+/// fn minute of unix time <seconds: &Integer> -> Integer
+/// ```
+#[no_mangle]
+pub extern "C" fn unix_time_minute(seconds: &Integer) -> Integer {
+    integer_from_i64(date_time(seconds)[4])
+}
+
+/// # PPL
+/// ```no_run
+/// @mangle_as("unix_time_second")
+/// fn second of unix time <seconds: &Integer> -> Integer
+/// ```
+#[no_mangle]
+pub extern "C" fn unix_time_second(seconds: &Integer) -> Integer {
+    integer_from_i64(date_time(seconds)[5])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn components() {
+        let at = |seconds: i64| date_time(&seconds.into());
+
+        assert_eq!(at(0), [1970, 1, 1, 0, 0, 0]);
+        assert_eq!(at(951782400 + 3723), [2000, 2, 29, 1, 2, 3]);
+        assert_eq!(at(-1), [1969, 12, 31, 23, 59, 59]);
+    }
+}
@@ -1,6 +1,11 @@
+use std::mem::ManuallyDrop;
+
 use crate::{Integer, String};
 
-/// Runtime type information
+/// Runtime type information.
+///
+/// Type information is stored in global variables and passed by value,
+/// so runtime never releases its fields
 ///
 /// # PPL
 /// ```no_run
@@ -10,6 +15,6 @@ use crate::{Integer, String};
 /// ```
 #[repr(C)]
 pub struct Type {
-    pub name: String,
-    pub size: Integer,
+    pub name: ManuallyDrop<String>,
+    pub size: ManuallyDrop<Integer>,
 }
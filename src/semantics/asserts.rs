@@ -0,0 +1,71 @@
+use derive_visitor::{DriveMut, VisitorMut};
+
+use crate::hir::{Block, Expression, FunctionData, ModuleData, Statement};
+
+/// Is this a builtin `assert` function?
+fn is_assert(f: &FunctionData) -> bool {
+    matches!(
+        f.mangled_name.as_deref(),
+        Some("assert" | "assert_condition")
+    )
+}
+
+/// Removes assertions from code, that is compiled with optimizations.
+/// Conditions of removed assertions aren't evaluated
+#[derive(VisitorMut)]
+#[visitor(Statement(exit), ModuleData(exit))]
+pub struct AssertsStripper;
+
+impl AssertsStripper {
+    fn exit_module_data(&mut self, module: &mut ModuleData) {
+        module
+            .monomorphized_functions
+            .iter_mut()
+            .for_each(|f| f.drive_mut(self))
+    }
+
+    fn exit_statement(&mut self, stmt: &mut Statement) {
+        if let Statement::Expression(Expression::Call(call)) = stmt
+            && is_assert(&call.function.read().unwrap())
+        {
+            *stmt = Block { statements: vec![] }.into();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ast,
+        compilation::Compiler,
+        hir::{self, ModuleData, Statement},
+        semantics::{ModuleContext, ToHIR},
+    };
+
+    /// Lower module and return its top-level statements
+    fn statements(source: &str, strip_asserts: bool) -> Vec<Statement> {
+        let mut compiler = Compiler::new();
+        compiler.strip_asserts = strip_asserts;
+        let mut context = ModuleContext::new(ModuleData::default(), &mut compiler);
+        source
+            .parse::<ast::Module>()
+            .unwrap()
+            .to_hir(&mut context)
+            .unwrap()
+            .statements
+    }
+
+    #[test]
+    fn strip_asserts() {
+        let source = "assert (1 == 2)\nassert (1 == 1) \"math works\"";
+
+        let kept = statements(source, false);
+        assert!(matches!(kept.first(), Some(Statement::Expression(_))));
+
+        let stripped = statements(source, true);
+        assert!(!stripped.is_empty());
+        assert!(stripped.iter().all(
+            |s| matches!(s, Statement::Block(hir::Block { statements }) if statements.is_empty())
+        ));
+    }
+}
@@ -16,7 +16,8 @@ use super::Context;
     Return(exit),
     Initializer(exit),
     Call(exit),
-    VariableData(exit)
+    VariableData(exit),
+    ImplicitConversion(exit)
 )]
 pub struct Clonner<'ctx, C: Context> {
     context: &'ctx mut C,
@@ -86,4 +87,11 @@ impl<'ctx, C: Context> Clonner<'ctx, C> {
             self.clone_expr(arg);
         }
     }
+
+    fn exit_implicit_conversion(&mut self, conversion: &mut ImplicitConversion) {
+        // Trait object owns its value
+        if let ImplicitConversionKind::TraitObject(_) = conversion.kind {
+            self.clone_expr(&mut conversion.expression);
+        }
+    }
 }
@@ -0,0 +1,283 @@
+use std::collections::HashMap;
+
+use derive_more::Display;
+
+use crate::{
+    hir::{Call, Declaration, Expression, Literal, ParameterOrVariable, Return, Statement},
+    mutability::Mutable,
+    named::Named,
+    syntax::Ranged,
+};
+
+use super::error::NotConstEvaluable;
+
+/// Value of expression, evaluated at compile time
+#[derive(Debug, Display, Clone, PartialEq, Eq)]
+pub enum ConstValue {
+    Integer(rug::Integer),
+    Bool(bool),
+}
+
+/// Trait for evaluating expressions at compile time
+pub trait ConstEval {
+    /// Evaluate expression at compile time
+    fn const_eval(&self) -> Result<ConstValue, NotConstEvaluable>;
+}
+
+impl ConstEval for Expression {
+    fn const_eval(&self) -> Result<ConstValue, NotConstEvaluable> {
+        Interpreter::default().expression(self)
+    }
+}
+
+/// Maximum depth of nested calls, to stop on infinite recursion
+const MAX_CALL_DEPTH: usize = 256;
+
+/// Interpreter for pure subset of HIR: integers, booleans, arithmetic and `if`
+#[derive(Default)]
+struct Interpreter {
+    /// Values of parameters and local variables
+    variables: HashMap<String, ConstValue>,
+    /// Depth of nested calls
+    depth: usize,
+}
+
+/// What to do after evaluating a statement
+enum Flow {
+    /// Continue with next statement
+    Next,
+    /// Return value from function
+    Return(ConstValue),
+}
+
+impl Interpreter {
+    /// Evaluate expression
+    fn expression(&mut self, expr: &Expression) -> Result<ConstValue, NotConstEvaluable> {
+        let not_const = || NotConstEvaluable {
+            at: expr.range().into(),
+        };
+        match expr {
+            Expression::Literal(Literal::Integer { value, .. }) => {
+                Ok(ConstValue::Integer(value.clone()))
+            }
+            Expression::Literal(Literal::Bool { value, .. }) => Ok(ConstValue::Bool(*value)),
+            Expression::VariableReference(var) => {
+                if let Some(value) = self.variables.get(var.variable.name().as_ref()) {
+                    return Ok(value.clone());
+                }
+
+                let ParameterOrVariable::Variable(variable) = &var.variable else {
+                    return Err(not_const());
+                };
+                let initializer = {
+                    let variable = variable.read().unwrap();
+                    if variable.is_mutable() {
+                        return Err(not_const());
+                    }
+                    variable.initializer.clone().ok_or_else(not_const)?
+                };
+                Interpreter::default().expression(&initializer)
+            }
+            // References, dereferences and copies don't change value
+            Expression::ImplicitConversion(conversion) => self.expression(&conversion.expression),
+            Expression::Call(call) => self.call(call),
+            _ => Err(not_const()),
+        }
+    }
+
+    /// Evaluate function call
+    fn call(&mut self, call: &Call) -> Result<ConstValue, NotConstEvaluable> {
+        let not_const = || NotConstEvaluable {
+            at: call.range().into(),
+        };
+        if self.depth >= MAX_CALL_DEPTH {
+            return Err(not_const());
+        }
+
+        let args: Vec<_> = call
+            .args
+            .iter()
+            .map(|arg| self.expression(arg))
+            .try_collect()?;
+
+        let (mangled_name, parameters, body) = {
+            let f = call.function.read().unwrap();
+            (
+                f.mangled_name.clone(),
+                f.parameters()
+                    .map(|p| p.name().to_string())
+                    .collect::<Vec<_>>(),
+                f.body.clone(),
+            )
+        };
+        if let Some(name) = mangled_name {
+            return builtin(&name, &args).ok_or_else(not_const);
+        }
+
+        let mut callee = Interpreter {
+            variables: parameters.into_iter().zip(args).collect(),
+            depth: self.depth + 1,
+        };
+        match callee.block(&body)? {
+            Flow::Return(value) => Ok(value),
+            Flow::Next => Err(not_const()),
+        }
+    }
+
+    /// Evaluate block of statements
+    fn block(&mut self, statements: &[Statement]) -> Result<Flow, NotConstEvaluable> {
+        for statement in statements {
+            if let Flow::Return(value) = self.statement(statement)? {
+                return Ok(Flow::Return(value));
+            }
+        }
+        Ok(Flow::Next)
+    }
+
+    /// Evaluate statement
+    fn statement(&mut self, statement: &Statement) -> Result<Flow, NotConstEvaluable> {
+        match statement {
+            // Value is unused, but expression must still be pure
+            Statement::Expression(expr) => {
+                self.expression(expr)?;
+                Ok(Flow::Next)
+            }
+            Statement::Declaration(Declaration::Variable(variable)) => {
+                let variable = variable.read().unwrap();
+                let value = match &variable.initializer {
+                    Some(initializer) => self.expression(initializer)?,
+                    None => {
+                        return Err(NotConstEvaluable {
+                            at: statement.range().into(),
+                        })
+                    }
+                };
+                self.variables.insert(variable.name().to_string(), value);
+                Ok(Flow::Next)
+            }
+            Statement::Return(Return::Implicit { value })
+            | Statement::Return(Return::Explicit {
+                value: Some(value), ..
+            }) => Ok(Flow::Return(self.expression(value)?)),
+            Statement::If(stmt) => {
+                if self.condition(&stmt.condition)? {
+                    return self.block(&stmt.body);
+                }
+                for else_if in &stmt.else_ifs {
+                    if self.condition(&else_if.condition)? {
+                        return self.block(&else_if.body);
+                    }
+                }
+                match &stmt.else_block {
+                    Some(else_block) => self.block(&else_block.body),
+                    None => Ok(Flow::Next),
+                }
+            }
+            Statement::Block(block) => self.block(&block.statements),
+            _ => Err(NotConstEvaluable {
+                at: statement.range().into(),
+            }),
+        }
+    }
+
+    /// Evaluate condition of `if`
+    fn condition(&mut self, condition: &Expression) -> Result<bool, NotConstEvaluable> {
+        match self.expression(condition)? {
+            ConstValue::Bool(value) => Ok(value),
+            _ => Err(NotConstEvaluable {
+                at: condition.range().into(),
+            }),
+        }
+    }
+}
+
+/// Evaluate builtin function by its mangled name.
+/// Returns [`None`], if function isn't supported or fails for these arguments
+fn builtin(name: &str, args: &[ConstValue]) -> Option<ConstValue> {
+    use ConstValue::*;
+
+    let value = match (name, args) {
+        ("integer_eq_integer", [Integer(x), Integer(y)]) => Bool(x == y),
+        ("integer_less_integer", [Integer(x), Integer(y)]) => Bool(x < y),
+        ("minus_integer", [Integer(x)]) => Integer(-x.clone()),
+        ("integer_plus_integer", [Integer(x), Integer(y)]) => Integer((x + y).into()),
+        ("integer_star_integer", [Integer(x), Integer(y)]) => Integer((x * y).into()),
+        ("integer_power_integer", [Integer(x), Integer(n)]) => Integer(x.clone().pow(n.to_u32()?)),
+        ("integer_and_integer", [Integer(x), Integer(y)]) => Integer((x & y).into()),
+        ("integer_or_integer", [Integer(x), Integer(y)]) => Integer((x | y).into()),
+        ("integer_xor_integer", [Integer(x), Integer(y)]) => Integer((x ^ y).into()),
+        ("integer_shl_integer", [Integer(x), Integer(n)]) => Integer((x << n.to_u32()?).into()),
+        ("integer_shr_integer", [Integer(x), Integer(n)]) => Integer((x >> n.to_u32()?).into()),
+        (_, [Integer(_), Integer(y)]) if *y == 0 => return None,
+        ("integer_mod_integer", [Integer(x), Integer(y)]) => Integer(x.clone().modulo(y)),
+        ("integer_div_integer", [Integer(x), Integer(y)]) => Integer(x.clone().div_floor(y)),
+        ("integer_modulo_integer", [Integer(x), Integer(y)]) => Integer(x.clone().rem_floor(y)),
+        ("integer_rem_integer", [Integer(x), Integer(y)]) => Integer(x.clone().rem_trunc(y)),
+        _ => return None,
+    };
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ast,
+        compilation::Compiler,
+        hir::ModuleData,
+        semantics::{error::Error, ModuleContext, ToHIR},
+    };
+    use pretty_assertions::assert_eq;
+
+    /// Lower expression and evaluate it at compile time
+    fn eval(source: &str) -> Result<ConstValue, NotConstEvaluable> {
+        source
+            .parse::<crate::ast::Expression>()
+            .unwrap()
+            .to_hir_without_context()
+            .unwrap()
+            .const_eval()
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(eval("1 + 2 * 3"), Ok(ConstValue::Integer(7.into())));
+        assert_eq!(eval("2 - 5"), Ok(ConstValue::Integer((-3).into())));
+    }
+
+    #[test]
+    fn booleans() {
+        assert_eq!(eval("not (1 < 2)"), Ok(ConstValue::Bool(false)));
+        assert_eq!(eval("true and 1 == 1"), Ok(ConstValue::Bool(true)));
+    }
+
+    #[test]
+    fn not_const() {
+        assert!(eval("\"string\"").is_err());
+        assert!(eval("1 / 2").is_err());
+    }
+
+    /// Lower module and return its errors
+    fn errors(source: &str) -> Vec<Error> {
+        let mut compiler = Compiler::new();
+        let mut context = ModuleContext::new(ModuleData::default(), &mut compiler);
+        match source.parse::<ast::Module>().unwrap().to_hir(&mut context) {
+            Ok(_) => vec![],
+            Err(err) => err.errors,
+        }
+    }
+
+    #[test]
+    fn expression_statements() {
+        assert_eq!(
+            errors("fn answer:\n\t1 + 1\n\treturn 42\n\nstatic assert (answer == 42)"),
+            vec![]
+        );
+
+        let errors = errors("fn answer:\n\tprintln 1\n\treturn 42\n\nstatic assert (answer == 42)");
+        assert!(
+            matches!(errors.as_slice(), [Error::NotConstEvaluable(_)]),
+            "{errors:?}"
+        );
+    }
+}
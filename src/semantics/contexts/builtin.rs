@@ -57,7 +57,7 @@ impl BuiltinTraits<'_> {
             .as_trait()
     }
 
-    builtin_traits!(clonnable, destructible);
+    builtin_traits!(clonnable, destructible, iterator);
 }
 
 /// Helper struct to get builtin types
@@ -84,7 +84,7 @@ impl BuiltinTypes<'_> {
             .expect(&format!("Builtin type `{name}` should be present"))
     }
 
-    builtin_types!(none, bool, integer, rational, string, reference, i32, f64);
+    builtin_types!(none, never, bool, integer, rational, string, reference, i32, f64);
 
     /// Get builtin type for types
     pub fn type_(&self) -> Type {
@@ -1,12 +1,14 @@
 use std::fmt::Display;
 
+use indexmap::IndexSet;
+
 use crate::{
     compilation::Compiler,
-    hir::{Function, FunctionData, FunctionNamePart, ModuleData, Type, Typed},
+    hir::{Function, FunctionData, FunctionNamePart, ModuleData, Statement, Type, Typed},
     semantics::{AddDeclaration, ConvertibleTo, FindDeclaration, Implements},
 };
 
-use super::{BuiltinContext, GenericContext};
+use super::{Breaks, BuiltinContext, GenericContext};
 
 use crate::DataHolder;
 
@@ -47,6 +49,11 @@ pub trait Context: FindDeclaration + AddDeclaration + Display {
         Context::parent(self).and_then(|p| p.function())
     }
 
+    /// Get breaks of the innermost loop, if inside of loop
+    fn breaks_mut(&mut self) -> Option<&mut Breaks> {
+        Context::parent_mut(self).and_then(|p| p.breaks_mut())
+    }
+
     /// Get module context of builtin module
     fn builtin(&self) -> BuiltinContext
     where
@@ -64,12 +71,40 @@ pub trait Context: FindDeclaration + AddDeclaration + Display {
     where
         Self: Sized,
     {
+        let n = trait_fn.name_parts().len();
+        // Implementation may be exported by imported module without being imported itself,
+        // e.g. when library implements its trait for builtin types
+        let imported_modules: IndexSet<_> = self
+            .module()
+            .statements
+            .iter()
+            .filter_map(|s| match s {
+                Statement::Use(u) => Some(u.module),
+                _ => None,
+            })
+            .collect();
+        let imported: Vec<_> = imported_modules
+            .into_iter()
+            .flat_map(|m| {
+                let module = m.data(self.compiler());
+                module
+                    .functions_with_n_name_parts(n)
+                    .filter(|f| module.is_function_exported(f))
+                    .cloned()
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        let funcs: Vec<_> = self
+            .functions_with_n_name_parts(n)
+            .into_iter()
+            .chain(imported)
+            .collect();
+
         let self_ty: Type = trait_fn.tr.clone().unwrap().self_type().into();
         let mut context = GenericContext::for_generics(vec![self_ty.clone()], self);
         if let Some(concrete) = self_type_specialization.clone() {
             context.map_generic(self_ty, concrete);
         }
-        let funcs = context.functions_with_n_name_parts(trait_fn.name_parts().len());
         funcs.into_iter().find(|f| {
             let params_ok = trait_fn
                 .name_parts()
@@ -113,6 +148,17 @@ pub trait Context: FindDeclaration + AddDeclaration + Display {
                 .ok()?
                 .into_iter()
                 .next(),
+            // Trait objects are destroyed through their virtual table
+            Type::Trait(_) => self
+                .builtin()
+                .traits()
+                .destructible()
+                .read()
+                .unwrap()
+                .functions
+                .values()
+                .next()
+                .cloned(),
             _ => None,
         }
     }
@@ -129,10 +175,41 @@ pub trait Context: FindDeclaration + AddDeclaration + Display {
                 .ok()?
                 .into_iter()
                 .next(),
+            // Trait objects are cloned through their virtual table
+            Type::Trait(_) => self
+                .builtin()
+                .traits()
+                .clonnable()
+                .read()
+                .unwrap()
+                .functions
+                .values()
+                .next()
+                .cloned(),
             _ => None,
         }
     }
 
+    /// Find user-defined `fn init <:T> -> T` function for type.
+    ///
+    /// Constructors inside of `init` itself don't use it
+    fn init_for(&self, ty: Type) -> Option<Function>
+    where
+        Self: Sized,
+    {
+        let current = self.function();
+        self.functions_with_n_name_parts(2).into_iter().find(|f| {
+            if current.as_ref().is_some_and(|current| current.is_same(f)) {
+                return false;
+            }
+
+            let f = f.read().unwrap();
+            matches!(&f.name_parts()[0], FunctionNamePart::Text(t) if t.as_str() == "init")
+                && matches!(&f.name_parts()[1], FunctionNamePart::Parameter(p) if p.ty() == ty)
+                && f.return_type == ty
+        })
+    }
+
     /// Debug function to print hierarchy of contexts
     fn print_contexts_hierarchy(&self)
     where
@@ -153,3 +230,49 @@ pub trait Context: FindDeclaration + AddDeclaration + Display {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use tempdir::TempDir;
+
+    use super::*;
+    use crate::semantics::ModuleContext;
+
+    /// Find implementation of `Named` trait for `Integer` from module,
+    /// that imports only the trait from `named.ppl` with `source`
+    fn implementation(source: &str) -> Option<Function> {
+        let dir = TempDir::new("ppl").unwrap();
+        fs::create_dir(dir.path().join("src")).unwrap();
+        fs::write(dir.path().join("src/named.ppl"), source).unwrap();
+        fs::write(dir.path().join("src/main.ppl"), "use named.Named").unwrap();
+
+        let mut compiler = Compiler::new().at(dir.path());
+        let main = compiler.compile("main").unwrap();
+        let data = main.data(&compiler).clone();
+        let mut context = ModuleContext::new(data, &mut compiler);
+
+        let Some(Type::Trait(named)) = context.find_type("Named") else {
+            panic!("`Named` trait isn't imported");
+        };
+        let trait_fn = named.read().unwrap().all_functions().next().unwrap();
+        let integer = context.builtin().types().integer();
+        context.find_implementation(&trait_fn.read().unwrap(), Some(integer))
+    }
+
+    const NAMED: &str = "trait Named:\n\tfn name of <x: Self> -> String\n\nfn name of <x: Integer> => \"integer\"\n";
+
+    #[test]
+    fn implementation_from_imported_module() {
+        let f = implementation(NAMED).unwrap();
+        let parameter = f.read().unwrap().parameters().next().unwrap();
+        assert!(parameter.ty().is_integer());
+    }
+
+    #[test]
+    fn implementation_must_be_exported() {
+        assert!(implementation(&format!("{NAMED}\nexport Named, name of")).is_some());
+        assert!(implementation(&format!("{NAMED}\nexport Named")).is_none());
+    }
+}
@@ -1,13 +1,13 @@
 use std::fmt::Display;
 
 use crate::{
-    hir::{Class, Function, ParameterOrVariable, Trait, Type, Variable},
+    hir::{Function, ParameterOrVariable, Type, Variable},
     named::Named,
     semantics::{AddDeclaration, FindDeclaration, FindDeclarationHere},
     DataHolder,
 };
 
-use super::Context;
+use super::{Breaks, Context};
 
 /// Context for lowering body of function
 pub struct FunctionContext<'p> {
@@ -54,6 +54,30 @@ impl FindDeclarationHere for FunctionContext<'_> {
             .find(|p| p.name() == name)
             .cloned()
     }
+
+    fn variable_names_here(&self) -> Vec<String> {
+        self.variables
+            .iter()
+            .map(|v| v.name().to_string())
+            .chain(
+                self.function
+                    .read()
+                    .unwrap()
+                    .parameters()
+                    .map(|p| p.name().to_string()),
+            )
+            .collect()
+    }
+
+    fn type_names_here(&self) -> Vec<String> {
+        self.function
+            .read()
+            .unwrap()
+            .generic_types
+            .iter()
+            .map(|t| t.name().to_string())
+            .collect()
+    }
 }
 
 impl FindDeclaration for FunctionContext<'_> {
@@ -82,14 +106,6 @@ impl AddDeclaration for FunctionContext<'_> {
         Some(self.parent)
     }
 
-    fn add_type(&mut self, _ty: Class) {
-        todo!("local types")
-    }
-
-    fn add_trait(&mut self, _tr: Trait) {
-        todo!("local traits")
-    }
-
     fn add_function(&mut self, f: Function) {
         // TODO: local functions
         self.parent.add_function(f)
@@ -112,4 +128,9 @@ impl Context for FunctionContext<'_> {
     fn function(&self) -> Option<Function> {
         Some(self.function.clone())
     }
+
+    fn breaks_mut(&mut self) -> Option<&mut Breaks> {
+        // Loops of outer scope can't be broken from function
+        None
+    }
 }
@@ -115,6 +115,13 @@ impl FindDeclarationHere for GenericContext<'_> {
             .find(|p| p.name() == name)
             .cloned()
     }
+
+    fn type_names_here(&self) -> Vec<String> {
+        self.generic_parameters
+            .iter()
+            .map(|p| p.name().to_string())
+            .collect()
+    }
 }
 
 impl FindDeclaration for GenericContext<'_> {
@@ -0,0 +1,93 @@
+use std::fmt::Display;
+
+use crate::{
+    hir::Type,
+    semantics::{AddDeclaration, FindDeclaration, FindDeclarationHere},
+};
+
+use super::Context;
+
+/// Values, passed out of loop by `break` statements
+#[derive(Debug, Clone, PartialEq)]
+pub struct Breaks {
+    /// May `break` pass a value out of this loop?
+    pub with_value: bool,
+    /// Type of values, passed by the first `break`, if any
+    pub ty: Option<Type>,
+}
+
+/// Context for lowering body of loop
+pub struct LoopContext<'p> {
+    /// Values, passed out of this loop
+    pub breaks: Breaks,
+
+    /// Parent context for this loop
+    pub parent: &'p mut dyn Context,
+}
+
+impl<'p> LoopContext<'p> {
+    /// Create context for `loop`, that may break with value
+    pub fn for_loop(parent: &'p mut impl Context) -> Self {
+        Self {
+            breaks: Breaks {
+                with_value: true,
+                ty: None,
+            },
+            parent,
+        }
+    }
+
+    /// Create context for `while`, that can't break with value
+    pub fn for_while(parent: &'p mut impl Context) -> Self {
+        Self {
+            breaks: Breaks {
+                with_value: false,
+                ty: None,
+            },
+            parent,
+        }
+    }
+
+    /// Run code in this context
+    pub fn run<R>(&mut self, f: impl FnOnce(&mut Self) -> R) -> R {
+        f(self)
+    }
+}
+
+impl Display for LoopContext<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "LoopContext")?;
+        if let Some(ty) = &self.breaks.ty {
+            writeln!(f, "\tbreaks with: {ty}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FindDeclarationHere for LoopContext<'_> {}
+
+impl FindDeclaration for LoopContext<'_> {
+    fn parent(&self) -> Option<&dyn FindDeclaration> {
+        Some(self.parent as _)
+    }
+}
+
+impl AddDeclaration for LoopContext<'_> {
+    fn parent_mut(&mut self) -> Option<&mut dyn AddDeclaration> {
+        Some(self.parent as _)
+    }
+}
+
+impl Context for LoopContext<'_> {
+    fn parent(&self) -> Option<&dyn Context> {
+        Some(self.parent)
+    }
+
+    fn parent_mut(&mut self) -> Option<&mut dyn Context> {
+        Some(self.parent)
+    }
+
+    fn breaks_mut(&mut self) -> Option<&mut Breaks> {
+        Some(&mut self.breaks)
+    }
+}
@@ -10,6 +10,9 @@ pub use function::*;
 mod generic;
 pub use generic::*;
 
+mod r#loop;
+pub use r#loop::*;
+
 mod module;
 pub use module::*;
 
@@ -1,7 +1,7 @@
 use std::fmt::Display;
 
 use crate::{
-    hir::{Class, Function, Trait, Type, Variable},
+    hir::{Function, Trait, Type, Variable},
     named::Named,
     semantics::{AddDeclaration, FindDeclaration, FindDeclarationHere},
 };
@@ -56,6 +56,10 @@ impl FindDeclarationHere for TraitContext<'_> {
             .cloned()
             .collect()
     }
+
+    fn type_names_here(&self) -> Vec<String> {
+        vec!["Self".to_string()]
+    }
 }
 
 impl FindDeclaration for TraitContext<'_> {
@@ -69,14 +73,6 @@ impl AddDeclaration for TraitContext<'_> {
         Some(self.parent as _)
     }
 
-    fn add_type(&mut self, _ty: Class) {
-        todo!("types in traits")
-    }
-
-    fn add_trait(&mut self, _tr: Trait) {
-        todo!("traits in traits?")
-    }
-
     fn add_function(&mut self, f: Function) {
         f.write().unwrap().tr = Some(self.tr.clone());
         self.tr
@@ -1,5 +1,8 @@
 use crate::{
-    hir::{Class, Expression, FunctionType, Generic, GenericType, SelfType, Trait, Type, Typed},
+    hir::{
+        Class, Expression, FunctionType, Generic, GenericType, Literal, SelfType, Trait, Type,
+        Typed,
+    },
     mutability::Mutable,
     semantics::error::ReferenceMutToImmutable,
     syntax::Ranged,
@@ -37,11 +40,35 @@ impl ConvertibleToRequest<'_, Type> {
         let from = self.from.without_ref();
         let to = self.to.without_ref();
 
+        // Diverging expressions may be used in place of any value.
+        // They bind generic parameter only if it's not bound yet,
+        // so that other arguments may still rebind it
+        if from.is_never() {
+            if let Type::Generic(_) = to
+                && context.get_specialized(to.clone()).is_none()
+            {
+                context.map_generic(to, from);
+            }
+            return Ok(true);
+        }
+
+        // Generic arguments behind mutable reference are invariant.
+        // Otherwise `&mut Array<&Integer>` parameter could be used
+        // to store immutable reference into `Array<&mut Integer>`
+        if self.to.is_mutable() {
+            let specialized = |ty: &Type| context.get_specialized(ty.clone()).unwrap_or(ty.clone());
+            if !same_reference_kinds(&specialized(&from), &specialized(&to)) {
+                return Ok(false);
+            }
+        }
+
         if let Some(specialized) = context.get_specialized(from.clone()) {
             return specialized.convertible_to(to).within(context);
         }
 
-        if let Some(specialized) = context.get_specialized(to.clone()) {
+        if let Some(specialized) = context.get_specialized(to.clone())
+            && !specialized.is_never()
+        {
             return from.convertible_to(specialized).within(context);
         }
 
@@ -57,7 +84,13 @@ impl ConvertibleToRequest<'_, Type> {
             Type::Trait(tr) => tr.convertible_to(to).within(context),
         }?;
 
-        if generic.is_generic() && convertible && generic != from && !matches!(from, Type::Trait(_))
+        // Only trait objects may specialize `Self`, other traits are constraints
+        let is_trait_object_for_self =
+            matches!(from, Type::Trait(_)) && matches!(generic, Type::SelfType(_));
+        if generic.is_generic()
+            && convertible
+            && generic != from
+            && (!matches!(from, Type::Trait(_)) || is_trait_object_for_self)
         {
             context.map_generic(generic, from);
         }
@@ -66,6 +99,42 @@ impl ConvertibleToRequest<'_, Type> {
     }
 }
 
+/// Check if generic argument of specialized type may be converted to another one.
+///
+/// Mutable references may be used in place of immutable ones, but not vice versa.
+/// References and values aren't interchangeable, because they have different layout
+fn argument_convertible(from: &Type, to: &Type, context: &mut impl Context) -> bool {
+    if let (Type::Class(_), Type::Class(_)) = (from, to) {
+        if from.is_any_reference() != to.is_any_reference() {
+            return false;
+        }
+        if from.is_any_reference() && from.is_immutable() && to.is_mutable() {
+            return false;
+        }
+    }
+
+    from.clone()
+        .convertible_to(to.clone())
+        .within(context)
+        .is_ok_and(|convertible| convertible)
+}
+
+/// Check that generic arguments of types have the same kinds of references at any nesting depth
+fn same_reference_kinds(from: &Type, to: &Type) -> bool {
+    from.generics()
+        .iter()
+        .zip(to.generics().iter())
+        .all(|(from, to)| {
+            if let (Type::Class(_), Type::Class(_)) = (from, to)
+                && (from.is_any_reference() != to.is_any_reference()
+                    || from.is_mutable() != to.is_mutable())
+            {
+                return false;
+            }
+            same_reference_kinds(from, to)
+        })
+}
+
 impl ConvertibleTo for Class {}
 impl ConvertibleToRequest<'_, Class> {
     /// Check if struct type can be converted to another type within context
@@ -85,11 +154,8 @@ impl ConvertibleToRequest<'_, Class> {
                         .iter()
                         .zip(to.generics().iter())
                         .all(|(from, to)| {
-                            from.clone()
-                                .convertible_to(to.clone())
-                                .within(context)
-                                // TODO: Add error
-                                .is_ok_and(|convertible| convertible)
+                            // TODO: Add error
+                            argument_convertible(from, to, context)
                         })
                 } else {
                     *from.read().unwrap() == *to
@@ -271,6 +337,14 @@ impl Convert for Expression {
     }
 }
 
+/// Generic types, used in `ty`, including itself
+fn generic_types(ty: &Type) -> Vec<Type> {
+    match ty {
+        Type::Generic(_) => vec![ty.clone()],
+        _ => ty.generics().iter().flat_map(generic_types).collect(),
+    }
+}
+
 /// Helper struct to perform check within context
 pub struct ConversionRequest {
     from: WithSourceLocation<Expression>,
@@ -283,6 +357,23 @@ impl ConversionRequest {
         let from = self.from.value.ty();
         let to = self.to.value;
 
+        // Integer literals are inferred to be of fixed-width type, if they fit in it
+        if let Expression::Literal(Literal::Integer { span, value, .. }) = &self.from.value
+            && let Type::Class(class) = &to
+            && class.read().unwrap().fits_integer(value)
+        {
+            return Ok(Literal::Integer {
+                span: span.clone(),
+                value: value.clone(),
+                ty: to,
+            }
+            .into());
+        }
+
+        if from.is_never() && !to.is_never() {
+            return Ok(self.from.value.coerce_never(to));
+        }
+
         let convertible = from.convertible_to(to.clone()).within(context)?;
 
         if !convertible {
@@ -293,6 +384,14 @@ impl ConversionRequest {
                     at: self.from.source_location.at.clone(),
                     source_file: self.from.source_location.source_file.clone(),
                 },
+                bindings: generic_types(&to)
+                    .into_iter()
+                    .filter_map(|generic| {
+                        context
+                            .get_specialized(generic.clone())
+                            .map(|ty| (generic, ty))
+                    })
+                    .collect(),
                 expected: TypeWithSpan {
                     ty: to,
                     at: self.to.source_location.at,
@@ -309,6 +408,20 @@ impl ConversionRequest {
             .into());
         }
 
+        // Static type of value is erased, when it's converted to trait
+        if let Type::Trait(tr) = &to
+            && let Type::Class(_) = from.without_ref()
+        {
+            let value = if from.is_any_reference() {
+                self.from.value.dereference()
+            } else if self.from.value.is_reference() {
+                self.from.value.copy()
+            } else {
+                self.from.value
+            };
+            return Ok(value.to_trait_object(tr.clone(), context)?);
+        }
+
         if from.is_any_reference() && to.is_any_reference() {
             return Ok(self.from.value);
         }
@@ -332,3 +445,83 @@ impl ConversionRequest {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{compilation::Compiler, hir::ModuleData, semantics::ModuleContext};
+
+    #[test]
+    fn reference_mut_to_reference() {
+        let mut compiler = Compiler::new();
+        let mut context = ModuleContext::new(ModuleData::default(), &mut compiler);
+        let types = context.builtin().types();
+        let integer = types.integer();
+        let reference = types.reference_to(integer.clone());
+        let reference_mut = types.reference_mut_to(integer.clone());
+
+        let convertible = |context: &mut ModuleContext, from: &Type, to: &Type| {
+            from.convertible_to(to.clone()).within(context).unwrap()
+        };
+
+        assert!(convertible(&mut context, &reference_mut, &reference));
+
+        // Type<&mut Integer> -> Type<&Integer>
+        let types = context.builtin().types();
+        let of_reference = types.type_of(reference.clone());
+        let of_reference_mut = types.type_of(reference_mut.clone());
+        let of_integer = types.type_of(integer.clone());
+        assert!(convertible(&mut context, &of_reference_mut, &of_reference));
+        assert!(!convertible(&mut context, &of_reference, &of_reference_mut));
+        assert!(!convertible(&mut context, &of_reference, &of_integer));
+
+        // Type<Type<&mut Integer>> -> Type<Type<&Integer>>
+        let types = context.builtin().types();
+        let nested_reference = types.type_of(of_reference.clone());
+        let nested_reference_mut = types.type_of(of_reference_mut.clone());
+        assert!(convertible(
+            &mut context,
+            &nested_reference_mut,
+            &nested_reference
+        ));
+        assert!(!convertible(
+            &mut context,
+            &nested_reference,
+            &nested_reference_mut
+        ));
+
+        // Generic arguments behind `&mut` are invariant
+        let types = context.builtin().types();
+        let mut_of_reference = types.reference_mut_to(of_reference.clone());
+        let mut_of_reference_mut = types.reference_mut_to(of_reference_mut.clone());
+        let ref_of_reference = types.reference_to(of_reference.clone());
+        assert!(!convertible(
+            &mut context,
+            &mut_of_reference_mut,
+            &mut_of_reference
+        ));
+        assert!(convertible(
+            &mut context,
+            &mut_of_reference_mut,
+            &ref_of_reference
+        ));
+    }
+
+    #[test]
+    fn never_is_convertible_to_any_type() {
+        let mut compiler = Compiler::new();
+        let mut context = ModuleContext::new(ModuleData::default(), &mut compiler);
+        let types = context.builtin().types();
+        let never = types.never();
+        let integer = types.integer();
+        let string = types.reference_to(types.string());
+
+        let convertible = |context: &mut ModuleContext, from: &Type, to: &Type| {
+            from.convertible_to(to.clone()).within(context).unwrap()
+        };
+
+        assert!(convertible(&mut context, &never, &integer));
+        assert!(convertible(&mut context, &never, &string));
+        assert!(!convertible(&mut context, &integer, &never));
+    }
+}
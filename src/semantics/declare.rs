@@ -1,16 +1,27 @@
 use std::collections::HashMap;
 
 use indexmap::IndexMap;
+use inkwell::{
+    context::Context as LLVMContext,
+    intrinsics::Intrinsic,
+    types::{BasicMetadataTypeEnum, BasicType},
+    values::BasicMetadataValueEnum,
+};
 
 use crate::{
     ast,
-    hir::{self, Function, Trait, Type, Typed},
+    hir::{self, BuiltinClass, Function, Trait, Type, Typed},
+    ir::Types,
     syntax::Ranged,
     AddSourceLocation,
 };
 
 use super::{
-    error::{CantDeduceReturnType, Error, ReturnTypeMismatch},
+    error::{
+        CantDeduceReturnType, ConflictingAnnotations, Error, ExternWithBody,
+        IntrinsicSignatureMismatch, IntrinsicWithBody, MisplacedAnnotation, NonCAbiType,
+        Redeclaration, RelatedSpan, ReturnTypeMismatch, UnknownIntrinsic,
+    },
     Context, Convert, FunctionContext, GenericContext, Monomorphize, ToHIR, TraitContext,
 };
 
@@ -37,7 +48,6 @@ impl Declare for ast::FunctionDeclaration {
     type Definition = Function;
 
     fn declare(&self, context: &mut impl Context) -> Result<Self::Declaration, Error> {
-        // TODO: check for collision
         let generic_parameters: Vec<Type> = self.generic_parameters.to_hir(context)?;
 
         let (name_parts, return_type, generic_parameters) =
@@ -73,14 +83,186 @@ impl Declare for ast::FunctionDeclaration {
             _ => None,
         });
 
+        let hints: Vec<_> = self
+            .annotations
+            .iter()
+            .zip(&annotations)
+            .filter_map(|(annotation, hir)| match hir {
+                hir::Annotation::Inline => Some((annotation, hir::InlineHint::Always)),
+                hir::Annotation::NoInline => Some((annotation, hir::InlineHint::Never)),
+                _ => None,
+            })
+            .collect();
+        if let Some((first, hint)) = hints.first()
+            && let Some((other, _)) = hints.iter().find(|(_, h)| h != hint)
+        {
+            return Err(ConflictingAnnotations {
+                name: other.name.to_string(),
+                other: first.name.to_string(),
+                at: other.name.range().into(),
+            }
+            .into());
+        }
+        let inline_hint = hints.first().map(|(_, hint)| *hint);
+
+        let deprecated = annotations.iter().find_map(|a| match a {
+            hir::Annotation::Deprecated(message) => Some(message.clone()),
+            _ => None,
+        });
+
+        let mut mangled_name = mangled_name;
+        let extern_name =
+            self.annotations
+                .iter()
+                .zip(&annotations)
+                .find_map(|(annotation, hir)| match hir {
+                    hir::Annotation::Extern(name) => Some((annotation, name)),
+                    _ => None,
+                });
+        if let Some((annotation, name)) = extern_name {
+            if !self.body.is_empty() {
+                return Err(ExternWithBody {
+                    at: annotation.name.range().into(),
+                }
+                .into());
+            }
+            if let Some(mangle_as) = self
+                .annotations
+                .iter()
+                .find(|a| a.name.as_str() == "mangle_as")
+            {
+                return Err(ConflictingAnnotations {
+                    name: mangle_as.name.to_string(),
+                    other: annotation.name.to_string(),
+                    at: mangle_as.name.range().into(),
+                }
+                .into());
+            }
+
+            for (part, hir) in self.name_parts.iter().zip(&name_parts) {
+                if let (
+                    ast::FunctionNamePart::Parameter(p),
+                    hir::FunctionNamePart::Parameter(param),
+                ) = (part, hir)
+                    && !param.ty().has_c_abi()
+                {
+                    return Err(NonCAbiType {
+                        ty: param.ty(),
+                        at: p.ty.range().into(),
+                    }
+                    .into());
+                }
+            }
+            if !return_type.has_c_abi() {
+                return Err(NonCAbiType {
+                    ty: return_type,
+                    at: self
+                        .return_type
+                        .as_ref()
+                        .map_or(self.name_parts.range(), |ty| ty.range())
+                        .into(),
+                }
+                .into());
+            }
+
+            // Without explicit symbol, function is bound to its name: `fn puts <:&U8>` -> `puts`
+            mangled_name = Some(name.clone().unwrap_or_else(|| {
+                self.name_parts
+                    .iter()
+                    .filter_map(|part| match part {
+                        ast::FunctionNamePart::Text(t) => Some(t.as_str()),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+                    .join("_")
+            }));
+        }
+
+        let intrinsic = self
+            .annotations
+            .iter()
+            .zip(&annotations)
+            .find_map(|(annotation, hir)| match hir {
+                hir::Annotation::Intrinsic(name) => Some((annotation, name)),
+                _ => None,
+            });
+        if let Some((annotation, name)) = intrinsic {
+            if !self.body.is_empty() {
+                return Err(IntrinsicWithBody {
+                    at: annotation.name.range().into(),
+                }
+                .into());
+            }
+            if Intrinsic::find(name).is_none() {
+                return Err(UnknownIntrinsic {
+                    name: name.clone(),
+                    at: annotation.name.range().into(),
+                }
+                .into());
+            }
+            if let Some(other) = self
+                .annotations
+                .iter()
+                .find(|a| matches!(a.name.as_str(), "mangle_as" | "extern"))
+            {
+                return Err(ConflictingAnnotations {
+                    name: annotation.name.to_string(),
+                    other: other.name.to_string(),
+                    at: annotation.name.range().into(),
+                }
+                .into());
+            }
+
+            let parameters: Vec<Type> = name_parts
+                .iter()
+                .filter_map(|part| match part {
+                    hir::FunctionNamePart::Parameter(p) => Some(p.ty()),
+                    _ => None,
+                })
+                .collect();
+            if let Err(reason) = check_intrinsic_signature(name, &parameters, &return_type) {
+                return Err(IntrinsicSignatureMismatch {
+                    name: name.clone(),
+                    reason,
+                    at: self.name_parts.range().into(),
+                }
+                .into());
+            }
+
+            // LLVM recognizes functions with intrinsic names,
+            // so they are never looked up in runtime
+            mangled_name = Some(name.clone());
+        }
+
         let f = Function::new(
             hir::FunctionData::build(context.compiler().current_module(), self.keyword)
                 .with_generic_types(generic_parameters)
                 .with_name(name_parts)
                 .with_mangled_name(mangled_name)
+                .with_inline_hint(inline_hint)
+                .with_deprecation(deprecated)
                 .with_return_type(return_type),
         );
 
+        // Functions from other modules may be shadowed
+        let previous = context
+            .functions_with_format(&f.read().unwrap().name_format())
+            .get(f.name().as_ref())
+            .filter(|p| p.read().unwrap().module == context.compiler().current_module())
+            .cloned();
+        if let Some(previous) = previous {
+            return Err(Redeclaration {
+                name: f.name().to_string(),
+                at: self.name_parts.range().into(),
+                previous: vec![RelatedSpan {
+                    message: "previous declaration is here".to_string(),
+                    at: previous.read().unwrap().name_parts.range().into(),
+                    source_file: Some(context.compiler().current_file().clone()),
+                }],
+            }
+            .into());
+        }
+
         context.add_function(f.clone());
 
         Ok(f)
@@ -108,7 +290,8 @@ impl Declare for ast::FunctionDeclaration {
 
         if self.implicit_return {
             let mut return_type = f_context.function.read().unwrap().return_type.clone();
-            let expr: hir::Expression = body.pop().unwrap().try_into().unwrap();
+            let mut expr: hir::Expression = body.pop().unwrap().try_into().unwrap();
+            expr.monomorphize_for(return_type.clone(), context);
             if return_type == Type::Unknown {
                 if expr.ty() == Type::Unknown {
                     return Err(CantDeduceReturnType {
@@ -166,6 +349,62 @@ impl Declare for ast::FunctionDeclaration {
     }
 }
 
+/// Check that LLVM accepts calls to intrinsic `name` with this signature.
+/// Returns reason, reported by LLVM, otherwise
+fn check_intrinsic_signature(
+    name: &str,
+    parameters: &[Type],
+    return_type: &Type,
+) -> Result<(), String> {
+    let llvm = LLVMContext::create();
+    let module = llvm.create_module("intrinsic");
+    let types = Types::new(module.get_context());
+
+    let scalar = |ty: &Type| {
+        Ok(match ty.builtin() {
+            Some(BuiltinClass::Bool) => types.bool().as_basic_type_enum(),
+            Some(BuiltinClass::U8 | BuiltinClass::Byte) => types.i(8).as_basic_type_enum(),
+            Some(BuiltinClass::I32 | BuiltinClass::U32) => types.i32().as_basic_type_enum(),
+            Some(BuiltinClass::I64 | BuiltinClass::U64) => types.i64().as_basic_type_enum(),
+            Some(BuiltinClass::F64) => types.f64().as_basic_type_enum(),
+            Some(BuiltinClass::Reference | BuiltinClass::ReferenceMut) => {
+                types.pointer().as_basic_type_enum()
+            }
+            _ => return Err(format!("`{ty}` can't be passed to intrinsics")),
+        })
+    };
+
+    let parameters = parameters
+        .iter()
+        .map(scalar)
+        .collect::<Result<Vec<_>, _>>()?;
+    let parameter_types: Vec<BasicMetadataTypeEnum> =
+        parameters.iter().map(|ty| (*ty).into()).collect();
+    let ty = if return_type.is_none() || return_type.is_never() {
+        types.void().fn_type(&parameter_types, false)
+    } else {
+        scalar(return_type)?.fn_type(&parameter_types, false)
+    };
+    let intrinsic = module.add_function(name, ty, None);
+
+    // Verifier checks signatures of intrinsics only at their calls
+    let caller = module.add_function("caller", types.void().fn_type(&[], false), None);
+    let builder = llvm.create_builder();
+    builder.position_at_end(llvm.append_basic_block(caller, ""));
+    let arguments: Vec<BasicMetadataValueEnum> =
+        parameters.iter().map(|ty| ty.const_zero().into()).collect();
+    builder.build_call(intrinsic, &arguments, "").unwrap();
+    builder.build_return(None).unwrap();
+
+    module.verify().map_err(|err| {
+        err.to_string()
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .to_string()
+    })
+}
+
 impl Declare for ast::TraitDeclaration {
     type Declaration = Trait;
     type Definition = Trait;
@@ -223,6 +462,22 @@ impl Declare for ast::TypeDeclaration {
             .iter()
             .map(|a| a.to_hir(context))
             .collect::<Result<Vec<_>, _>>()?;
+        if let Some((annotation, _)) = self.annotations.iter().zip(&annotations).find(|(_, a)| {
+            matches!(
+                a,
+                hir::Annotation::Inline
+                    | hir::Annotation::NoInline
+                    | hir::Annotation::Deprecated(_)
+                    | hir::Annotation::Extern(_)
+                    | hir::Annotation::Intrinsic(_)
+            )
+        }) {
+            return Err(MisplacedAnnotation {
+                name: annotation.name.to_string(),
+                at: annotation.name.range().into(),
+            }
+            .into());
+        }
         let is_builtin = annotations
             .iter()
             .any(|a| matches!(a, hir::Annotation::Builtin));
@@ -244,6 +499,7 @@ impl Declare for ast::TypeDeclaration {
             generic_parameters,
             builtin,
             members: vec![],
+            module: context.compiler().current_module(),
         });
 
         context.add_type(ty.clone());
@@ -256,6 +512,21 @@ impl Declare for ast::TypeDeclaration {
         declaration: Self::Declaration,
         context: &mut impl Context,
     ) -> Result<Self::Definition, Error> {
+        for (i, member) in self.members.iter().enumerate() {
+            if let Some(previous) = self.members[..i].iter().find(|m| m.name == member.name) {
+                return Err(Redeclaration {
+                    name: member.name.to_string(),
+                    at: member.name.range().into(),
+                    previous: vec![RelatedSpan {
+                        message: "previous declaration is here".to_string(),
+                        at: previous.name.range().into(),
+                        source_file: Some(context.compiler().current_file().clone()),
+                    }],
+                }
+                .into());
+            }
+        }
+
         let mut generic_context = GenericContext {
             parent: context,
             generic_parameters: declaration
@@ -289,6 +560,7 @@ impl Declare for ast::VariableDeclaration {
         let var = hir::Variable::new(hir::VariableData {
             keyword: self.keyword.clone(),
             name: self.name.clone(),
+            source_file: Some(context.compiler().current_file().clone()),
             ty: type_reference
                 .as_ref()
                 .map(|t| t.referenced_type.clone())
@@ -313,6 +585,7 @@ impl Declare for ast::VariableDeclaration {
 
         let range = declaration.read().unwrap().name.range();
         let mut ty = declaration.read().unwrap().ty();
+        initializer.monomorphize_for(ty.clone(), context);
         if ty == Type::Unknown {
             ty = initializer.ty();
             declaration.write().unwrap().ty = ty.clone();
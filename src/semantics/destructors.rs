@@ -11,10 +11,13 @@ use crate::{
 
 use super::Context;
 
-/// Insert destructors calls to HIR
+/// Insert destructors calls to HIR.
+///
+/// `kill` are destroyed on return, `kill_on_break` are destroyed on break
 fn with_destructors(
     statements: &[Statement],
     mut kill: Vec<ParameterOrVariable>,
+    mut kill_on_break: Vec<ParameterOrVariable>,
     context: &mut impl Context,
 ) -> Vec<Statement> {
     let mut decls: Vec<ParameterOrVariable> = vec![];
@@ -53,21 +56,42 @@ fn with_destructors(
             }
             Assignment(a) => {
                 destroy(&mut new_statements, a.target.clone(), context);
-                new_statements.push(stmt.clone());
+                new_statements.push(
+                    hir::Assignment {
+                        value: with_destructors_in_loop(&a.value, &kill, context),
+                        ..a.clone()
+                    }
+                    .into(),
+                );
             }
             If(if_stmt) => {
                 new_statements.push(
                     hir::If {
-                        body: with_destructors(&if_stmt.body, kill.clone(), context),
+                        body: with_destructors(
+                            &if_stmt.body,
+                            kill.clone(),
+                            kill_on_break.clone(),
+                            context,
+                        ),
                         else_block: if_stmt.else_block.as_ref().map(|else_block| hir::Else {
                             keyword: else_block.keyword.clone(),
-                            body: with_destructors(&else_block.body, kill.clone(), context),
+                            body: with_destructors(
+                                &else_block.body,
+                                kill.clone(),
+                                kill_on_break.clone(),
+                                context,
+                            ),
                         }),
                         else_ifs: if_stmt
                             .else_ifs
                             .iter()
                             .map(|else_if| hir::ElseIf {
-                                body: with_destructors(&else_if.body, kill.clone(), context),
+                                body: with_destructors(
+                                    &else_if.body,
+                                    kill.clone(),
+                                    kill_on_break.clone(),
+                                    context,
+                                ),
                                 ..else_if.clone()
                             })
                             .collect(),
@@ -79,8 +103,8 @@ fn with_destructors(
             Loop(l) => {
                 new_statements.push(
                     hir::Loop {
-                        keyword: l.keyword.clone(),
-                        body: with_destructors(&l.body, kill.clone(), context),
+                        body: with_destructors(&l.body, kill.clone(), vec![], context),
+                        ..l.clone()
                     }
                     .into(),
                 );
@@ -88,14 +112,20 @@ fn with_destructors(
             While(w) => {
                 new_statements.push(
                     hir::While {
-                        body: with_destructors(&w.body, kill.clone(), context),
+                        body: with_destructors(&w.body, kill.clone(), vec![], context),
                         ..w.clone()
                     }
                     .into(),
                 );
             }
             Declaration(hir::Declaration::Variable(v)) => {
+                let initializer = v.read().unwrap().initializer.clone();
+                if let Some(initializer) = initializer {
+                    v.write().unwrap().initializer =
+                        Some(with_destructors_in_loop(&initializer, &kill, context));
+                }
                 kill.push(v.clone().into());
+                kill_on_break.push(v.clone().into());
                 decls.push(v.clone().into());
                 new_statements.push(stmt.clone());
             }
@@ -124,7 +154,31 @@ fn with_destructors(
                 new_statements.push(stmt.clone());
                 break;
             }
-            Expression(_) | Use(_) | Declaration(_) => {
+            Break(brk) => {
+                if let Some(hir::Expression::VariableReference(VariableReference {
+                    variable,
+                    ..
+                })) = &brk.value
+                {
+                    kill_on_break.retain(|decl| decl != variable);
+                    decls.retain(|decl| decl != variable);
+                }
+                for variable in kill_on_break {
+                    let span = variable.range();
+                    destroy(
+                        &mut new_statements,
+                        VariableReference { variable, span }.into(),
+                        context,
+                    );
+                }
+                decls = vec![];
+                new_statements.push(stmt.clone());
+                break;
+            }
+            Expression(expr) => {
+                new_statements.push(with_destructors_in_loop(expr, &kill, context).into());
+            }
+            Use(_) | Declaration(_) => {
                 new_statements.push(stmt.clone());
             }
         }
@@ -141,6 +195,22 @@ fn with_destructors(
     new_statements
 }
 
+/// Insert destructors calls to body of loop, if expression is a loop
+fn with_destructors_in_loop(
+    expr: &Expression,
+    kill: &[ParameterOrVariable],
+    context: &mut impl Context,
+) -> Expression {
+    match expr {
+        Expression::Loop(l) => hir::Loop {
+            body: with_destructors(&l.body, kill.to_vec(), vec![], context),
+            ..l.clone()
+        }
+        .into(),
+        _ => expr.clone(),
+    }
+}
+
 /// Trait to add destructors calls to HIR
 pub trait InsertDestructors {
     /// Add destructors calls to HIR
@@ -150,7 +220,7 @@ pub trait InsertDestructors {
 impl InsertDestructors for hir::ModuleData {
     fn insert_destructors(&mut self, context: &mut impl Context) {
         let kill = vec![];
-        self.statements = with_destructors(&self.statements, kill, context);
+        self.statements = with_destructors(&self.statements, kill, vec![], context);
     }
 }
 
@@ -163,7 +233,7 @@ impl InsertDestructors for FunctionData {
         trace!(target: "steps", "Inserting destructors in: {self}");
 
         let kill = self.parameters().map(Into::into).collect();
-        self.body = with_destructors(&self.body, kill, context);
+        self.body = with_destructors(&self.body, kill, vec![], context);
 
         trace!(target: "steps", "After inserting destructors: {self}");
     }
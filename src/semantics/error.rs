@@ -8,7 +8,7 @@ use derive_more::From;
 use crate::{
     ast::FnKind,
     hir::{Trait, Type},
-    SourceFile,
+    ErrVec, FixIt, SourceFile,
 };
 
 /// Diagnostic for undefined variables
@@ -22,6 +22,10 @@ pub struct UndefinedVariable {
     /// Span of name
     #[label("reference to undefined variable")]
     pub at: SourceSpan,
+
+    /// Variables with similar names
+    #[related]
+    pub suggestions: Vec<FixIt>,
 }
 
 /// Diagnostic for unknown type
@@ -35,6 +39,10 @@ pub struct UnknownType {
     /// Span of name
     #[label("reference to unknown type")]
     pub at: SourceSpan,
+
+    /// Types with similar names
+    #[related]
+    pub suggestions: Vec<FixIt>,
 }
 
 /// Diagnostic for unknown annotations
@@ -50,6 +58,156 @@ pub struct UnknownAnnotation {
     pub at: SourceSpan,
 }
 
+/// Diagnostic for annotations, that can't be applied to declaration
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq)]
+#[error("annotation `@{name}` may only be used on functions")]
+#[diagnostic(code(semantics::misplaced_annotation))]
+pub struct MisplacedAnnotation {
+    /// Name of annotation
+    pub name: String,
+
+    /// Span of name
+    #[label("here")]
+    pub at: SourceSpan,
+}
+
+/// Diagnostic for annotations, that can't be used together
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq)]
+#[error("annotation `@{name}` conflicts with `@{other}`")]
+#[diagnostic(code(semantics::conflicting_annotations))]
+pub struct ConflictingAnnotations {
+    /// Name of annotation
+    pub name: String,
+    /// Name of conflicting annotation
+    pub other: String,
+
+    /// Span of name
+    #[label("here")]
+    pub at: SourceSpan,
+}
+
+/// Diagnostic for types, that can't be used in `extern` functions
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq)]
+#[error("`{ty}` can't be passed to or returned from C functions")]
+#[diagnostic(code(semantics::non_c_abi_type))]
+pub struct NonCAbiType {
+    /// Type without C ABI
+    pub ty: Type,
+
+    /// Span of type
+    #[label("this type has no C representation")]
+    pub at: SourceSpan,
+}
+
+/// Diagnostic for `extern` function with body
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq)]
+#[error("`extern` function can't have a body")]
+#[diagnostic(code(semantics::extern_with_body))]
+pub struct ExternWithBody {
+    /// Span of `extern` keyword
+    #[label("function is declared `extern` here")]
+    pub at: SourceSpan,
+}
+
+/// Diagnostic for `@intrinsic` with name, unknown to LLVM
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq)]
+#[error("unknown intrinsic `{name}`")]
+#[diagnostic(code(semantics::unknown_intrinsic))]
+pub struct UnknownIntrinsic {
+    /// Name of intrinsic
+    pub name: String,
+
+    /// Span of annotation
+    #[label("LLVM has no such intrinsic")]
+    pub at: SourceSpan,
+}
+
+/// Diagnostic for intrinsic function with body
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq)]
+#[error("intrinsic function can't have a body")]
+#[diagnostic(code(semantics::intrinsic_with_body))]
+pub struct IntrinsicWithBody {
+    /// Span of annotation
+    #[label("function is bound to intrinsic here")]
+    pub at: SourceSpan,
+}
+
+/// Diagnostic for intrinsic function with signature, that LLVM doesn't accept
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq)]
+#[error("signature doesn't match intrinsic `{name}`")]
+#[diagnostic(code(semantics::intrinsic_signature_mismatch))]
+pub struct IntrinsicSignatureMismatch {
+    /// Name of intrinsic
+    pub name: String,
+
+    /// Reason, reported by LLVM
+    pub reason: String,
+
+    /// Span of function's name
+    #[label("{reason}")]
+    pub at: SourceSpan,
+}
+
+/// Diagnostic for expression, that can't be evaluated at compile time
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq)]
+#[error("expression can't be evaluated at compile time")]
+#[diagnostic(code(semantics::not_const_evaluable))]
+pub struct NotConstEvaluable {
+    /// Span of expression
+    #[label("this isn't a compile time constant")]
+    pub at: SourceSpan,
+}
+
+/// Diagnostic for `static` applied to something, that isn't an assertion
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq)]
+#[error("only assertions may be checked at compile time")]
+#[diagnostic(code(semantics::not_an_assertion))]
+pub struct NotAnAssertion {
+    /// Span of expression
+    #[label("expected call to `assert`")]
+    pub at: SourceSpan,
+}
+
+/// Diagnostic for static assertion with false condition
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq)]
+#[error("static assertion failed: {message}")]
+#[diagnostic(code(semantics::static_assertion_failed))]
+pub struct StaticAssertionFailed {
+    /// Message of assertion
+    pub message: String,
+
+    /// Span of condition
+    #[label("this is false")]
+    pub at: SourceSpan,
+}
+
+/// Diagnostic for division by constant zero
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq)]
+#[error("division by zero")]
+#[diagnostic(code(semantics::division_by_zero))]
+pub struct DivisionByZero {
+    /// Span of divisor
+    #[label("this is zero")]
+    pub at: SourceSpan,
+}
+
+/// Secondary span, related to diagnostic, like previous definition
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq)]
+#[error("{message}")]
+#[diagnostic(severity(Advice))]
+pub struct RelatedSpan {
+    /// What is located at span
+    pub message: String,
+
+    /// Related span
+    #[label]
+    pub at: SourceSpan,
+
+    /// Source code of the module, this span is located at
+    #[source_code]
+    pub source_file: Option<SourceFile>,
+}
+
 /// Diagnostic for assignment to immutable
 #[derive(Error, Diagnostic, Debug, Clone, PartialEq)]
 #[error("assignment to immutable")]
@@ -60,6 +218,9 @@ pub struct AssignmentToImmutable {
     /// Span of immutable thing
     #[label("this value is an immutable `{ty}`")]
     pub at: SourceSpan,
+    /// Declaration of immutable variable or parameter
+    #[related]
+    pub declared_at: Vec<RelatedSpan>,
 }
 
 /// Show type in diagnostic
@@ -86,12 +247,28 @@ impl From<TypeWithSpan> for SourceSpan {
 
 /// Diagnostic for not convertible types
 #[derive(Error, Debug, Clone, PartialEq)]
-#[error("expected `{expected}` type, got `{got}`")]
+#[error("expected `{expected}` type, got `{got}`{}", with_bindings(bindings))]
 pub struct TypeMismatch {
     /// Expected type
     pub expected: TypeWithSpan,
     /// Real type
     pub got: TypeWithSpan,
+    /// Generic types of expected type, that were already inferred
+    pub bindings: Vec<(Type, Type)>,
+}
+
+/// Format inferred generic types like ` (with T = String)`
+fn with_bindings(bindings: &[(Type, Type)]) -> String {
+    if bindings.is_empty() {
+        return String::new();
+    }
+
+    let bindings = bindings
+        .iter()
+        .map(|(generic, ty)| format!("{generic} = {ty}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!(" (with {bindings})")
 }
 
 impl Diagnostic for TypeMismatch {
@@ -170,6 +347,9 @@ pub struct NoFunction {
 
     /// Reasons, why candidates failed
     pub candidates: Vec<CandidateNotViable>,
+
+    /// Functions with similar names
+    pub suggestions: Vec<FixIt>,
 }
 
 impl Display for NoFunction {
@@ -212,11 +392,14 @@ impl Diagnostic for NoFunction {
     }
 
     fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
-        if self.candidates.is_empty() {
+        if self.candidates.is_empty() && self.suggestions.is_empty() {
             None
         } else {
             Some(Box::new(
-                self.candidates.iter().map(|c| c as &dyn Diagnostic),
+                self.candidates
+                    .iter()
+                    .map(|c| c as &dyn Diagnostic)
+                    .chain(self.suggestions.iter().map(|s| s as &dyn Diagnostic)),
             ))
         }
     }
@@ -259,6 +442,41 @@ pub struct ReturnTypeMismatch {
     pub expected: Type,
 }
 
+/// Diagnostic for break outside of loop
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq)]
+#[error("break outside of loop")]
+#[diagnostic(code(semantics::break_outside_loop))]
+pub struct BreakOutsideLoop {
+    /// Span of break statement
+    #[label("this break is outside of loop")]
+    pub at: SourceSpan,
+}
+
+/// Diagnostic for break with value out of `while` loop
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq)]
+#[error("can't break with value out of `while` loop")]
+#[diagnostic(code(semantics::break_with_value_out_of_while))]
+pub struct BreakWithValueOutOfWhile {
+    /// Span of passed value
+    #[label("this value can't be passed out of `while` loop")]
+    pub at: SourceSpan,
+}
+
+/// Diagnostic for mismatch of types, passed out of the same loop
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq)]
+#[error("break type mismatch: got `{got}`, expected `{expected}`")]
+#[diagnostic(code(semantics::break_type_mismatch))]
+pub struct BreakTypeMismatch {
+    /// Type of passed value
+    pub got: Type,
+    /// Span of break statement
+    #[label("this has `{got}` type")]
+    pub got_span: SourceSpan,
+
+    /// Type of values, passed by previous breaks
+    pub expected: Type,
+}
+
 /// Diagnostic for recursive implicit return type
 #[derive(Error, Diagnostic, Debug, Clone, PartialEq)]
 #[error("can't deduce implicit return type of function")]
@@ -297,6 +515,20 @@ pub struct NoMember {
     pub name: String,
 }
 
+/// Diagnostic for use of private member outside of module, where its type is declared
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq)]
+#[error("member `{name}` of `{ty}` is private")]
+#[diagnostic(code(semantics::private_member))]
+pub struct PrivateMember {
+    /// Type with this member
+    pub ty: Type,
+    /// Span of member's name
+    #[label("private member is used here")]
+    pub at: SourceSpan,
+    /// Name of member
+    pub name: String,
+}
+
 /// Diagnostic for multiple initializers for single field
 #[derive(Diagnostic, Error, Debug, Clone, PartialEq)]
 #[error("field `{name}` initialized multiple times")]
@@ -312,6 +544,21 @@ pub struct MultipleInitialization {
     pub repeated_at: Vec<SourceSpan>,
 }
 
+/// Diagnostic for declaration with the same name as another one
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq)]
+#[error("`{name}` is already declared")]
+#[diagnostic(code(semantics::redeclaration))]
+pub struct Redeclaration {
+    /// Name of redeclared entity
+    pub name: String,
+    /// Span of repeated declaration
+    #[label("redeclared here")]
+    pub at: SourceSpan,
+    /// Previous declaration
+    #[related]
+    pub previous: Vec<RelatedSpan>,
+}
+
 /// Diagnostic for missing fields in constructor
 #[derive(Error, Diagnostic, Debug, Clone, PartialEq)]
 #[error("type `{ty}` has missing fields: {fields}")]
@@ -326,6 +573,20 @@ pub struct MissingFields {
     pub fields: DisplayVec<String>,
 }
 
+/// Diagnostic for tuple destructuring with wrong number of names
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq)]
+#[error("expected {expected} names to destructure, got {got}")]
+#[diagnostic(code(semantics::wrong_number_of_names))]
+pub struct WrongNumberOfNames {
+    /// Number of destructured values
+    pub expected: usize,
+    /// Number of names
+    pub got: usize,
+    /// Span of destructured object
+    #[label("this has {expected} values")]
+    pub at: SourceSpan,
+}
+
 /// Wrapper around [`Vec`] to display it
 #[derive(Debug, PartialEq, Eq, Clone, From)]
 pub struct DisplayVec<D: Display>(pub Vec<D>);
@@ -405,6 +666,41 @@ pub struct UnresolvedImport {
     pub at: SourceSpan,
 }
 
+/// Diagnostic for export statement outside of module's top level
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq)]
+#[error("export outside of module's top level")]
+#[diagnostic(code(semantics::export_outside_module))]
+pub struct ExportOutsideModule {
+    /// Span of export statement
+    #[label("this export is not at module's top level")]
+    pub at: SourceSpan,
+}
+
+/// Diagnostic for type or trait declaration inside of function
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq)]
+#[error("types and traits may be declared only at module's top level")]
+#[diagnostic(code(semantics::local_type_declaration))]
+pub struct LocalTypeDeclaration {
+    /// Span of declaration
+    #[label("this declaration is inside of function")]
+    pub at: SourceSpan,
+}
+
+/// Diagnostic for exporting name, that isn't declared in module
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq)]
+#[error("exported `{name}` is not declared in this module")]
+#[diagnostic(code(semantics::undefined_export))]
+pub struct UndefinedExport {
+    /// Exported name
+    pub name: String,
+    /// Location of the exported name
+    #[label("No such item in module")]
+    pub at: SourceSpan,
+}
+
+/// Several errors, reported together, like errors of call and its method call form
+pub type Errors = ErrVec<Error>;
+
 /// Helper macro to create error enumeration
 macro_rules! error_enum {
 	($($name:ident),*) => {
@@ -427,17 +723,38 @@ error_enum!(
     ConditionTypeMismatch,
     UnknownType,
     UnknownAnnotation,
+    MisplacedAnnotation,
+    ConflictingAnnotations,
+    NonCAbiType,
+    ExternWithBody,
+    UnknownIntrinsic,
+    IntrinsicWithBody,
+    IntrinsicSignatureMismatch,
+    NotConstEvaluable,
+    NotAnAssertion,
+    StaticAssertionFailed,
+    DivisionByZero,
     NoFunction,
     ReturnOutsideFunction,
     MissingReturnValue,
     ReturnTypeMismatch,
+    BreakOutsideLoop,
+    BreakWithValueOutOfWhile,
+    BreakTypeMismatch,
     CantDeduceReturnType,
     CantDeduceType,
     NoMember,
+    PrivateMember,
     MultipleInitialization,
+    Redeclaration,
     MissingFields,
+    WrongNumberOfNames,
     NonClassConstructor,
     NotImplemented,
     NotConvertible,
-    UnresolvedImport
+    UnresolvedImport,
+    ExportOutsideModule,
+    LocalTypeDeclaration,
+    UndefinedExport,
+    Errors
 );
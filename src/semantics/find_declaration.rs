@@ -46,6 +46,16 @@ pub trait FindDeclarationHere {
         let _ = ty;
         vec![]
     }
+
+    /// Get names of all variables without checking parent context
+    fn variable_names_here(&self) -> Vec<String> {
+        vec![]
+    }
+
+    /// Get names of all types without checking parent context
+    fn type_names_here(&self) -> Vec<String> {
+        vec![]
+    }
 }
 
 /// Trait to find declaration at current level or above
@@ -111,6 +121,26 @@ pub trait FindDeclaration: FindDeclarationHere {
             .collect()
     }
 
+    /// Get names of all visible variables
+    fn variable_names(&self) -> Vec<String> {
+        self.variable_names_here()
+            .into_iter()
+            .chain(
+                self.parent()
+                    .map(|p| p.variable_names())
+                    .unwrap_or_default(),
+            )
+            .collect()
+    }
+
+    /// Get names of all visible types
+    fn type_names(&self) -> Vec<String> {
+        self.type_names_here()
+            .into_iter()
+            .chain(self.parent().map(|p| p.type_names()).unwrap_or_default())
+            .collect()
+    }
+
     /// Get specialized type for generic type
     fn get_specialized(&self, generic: Type) -> Option<Type> {
         self.parent().map(|p| p.get_specialized(generic)).flatten()
@@ -211,6 +241,14 @@ impl FindDeclarationHere for ModuleData {
             })
             .collect()
     }
+
+    fn variable_names_here(&self) -> Vec<String> {
+        self.variables.keys().map(|name| name.to_string()).collect()
+    }
+
+    fn type_names_here(&self) -> Vec<String> {
+        self.types.keys().map(|name| name.to_string()).collect()
+    }
 }
 
 impl FindDeclaration for ModuleData {}
@@ -239,4 +277,12 @@ impl<M: AsRef<ModuleData>> FindDeclarationHere for M {
     fn traits_for_here(&self, ty: Class) -> Vec<Trait> {
         self.as_ref().traits_for_here(ty)
     }
+
+    fn variable_names_here(&self) -> Vec<String> {
+        self.as_ref().variable_names_here()
+    }
+
+    fn type_names_here(&self) -> Vec<String> {
+        self.as_ref().type_names_here()
+    }
 }
@@ -1,6 +1,14 @@
-use crate::hir::{Expression, ImplicitConversion, ImplicitConversionKind::*, Typed};
+use crate::{
+    hir::{
+        Expression, Function, FunctionData, ImplicitConversion, ImplicitConversionKind::*, Trait,
+        Type, Typed, VirtualFunctions,
+    },
+    DataHolder,
+};
 
-use super::Context;
+use super::{
+    error::NotImplemented, Context, ConvertibleTo, GenericContext, Implements, Monomorphize,
+};
 
 /// Trait to wrap expression with implicit dereference/reference
 pub trait Implicit {
@@ -15,6 +23,14 @@ pub trait Implicit {
 
     /// Implicitly copy this expression
     fn copy(self) -> Self;
+
+    /// Implicitly coerce this diverging expression to another type
+    fn coerce_never(self, ty: Type) -> Self;
+
+    /// Erase static type of this expression, converting it to trait object
+    fn to_trait_object(self, tr: Trait, context: &mut impl Context) -> Result<Self, NotImplemented>
+    where
+        Self: Sized;
 }
 
 impl Implicit for Expression {
@@ -57,4 +73,100 @@ impl Implicit for Expression {
         }
         .into()
     }
+
+    fn coerce_never(self, ty: Type) -> Self {
+        ImplicitConversion {
+            kind: Never,
+            ty,
+            expression: Box::new(self),
+        }
+        .into()
+    }
+
+    fn to_trait_object(
+        self,
+        tr: Trait,
+        context: &mut impl Context,
+    ) -> Result<Self, NotImplemented> {
+        let class = self.ty().as_class();
+        class.implements(tr.clone()).within(context)?;
+
+        let ty: Type = class.into();
+        let required: Vec<_> = tr.read().unwrap().required_functions().collect();
+        let required = required
+            .into_iter()
+            .map(|f| {
+                let f = f.read().unwrap().clone();
+                let implementation = context
+                    .find_implementation(&f, Some(ty.clone()))
+                    .expect("Implementation was checked above");
+                specialize_for(implementation, &f, &ty, context)
+            })
+            .collect();
+        let destructible = context.builtin().traits().destructible();
+        let clonnable = context.builtin().traits().clonnable();
+        let destructor = builtin_implementation(destructible, &ty, context);
+        let clone = builtin_implementation(clonnable, &ty, context);
+
+        Ok(ImplicitConversion {
+            kind: TraitObject(VirtualFunctions {
+                destructor,
+                clone,
+                required,
+            }),
+            ty: tr.into(),
+            expression: Box::new(self),
+        }
+        .into())
+    }
+}
+
+/// Find implementation of the only function of builtin trait
+/// (`Destructible` or `Clonnable`) for type of trait object
+fn builtin_implementation(tr: Trait, ty: &Type, context: &mut impl Context) -> Option<Function> {
+    let f = tr
+        .read()
+        .unwrap()
+        .functions
+        .values()
+        .next()?
+        .read()
+        .unwrap()
+        .clone();
+    let implementation = context.find_implementation(&f, Some(ty.clone()))?;
+    Some(specialize_for(implementation, &f, ty, context))
+}
+
+/// Specialize implementation of trait function for type of trait object
+fn specialize_for(
+    implementation: Function,
+    trait_fn: &FunctionData,
+    ty: &Type,
+    context: &mut impl Context,
+) -> Function {
+    if !implementation.read().unwrap().is_generic() {
+        return implementation;
+    }
+
+    let mut specialized = implementation.read().unwrap().clone();
+    GenericContext::for_fn(&specialized, context).run(|context| {
+        for (p, imp) in trait_fn
+            .parameters()
+            .zip(implementation.read().unwrap().parameters())
+        {
+            if matches!(p.ty().without_ref(), Type::SelfType(_)) {
+                // Binds `Self` and generic parameters to type of trait object
+                let _ = ty.convertible_to(imp.ty()).within(context);
+            }
+        }
+        specialized.monomorphize(context);
+    });
+
+    specialized.generic_version = Some(implementation);
+    let specialized = Function::new(specialized);
+    context
+        .module_mut()
+        .monomorphized_functions
+        .push(specialized.clone());
+    specialized
 }
@@ -12,8 +12,13 @@ pub use monomorphize::*;
 mod declare;
 pub use declare::*;
 
+mod const_eval;
+pub use const_eval::*;
+
 pub mod error;
 
+pub mod warning;
+
 mod find_declaration;
 pub use find_declaration::*;
 
@@ -35,6 +40,9 @@ pub use destructors::*;
 mod tmp;
 pub use tmp::*;
 
+mod asserts;
+pub use asserts::*;
+
 mod unnamed;
 pub use unnamed::*;
 
@@ -43,3 +51,9 @@ pub use replace_self::*;
 
 mod link_impls;
 pub use link_impls::*;
+
+mod unused;
+pub use unused::*;
+
+mod suggestions;
+pub use suggestions::*;
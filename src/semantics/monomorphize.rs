@@ -2,10 +2,11 @@ use log::{debug, trace};
 
 use crate::{
     hir::{
-        Assignment, Call, Class, Constructor, Declaration, Else, ElseIf, Expression, Function,
-        FunctionData, FunctionNamePart, Generic, If, ImplicitConversion, ImplicitConversionKind,
-        Initializer, Loop, Member, MemberReference, ModuleData, Parameter, ParameterOrVariable,
-        Return, Statement, Type, TypeReference, Typed, Variable, VariableReference, While,
+        Assignment, Break, Call, Class, Constructor, Declaration, Else, ElseIf, Expression,
+        Function, FunctionData, FunctionNamePart, Generic, If, ImplicitConversion,
+        ImplicitConversionKind, Initializer, Loop, Member, MemberReference, ModuleData, Parameter,
+        ParameterOrVariable, Return, Statement, Type, TypeReference, Typed, Variable,
+        VariableReference, While,
     },
     mutability::Mutable,
     semantics::GenericContext,
@@ -13,7 +14,7 @@ use crate::{
 
 use crate::DataHolder;
 
-use super::{Context, ReplaceWithTypeInfo};
+use super::{Context, ConvertibleTo, ReplaceWithTypeInfo};
 
 /// Trait to get monomorphized version of statements
 pub trait Monomorphize {
@@ -35,6 +36,7 @@ impl Monomorphize for Statement {
             Statement::If(stmt) => stmt.monomorphize(context),
             Statement::Loop(l) => l.monomorphize(context),
             Statement::While(l) => l.monomorphize(context),
+            Statement::Break(b) => b.monomorphize(context),
             Statement::Return(ret) => ret.monomorphize(context),
             Statement::Declaration(d) => d.monomorphize(context),
             Statement::Block(b) => b.statements.monomorphize(context),
@@ -120,6 +122,7 @@ impl Monomorphize for Return {
 impl Monomorphize for Loop {
     fn monomorphize(&mut self, context: &mut impl Context) {
         self.body.monomorphize(context);
+        self.ty.monomorphize(context);
     }
 }
 
@@ -130,6 +133,12 @@ impl Monomorphize for While {
     }
 }
 
+impl Monomorphize for Break {
+    fn monomorphize(&mut self, context: &mut impl Context) {
+        self.value.as_mut().map(|value| value.monomorphize(context));
+    }
+}
+
 impl Monomorphize for ImplicitConversion {
     fn monomorphize(&mut self, context: &mut impl Context) {
         self.expression.monomorphize(context);
@@ -145,6 +154,12 @@ impl Monomorphize for ImplicitConversion {
             }
             Dereference => ty.without_ref(),
             Copy => ty,
+            TraitObject(_) => self.ty.clone(),
+            Never => {
+                let mut ty = self.ty.clone();
+                ty.monomorphize(context);
+                ty
+            }
         };
     }
 }
@@ -162,6 +177,7 @@ impl Monomorphize for Expression {
             Expression::MemberReference(m) => m.monomorphize(context),
             Expression::Constructor(c) => c.monomorphize(context),
             Expression::ImplicitConversion(c) => c.monomorphize(context),
+            Expression::Loop(l) => l.monomorphize(context),
         }
     }
 }
@@ -263,8 +279,15 @@ impl Monomorphize for Member {
     }
 }
 
-impl Monomorphize for Call {
-    fn monomorphize(&mut self, context: &mut impl Context) {
+impl Call {
+    /// Monomorphize call, deducing generics, that can't be deduced from arguments,
+    /// from type, that is expected as a result of this call
+    pub fn monomorphize_for(&mut self, expected: Type, context: &mut impl Context) {
+        self.specialize(Some(expected), context)
+    }
+
+    /// Monomorphize call using types of arguments and, optionally, expected type
+    fn specialize(&mut self, expected: Option<Type>, context: &mut impl Context) {
         if !self.is_generic() {
             trace!(target: "monomorphizing-skipped", "{self}");
             return;
@@ -274,11 +297,24 @@ impl Monomorphize for Call {
         trace!(target: "monomorphizing", "{from}");
         self.args.monomorphize(context);
 
+        // Destructor of value is known only for concrete types
+        if self.function.read().unwrap().mangled_name.as_deref() == Some("drop_value")
+            && let Some(destructor) = context.destructor_for(self.args[0].ty().without_ref())
+        {
+            self.function = destructor;
+            return self.specialize(expected, context);
+        }
+
         let mut context = GenericContext::for_fn_with_args(
             &self.function.read().unwrap(),
             self.args.iter().cloned(),
             context,
         );
+        if let Some(expected) = expected {
+            let return_type = self.function.read().unwrap().return_type.clone();
+            // Mismatch will be reported by conversion to expected type
+            let _ = expected.convertible_to(return_type).within(&mut context);
+        }
 
         let mut f = self.function.read().unwrap().clone();
         f.monomorphize(&mut context);
@@ -297,6 +333,25 @@ impl Monomorphize for Call {
     }
 }
 
+impl Monomorphize for Call {
+    fn monomorphize(&mut self, context: &mut impl Context) {
+        self.specialize(None, context)
+    }
+}
+
+impl Expression {
+    /// Deduce generics of call, that can't be deduced from its arguments,
+    /// from type, that is expected as a result of this expression
+    pub fn monomorphize_for(&mut self, expected: Type, context: &mut impl Context) {
+        if expected != Type::Unknown
+            && let Expression::Call(call) = self
+            && call.ty().is_generic()
+        {
+            call.monomorphize_for(expected, context);
+        }
+    }
+}
+
 impl Monomorphize for VariableReference {
     fn monomorphize(&mut self, context: &mut impl Context) {
         if !self.is_generic() {
@@ -0,0 +1,62 @@
+/// Maximal amount of names to suggest
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Levenshtein distance between two strings, measured in characters
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            let insertion = current[j] + 1;
+            let deletion = previous[j + 1] + 1;
+            current.push(substitution.min(insertion).min(deletion));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Names from `candidates`, that are close enough to `name` to be its typo,
+/// ordered from the closest one
+pub fn similar_names(name: &str, candidates: impl IntoIterator<Item = String>) -> Vec<String> {
+    let max_distance = name.chars().count() / 3;
+    let mut similar: Vec<(usize, String)> = candidates
+        .into_iter()
+        .filter_map(|candidate| {
+            let distance = edit_distance(name, &candidate);
+            (distance > 0 && distance <= max_distance).then_some((distance, candidate))
+        })
+        .collect();
+    similar.sort();
+    similar.dedup_by(|a, b| a.1 == b.1);
+    similar
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, name)| name)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distance() {
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("héllo", "hello"), 1);
+    }
+
+    #[test]
+    fn similar() {
+        let candidates = ["counter", "count", "country", "x"].map(String::from);
+        assert_eq!(
+            similar_names("countr", candidates.clone()),
+            ["count", "counter", "country"]
+        );
+        assert_eq!(similar_names("y", candidates.clone()), Vec::<String>::new());
+        assert_eq!(similar_names("count", candidates), Vec::<String>::new());
+    }
+}
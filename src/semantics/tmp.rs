@@ -1,13 +1,14 @@
-use std::iter;
+use std::{iter, ops::Range};
 
 use derive_visitor::{DriveMut, VisitorMut};
 
 use crate::{
     hir::{
-        Block, Declaration, Expression, ImplicitConversion, ImplicitConversionKind, ModuleData,
-        Return, Statement, Typed, Variable, VariableData, VariableReference,
+        Assignment, Block, Declaration, Else, Expression, If, ImplicitConversion,
+        ImplicitConversionKind, ModuleData, Return, Statement, Typed, Variable, VariableData,
+        VariableReference,
     },
-    mutability::Mutable,
+    mutability::{Mutability, Mutable},
     syntax::{Identifier, Keyword, Ranged},
     DataHolder,
 };
@@ -16,11 +17,13 @@ use crate::{
 #[visitor(
     Statement(exit),
     Return(exit),
+    Expression(exit),
     ImplicitConversion(exit),
     ModuleData(exit)
 )]
 pub struct TemporariesInserter {
-    temporaries: Vec<Variable>,
+    /// Statements to insert before current statement
+    temporaries: Vec<Statement>,
 }
 
 impl<'ctx> TemporariesInserter {
@@ -36,6 +39,7 @@ impl<'ctx> TemporariesInserter {
             keyword: Keyword::<"let">::at(offset),
             mutability: expr.mutability(),
             name: Identifier::from(format!("$tmp@{offset}")).at(offset),
+            source_file: None,
             type_reference: None,
             ty: expr.ty(),
             initializer: Some(expr.clone()),
@@ -45,7 +49,89 @@ impl<'ctx> TemporariesInserter {
             variable: tmp.clone().into(),
         }
         .into();
-        self.temporaries.push(tmp);
+        self.temporaries.push(Declaration::from(tmp).into());
+    }
+
+    /// Evaluate right operand of builtin `and` or `or` together with its temporaries
+    /// only when left operand doesn't decide the result:
+    ///
+    /// ```ppl
+    /// let mut $and = <lhs>
+    /// if $and:
+    ///     <temporaries of rhs>
+    ///     $and = <rhs>
+    /// ```
+    fn exit_expression(&mut self, expr: &mut Expression) {
+        let Expression::Call(call) = expr else {
+            return;
+        };
+        let is_and = match call.function.read().unwrap().mangled_name().as_ref() {
+            "<:Bool> and <:Bool>" => true,
+            "<:Bool> or <:Bool>" => false,
+            _ => return,
+        };
+
+        let rhs = call.args[1].range();
+        let Some(first) = self
+            .temporaries
+            .iter()
+            .position(|stmt| rhs.contains(&stmt.start()))
+        else {
+            return;
+        };
+        let mut body = self.temporaries.split_off(first);
+
+        let offset = call.start();
+        let name = if is_and { "and" } else { "or" };
+        let result = Variable::new(VariableData {
+            keyword: Keyword::<"let">::at(offset),
+            mutability: Mutability::Mutable,
+            name: Identifier::from(format!("${name}@{offset}")).at(offset),
+            source_file: None,
+            type_reference: None,
+            ty: call.args[0].ty(),
+            initializer: Some(call.args[0].clone()),
+        });
+        let reference = |span: Range<usize>| -> Expression {
+            VariableReference {
+                span,
+                variable: result.clone().into(),
+            }
+            .into()
+        };
+        body.push(
+            Assignment {
+                target: reference(rhs.clone()),
+                value: call.args[1].clone(),
+            }
+            .into(),
+        );
+
+        let (body, else_block) = if is_and {
+            (body, None)
+        } else {
+            (
+                vec![],
+                Some(Else {
+                    keyword: Keyword::<"else">::at(offset),
+                    body,
+                }),
+            )
+        };
+        self.temporaries
+            .push(Declaration::from(result.clone()).into());
+        self.temporaries.push(
+            If {
+                keyword: Keyword::<"if">::at(offset),
+                condition: reference(call.args[0].range()),
+                body,
+                else_ifs: vec![],
+                else_block,
+            }
+            .into(),
+        );
+        let range = expr.range();
+        *expr = reference(range);
     }
 
     fn exit_module_data(&mut self, module: &mut ModuleData) {
@@ -84,8 +170,6 @@ impl<'ctx> TemporariesInserter {
             statements: self
                 .temporaries
                 .drain(..)
-                .map(Declaration::from)
-                .map(Statement::from)
                 .chain(iter::once(stmt.clone()))
                 .collect(),
         }
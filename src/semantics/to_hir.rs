@@ -1,5 +1,6 @@
 use core::panic;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
 
 use indexmap::IndexMap;
 use log::{debug, trace};
@@ -16,14 +17,18 @@ use crate::mutability::{Mutability, Mutable};
 use crate::named::Named;
 use crate::semantics::clone::Clonner;
 use crate::semantics::{
-    InsertDestructors, ParameterNamer, TemporariesInserter, TraitFunctionsLinker,
+    AssertsStripper, InsertDestructors, ParameterNamer, TemporariesInserter, TraitFunctionsLinker,
+    WarnUnused,
 };
 use crate::syntax::{Identifier, Keyword, Ranged};
-use crate::{AddSourceLocation, ErrVec, SourceLocation, WithSourceLocation};
+use crate::{AddSourceLocation, ErrVec, FixIt, SourceLocation, WithSourceLocation};
 
 use super::{
-    error::*, AddDeclaration, Context, Convert, ConvertibleTo, Declare, FindDeclaration,
-    GenericContext, Implicit, ModuleContext,
+    error::*,
+    similar_names,
+    warning::{ConcatenationInLoop, Deprecated},
+    AddDeclaration, ConstEval, ConstValue, Context, Convert, ConvertibleTo, Declare,
+    FindDeclaration, GenericContext, Implements, Implicit, LoopContext, ModuleContext,
 };
 use crate::ast::{self, CallNamePart, FnKind, If};
 use crate::semantics::monomorphize::Monomorphize;
@@ -52,6 +57,14 @@ impl ToHIR for ast::Statement {
     /// Lower [`ast::Statement`] to [`hir::Statement`] within lowering context
     fn to_hir(&self, context: &mut impl Context) -> Result<Self::HIR, Self::Error> {
         Ok(match self {
+            ast::Statement::Declaration(
+                decl @ (ast::Declaration::Type(_) | ast::Declaration::Trait(_)),
+            ) if context.function().is_some() => {
+                return Err(LocalTypeDeclaration {
+                    at: decl.range().into(),
+                }
+                .into())
+            }
             ast::Statement::Declaration(decl) => decl.to_hir(context)?.into(),
             ast::Statement::Assignment(assign) => assign.to_hir(context)?.into(),
             ast::Statement::Expression(expr) => expr.to_hir(context)?.into(),
@@ -59,7 +72,17 @@ impl ToHIR for ast::Statement {
             ast::Statement::If(stmt) => stmt.to_hir(context)?.into(),
             ast::Statement::Loop(stmt) => stmt.to_hir(context)?.into(),
             ast::Statement::While(stmt) => stmt.to_hir(context)?.into(),
+            ast::Statement::Break(stmt) => stmt.to_hir(context)?.into(),
+            ast::Statement::For(stmt) => stmt.to_hir(context)?.into(),
             ast::Statement::Use(u) => u.to_hir(context)?.into(),
+            ast::Statement::Export(e) => {
+                return Err(ExportOutsideModule {
+                    at: e.range().into(),
+                }
+                .into())
+            }
+            ast::Statement::Destructuring(d) => d.to_hir(context)?.into(),
+            ast::Statement::StaticAssert(s) => s.to_hir(context)?.into(),
         })
     }
 }
@@ -108,6 +131,10 @@ impl ToHIR for ast::VariableReference {
             return Err(UndefinedVariable {
                 name: self.name.clone().to_string(),
                 at: self.name.range().into(),
+                suggestions: similar_names(&self.name, context.variable_names())
+                    .into_iter()
+                    .map(|name| FixIt::did_you_mean(self.name.range(), name))
+                    .collect(),
             }
             .into());
         }
@@ -119,44 +146,175 @@ impl ToHIR for ast::VariableReference {
     }
 }
 
+/// Fold integer division with constant operands into literal.
+/// Division by constant zero is reported at compile time
+fn fold_integer_division(call: hir::Call) -> Result<hir::Expression, Error> {
+    let is_division = matches!(
+        call.function.read().unwrap().mangled_name.as_deref(),
+        Some("integer_div_integer" | "integer_modulo_integer" | "integer_rem_integer")
+    );
+    if !is_division {
+        return Ok(call.into());
+    }
+
+    let divisor = &call.args[1];
+    if divisor.const_eval() == Ok(ConstValue::Integer(0.into())) {
+        return Err(DivisionByZero {
+            at: divisor.range().into(),
+        }
+        .into());
+    }
+
+    let (span, ty) = (call.range(), call.ty());
+    let call: hir::Expression = call.into();
+    Ok(match call.const_eval() {
+        Ok(ConstValue::Integer(value)) => hir::Literal::Integer { span, value, ty }.into(),
+        _ => call,
+    })
+}
+
+/// Check if function concatenates strings with `+` or `+=`
+fn is_string_concatenation(f: &hir::FunctionData) -> bool {
+    match f.name_parts() {
+        [FunctionNamePart::Parameter(x), FunctionNamePart::Text(op), FunctionNamePart::Parameter(y)] => {
+            matches!(op.as_str(), "+" | "+=")
+                && x.ty().without_ref().is_string()
+                && y.ty().without_ref().is_string()
+        }
+        _ => false,
+    }
+}
+
+/// Suggest functions, that differ from `call` only by a single misspelled word
+fn similar_functions(call: &ast::Call, context: &impl Context) -> Vec<FixIt> {
+    let mut misspellings: IndexMap<Range<usize>, (String, Vec<String>)> = IndexMap::new();
+    for f in context.functions_with_n_name_parts(call.name_parts.len()) {
+        let mut misspelled = None;
+        let mut matches = true;
+        for (f_part, c_part) in f.read().unwrap().name_parts().iter().zip(&call.name_parts) {
+            match (f_part, c_part) {
+                (FunctionNamePart::Text(expected), CallNamePart::Text(got))
+                    if expected.as_str() != got.as_str() =>
+                {
+                    matches &= misspelled.is_none();
+                    misspelled = Some((got, expected.to_string()));
+                }
+                (FunctionNamePart::Text(_), CallNamePart::Text(_))
+                | (FunctionNamePart::Parameter(_), _) => {}
+                _ => matches = false,
+            }
+        }
+
+        if let (true, Some((got, expected))) = (matches, misspelled) {
+            misspellings
+                .entry(got.range())
+                .or_insert_with(|| (got.to_string(), vec![]))
+                .1
+                .push(expected);
+        }
+    }
+
+    misspellings
+        .into_iter()
+        .flat_map(|(at, (got, candidates))| {
+            similar_names(&got, candidates)
+                .into_iter()
+                .map(move |name| FixIt::did_you_mean(at.clone(), name))
+        })
+        .collect()
+}
+
+impl ToHIR for CallNamePart {
+    type HIR = Option<hir::Expression>;
+
+    /// Lower [`CallNamePart`] to argument of call.
+    /// Text parts are lowered only if they reference variable or type
+    fn to_hir(&self, context: &mut impl Context) -> Result<Self::HIR, Self::Error> {
+        match self {
+            CallNamePart::Argument(a) => Ok(Some(a.to_hir(context)?)),
+            CallNamePart::Text(t) => {
+                if let Some(var) = context.find_variable(t) {
+                    return Ok(Some(
+                        hir::VariableReference {
+                            span: t.range().into(),
+                            variable: var,
+                        }
+                        .into(),
+                    ));
+                } else if t.as_str().chars().nth(0).is_some_and(|c| c.is_uppercase())
+                    && let Some(ty) = context.find_type(t)
+                {
+                    return Ok(Some(
+                        hir::TypeReference {
+                            span: t.range().into(),
+                            referenced_type: ty.clone(),
+                            type_for_type: context.builtin().types().type_of(ty),
+                        }
+                        .into(),
+                    ));
+                }
+                Ok(None)
+            }
+        }
+    }
+}
+
 impl ToHIR for ast::Call {
     type HIR = hir::Call;
 
     /// Lower [`ast::Call`] to [`hir::Call`] within lowering context
     fn to_hir(&self, context: &mut impl Context) -> Result<Self::HIR, Self::Error> {
-        let args_cache: Vec<Option<hir::Expression>> = self
-            .name_parts
-            .iter()
-            .map(|part| match part {
-                CallNamePart::Argument(a) => Ok::<_, Error>(Some(a.to_hir(context)?)),
-                CallNamePart::Text(t) => {
-                    if let Some(var) = context.find_variable(t) {
-                        return Ok(Some(
-                            hir::VariableReference {
-                                span: t.range().into(),
-                                variable: var,
-                            }
-                            .into(),
-                        ));
-                    } else if t.as_str().chars().nth(0).is_some_and(|c| c.is_uppercase())
-                        && let Some(ty) = context.find_type(t)
-                    {
-                        return Ok(Some(
-                            hir::TypeReference {
-                                span: t.range().into(),
-                                referenced_type: ty.clone(),
-                                type_for_type: context.builtin().types().type_of(ty),
-                            }
-                            .into(),
-                        ));
-                    }
-                    Ok(None)
+        let method_call = self.as_method_call();
+
+        let mut args_cache = Vec::with_capacity(self.name_parts.len());
+        let mut receiver_error = None;
+        for part in &self.name_parts {
+            match part.to_hir(context) {
+                Ok(arg) => args_cache.push(arg),
+                // Receiver may be not a member, but an argument of method call
+                Err(Error::NoMember(err)) if method_call.is_some() && args_cache.is_empty() => {
+                    receiver_error = Some(err.into());
+                    args_cache.push(None);
                 }
-            })
-            .try_collect()?;
+                Err(err) => return Err(err),
+            }
+        }
+
+        let Some(method_call) = method_call else {
+            return self.resolve(&args_cache, context);
+        };
+
+        let error = match receiver_error {
+            Some(err) => err,
+            None => match self.resolve(&args_cache, context) {
+                Err(err @ Error::NoFunction(_)) => err,
+                result => return result,
+            },
+        };
 
+        // Try `receiver.function args...` syntax sugar.
+        // Only receiver is lowered again, as it was a base of member reference
+        let mut method_args = Vec::with_capacity(method_call.name_parts.len());
+        for part in &method_call.name_parts[..2] {
+            method_args.push(part.to_hir(context)?);
+        }
+        method_args.extend(args_cache.into_iter().skip(1));
+        method_call
+            .resolve(&method_args, context)
+            .map_err(|method_error| Errors::from(vec![error, method_error]).into())
+    }
+}
+
+impl ast::Call {
+    /// Resolve call to function, using already lowered arguments
+    fn resolve(
+        &self,
+        args_cache: &[Option<hir::Expression>],
+        context: &mut impl Context,
+    ) -> Result<hir::Call, Error> {
         let args_cache: Vec<_> = args_cache
-            .into_iter()
+            .iter()
+            .cloned()
             .map(|e| {
                 if let Some(hir::Expression::TypeReference(ty)) = e {
                     Some(ty.replace_with_type_info(context).into())
@@ -185,7 +343,10 @@ impl ToHIR for ast::Call {
                     match f_part {
                         FunctionNamePart::Text(_) => continue,
                         FunctionNamePart::Parameter(p) => {
-                            let arg = args_cache[i].as_ref().unwrap();
+                            let mut arg = args_cache[i].clone().unwrap();
+                            if !p.ty().is_generic() {
+                                arg.monomorphize_for(p.ty(), context);
+                            }
 
                             let arg_source_file = context.compiler().current_file().clone();
 
@@ -227,6 +388,15 @@ impl ToHIR for ast::Call {
                     .into());
                 }
 
+                let deprecated = f.read().unwrap().deprecated.clone();
+                if let Some(message) = deprecated {
+                    context.compiler_mut().warn(Deprecated {
+                        name: f.name().to_string(),
+                        message,
+                        at: self.range().into(),
+                    });
+                }
+
                 let generic = if f.read().unwrap().is_generic() {
                     Some(f.clone())
                 } else {
@@ -240,6 +410,16 @@ impl ToHIR for ast::Call {
                     args,
                 };
                 call.monomorphize(context);
+
+                // Builtin module is compiled without importing itself and isn't linted
+                if context.compiler().import_builtin
+                    && context.breaks_mut().is_some()
+                    && is_string_concatenation(&call.function.read().unwrap())
+                {
+                    context.compiler_mut().warn(ConcatenationInLoop {
+                        at: self.range().into(),
+                    });
+                }
                 return Ok(call);
             }
         }
@@ -270,11 +450,18 @@ impl ToHIR for ast::Call {
             self.name_parts[1].range()
         };
 
+        let suggestions = if candidates_not_viable.is_empty() {
+            similar_functions(self, context)
+        } else {
+            vec![]
+        };
+
         Err(NoFunction {
             kind: self.kind,
             name,
             arguments,
             candidates: candidates_not_viable,
+            suggestions,
             at: at.into(),
         }
         .into())
@@ -309,6 +496,10 @@ impl ToHIR for ast::TypeReference {
             return Err(UnknownType {
                 name: self.name.clone().to_string(),
                 at: self.name.range().into(),
+                suggestions: similar_names(name, context.type_names())
+                    .into_iter()
+                    .map(|name| FixIt::did_you_mean(self.name.range(), name))
+                    .collect(),
             }
             .into());
         }
@@ -351,6 +542,7 @@ impl ToHIR for ast::MemberReference {
             .enumerate()
             .find(|(_, m)| m.name() == self.name.as_str())
         {
+            check_access(member, &base.ty(), self.name.range(), context)?;
             let base = base.dereference();
             Ok(hir::MemberReference {
                 span: self.range().into(),
@@ -370,6 +562,26 @@ impl ToHIR for ast::MemberReference {
     }
 }
 
+/// Check that member of type `ty` may be used in current module
+fn check_access(
+    member: &Member,
+    ty: &Type,
+    at: Range<usize>,
+    context: &impl Context,
+) -> Result<(), PrivateMember> {
+    if let Type::Class(class) = ty.without_ref()
+        && member.is_private()
+        && class.read().unwrap().module != context.compiler().current_module()
+    {
+        return Err(PrivateMember {
+            ty: ty.without_ref(),
+            at: at.into(),
+            name: member.name().to_string(),
+        });
+    }
+    Ok(())
+}
+
 impl ToHIR for ast::Constructor {
     type HIR = hir::Constructor;
 
@@ -418,6 +630,8 @@ impl ToHIR for ast::Constructor {
                 .enumerate()
                 .find(|(_, m)| m.name() == name.as_str())
             {
+                let at = name.range();
+                check_access(member, &ty.referenced_type, at, &constructor_context)?;
                 let value = WithSourceLocation {
                     value: value.clone(),
                     source_location: SourceLocation {
@@ -490,7 +704,7 @@ impl ToHIR for ast::Constructor {
         Ok(hir::Constructor {
             ty,
             initializers,
-            rbrace: self.rbrace,
+            rbrace: self.rbrace.start(),
         })
     }
 }
@@ -502,14 +716,56 @@ impl ToHIR for ast::Expression {
     fn to_hir(&self, context: &mut impl Context) -> Result<Self::HIR, Self::Error> {
         Ok(match self {
             ast::Expression::Literal(l) => l.to_hir(context)?.into(),
-            ast::Expression::VariableReference(var) => var.to_hir(context)?.into(),
-            ast::Expression::Call(call) => call.to_hir(context)?.into(),
+            ast::Expression::VariableReference(var) => match var.to_hir(context) {
+                Ok(var) => var.into(),
+                // Fallback to call of function without parameters
+                Err(Error::UndefinedVariable(undefined)) => {
+                    let call = ast::Call {
+                        kind: FnKind::Function,
+                        name_parts: vec![CallNamePart::Text(var.name.clone())],
+                    };
+                    match call.to_hir(context) {
+                        Ok(call) => call.into(),
+                        // There is neither variable, nor function with such name
+                        Err(Error::NoFunction(err)) if err.candidates.is_empty() => {
+                            return Err(undefined.into());
+                        }
+                        Err(err) => return Err(err),
+                    }
+                }
+                Err(err) => return Err(err),
+            },
+            ast::Expression::Call(call) => fold_integer_division(call.to_hir(context)?)?,
             ast::Expression::Tuple(t) => t.to_hir(context)?.into(),
             ast::Expression::TypeReference(t) => {
                 t.to_hir(context)?.replace_with_type_info(context).into()
             }
-            ast::Expression::MemberReference(m) => m.to_hir(context)?.into(),
-            ast::Expression::Constructor(c) => c.to_hir(context)?.into(),
+            ast::Expression::MemberReference(m) => match m.to_hir(context) {
+                Ok(m) => m.into(),
+                // Fallback to property accessor or method call
+                Err(Error::NoMember(err)) => m
+                    .as_property_call()
+                    .to_hir(context)
+                    .or_else(|_| m.as_method_call().to_hir(context))
+                    .map_err(|call_error| Errors::from(vec![err.into(), call_error]))?
+                    .into(),
+                Err(err) => return Err(err),
+            },
+            ast::Expression::Loop(l) => l.to_hir(context)?.into(),
+            ast::Expression::Constructor(c) => {
+                let constructor = c.to_hir(context)?;
+                // Route constructor through user-defined `init`, if any
+                match context.init_for(constructor.ty()) {
+                    Some(init) => hir::Call {
+                        range: c.range(),
+                        function: init,
+                        generic: None,
+                        args: vec![constructor.into()],
+                    }
+                    .into(),
+                    None => constructor.into(),
+                }
+            }
         })
     }
 }
@@ -569,7 +825,7 @@ impl ToHIR for ast::Parameter {
             name: self.name.to_string(),
             name_range: self.name.range(),
             ty,
-            range: self.less..self.greater + 1,
+            range: self.range(),
         }))
     }
 }
@@ -589,6 +845,29 @@ impl ToHIR for ast::Annotation {
                 }
             }
             "builtin" if self.args.is_empty() => return Ok(hir::Annotation::Builtin),
+            "inline" if self.args.is_empty() => return Ok(hir::Annotation::Inline),
+            "noinline" if self.args.is_empty() => return Ok(hir::Annotation::NoInline),
+            "extern" => match self.args.as_slice() {
+                [] => return Ok(hir::Annotation::Extern(None)),
+                [ast::Expression::Literal(ast::Literal::String { value, .. })] => {
+                    return Ok(hir::Annotation::Extern(Some(value.clone())));
+                }
+                _ => {}
+            },
+            "intrinsic" => {
+                if let [ast::Expression::Literal(ast::Literal::String { value, .. })] =
+                    self.args.as_slice()
+                {
+                    return Ok(hir::Annotation::Intrinsic(value.clone()));
+                }
+            }
+            "deprecated" => match self.args.as_slice() {
+                [] => return Ok(hir::Annotation::Deprecated(String::new())),
+                [ast::Expression::Literal(ast::Literal::String { value, .. })] => {
+                    return Ok(hir::Annotation::Deprecated(value.clone()));
+                }
+                _ => {}
+            },
             _ => {}
         }
         Err(UnknownAnnotation {
@@ -606,14 +885,37 @@ impl ToHIR for ast::Assignment {
     fn to_hir(&self, context: &mut impl Context) -> Result<Self::HIR, Self::Error> {
         let target = self.target.to_hir(context)?;
         if target.is_immutable() {
+            let declared_at = match &target {
+                hir::Expression::VariableReference(var) => match &var.variable {
+                    hir::ParameterOrVariable::Variable(var) => Some(RelatedSpan {
+                        message: format!("`{}` is declared immutable here", var.name()),
+                        at: var.read().unwrap().name.range().into(),
+                        source_file: Some(
+                            var.read()
+                                .unwrap()
+                                .source_file
+                                .clone()
+                                .unwrap_or_else(|| context.compiler().current_file().clone()),
+                        ),
+                    }),
+                    hir::ParameterOrVariable::Parameter(p) => Some(RelatedSpan {
+                        message: format!("`{}` is declared immutable here", p.name()),
+                        at: p.read().unwrap().range().into(),
+                        source_file: Some(context.compiler().current_file().clone()),
+                    }),
+                },
+                _ => None,
+            };
             return Err(AssignmentToImmutable {
                 ty: target.ty(),
                 at: self.target.range().into(),
+                declared_at: declared_at.into_iter().collect(),
             }
             .into());
         }
 
-        let value = self.value.to_hir(context)?;
+        let mut value = self.value.to_hir(context)?;
+        value.monomorphize_for(target.ty().without_ref(), context);
         let value = value
             .convert_to(target.ty().without_ref().at(target.range()))
             .within(context)?;
@@ -627,7 +929,7 @@ impl ToHIR for ast::Return {
 
     /// Lower [`ast::Return`] to [`hir::Return`] within lowering context
     fn to_hir(&self, context: &mut impl Context) -> Result<Self::HIR, Self::Error> {
-        let value = self
+        let mut value = self
             .value
             .as_ref()
             .map(|expr| expr.to_hir(context))
@@ -635,7 +937,8 @@ impl ToHIR for ast::Return {
 
         if let Some(f) = context.function() {
             let return_type = f.read().unwrap().return_type.clone();
-            if let Some(value) = &value {
+            if let Some(value) = &mut value {
+                value.monomorphize_for(return_type.clone(), context);
                 if !value
                     .ty()
                     .convertible_to(return_type.clone())
@@ -725,13 +1028,21 @@ impl ToHIR for ast::Loop {
 
     /// Lower [`ast::Loop`] to [`hir::Loop`] within lowering context
     fn to_hir(&self, context: &mut impl Context) -> Result<Self::HIR, Self::Error> {
+        let mut context = LoopContext::for_loop(context);
+        let body = self
+            .body
+            .iter()
+            .map(|stmt| stmt.to_hir(&mut context))
+            .try_collect()?;
+        let ty = context
+            .breaks
+            .ty
+            .take()
+            .unwrap_or_else(|| context.builtin().types().none());
         Ok(hir::Loop {
             keyword: self.keyword.clone(),
-            body: self
-                .body
-                .iter()
-                .map(|stmt| stmt.to_hir(context))
-                .try_collect()?,
+            body,
+            ty,
         })
     }
 }
@@ -741,18 +1052,165 @@ impl ToHIR for ast::While {
 
     /// Lower [`ast::While`] to [`hir::While`] within lowering context
     fn to_hir(&self, context: &mut impl Context) -> Result<Self::HIR, Self::Error> {
+        let condition = self.condition.lower_condition_to_hir(context)?;
+        let mut context = LoopContext::for_while(context);
         Ok(hir::While {
             keyword: self.keyword.clone(),
-            condition: self.condition.lower_condition_to_hir(context)?,
+            condition,
             body: self
                 .body
                 .iter()
-                .map(|stmt| stmt.to_hir(context))
+                .map(|stmt| stmt.to_hir(&mut context))
                 .try_collect()?,
         })
     }
 }
 
+impl ToHIR for ast::Break {
+    type HIR = hir::Break;
+
+    /// Lower [`ast::Break`] to [`hir::Break`] within lowering context
+    fn to_hir(&self, context: &mut impl Context) -> Result<Self::HIR, Self::Error> {
+        let mut value = self
+            .value
+            .as_ref()
+            .map(|expr| expr.to_hir(context))
+            .transpose()?;
+
+        let Some(breaks) = context.breaks_mut().cloned() else {
+            return Err(BreakOutsideLoop {
+                at: self.range().into(),
+            }
+            .into());
+        };
+
+        if !breaks.with_value
+            && let Some(value) = &value
+        {
+            return Err(BreakWithValueOutOfWhile {
+                at: value.range().into(),
+            }
+            .into());
+        }
+
+        let ty = value
+            .as_ref()
+            .map_or_else(|| context.builtin().types().none(), |v| v.ty());
+        if let Some(expected) = breaks.ty {
+            if !ty
+                .convertible_to(expected.clone())
+                .within(context)
+                .is_ok_and(|convertible| convertible)
+            {
+                return Err(BreakTypeMismatch {
+                    got: ty,
+                    got_span: value.as_ref().map_or(self.range(), |v| v.range()).into(),
+
+                    expected,
+                }
+                .into());
+            }
+            value = value
+                .map(|value| {
+                    let range = value.range();
+                    value.convert_to(expected.at(range)).within(context)
+                })
+                .transpose()?;
+        } else if !ty.is_never() {
+            // Diverging breaks don't determine type of loop
+            context.breaks_mut().unwrap().ty = Some(ty);
+        }
+
+        Ok(hir::Break {
+            keyword: self.keyword.clone(),
+            value,
+        })
+    }
+}
+
+impl ToHIR for ast::For {
+    type HIR = hir::Block;
+
+    /// Lower [`ast::For`] to [`hir::Block`] within lowering context.
+    ///
+    /// Loop is desugared to iterator protocol:
+    /// ```ppl
+    /// let mut $iterator = iterator for <iterable>
+    /// while $iterator exists:
+    ///     let <name> = value from $iterator
+    ///     <body>
+    ///     advance $iterator
+    /// ```
+    /// Iterator type must implement builtin `Iterator` trait
+    fn to_hir(&self, context: &mut impl Context) -> Result<Self::HIR, Self::Error> {
+        let offset = self.keyword.start();
+        let iterator_name = Identifier::from(format!("$iterator@{offset}")).at(offset);
+        let iterator = || -> ast::Expression {
+            ast::VariableReference {
+                name: iterator_name.clone(),
+            }
+            .into()
+        };
+        let call = |name_parts: Vec<CallNamePart>| -> ast::Expression {
+            ast::Call {
+                kind: FnKind::Function,
+                name_parts,
+            }
+            .into()
+        };
+        let text = |text: &str, at: usize| CallNamePart::Text(Identifier::from(text).at(at));
+
+        let at = self.in_keyword.start();
+        let iterator_declaration: ast::Statement = ast::Declaration::from(ast::VariableDeclaration {
+            keyword: Keyword::<"let">::at(offset),
+            name: iterator_name.clone(),
+            ty: None,
+            initializer: call(vec![
+                text("iterator", at),
+                text("for", at),
+                CallNamePart::Argument(self.iterable.clone()),
+            ]),
+            mutability: Mutability::Mutable,
+        })
+        .into();
+
+        let value_declaration: ast::Statement = ast::Declaration::from(ast::VariableDeclaration {
+            keyword: Keyword::<"let">::at(offset),
+            name: self.name.clone(),
+            ty: None,
+            initializer: call(vec![
+                text("value", at),
+                text("from", at),
+                CallNamePart::Argument(iterator()),
+            ]),
+            mutability: Mutability::Immutable,
+        })
+        .into();
+
+        let mut body = vec![value_declaration];
+        body.extend(self.body.iter().cloned());
+        body.push(call(vec![text("advance", at), CallNamePart::Argument(iterator())]).into());
+
+        let r#while = ast::While {
+            keyword: Keyword::<"while">::at(offset),
+            condition: call(vec![CallNamePart::Argument(iterator()), text("exists", at)]),
+            body,
+        };
+
+        let iterator_declaration = iterator_declaration.to_hir(context)?;
+        if let hir::Statement::Declaration(hir::Declaration::Variable(var)) = &iterator_declaration
+            && let Type::Class(class) = var.ty()
+        {
+            let iterator = context.builtin().traits().iterator();
+            class.implements(iterator).within(context)?;
+        }
+
+        Ok(hir::Block {
+            statements: vec![iterator_declaration, r#while.to_hir(context)?.into()],
+        })
+    }
+}
+
 impl ToHIR for ast::Use {
     type HIR = hir::Use;
 
@@ -790,12 +1248,50 @@ impl ToHIR for ast::Use {
         let mut variables = IndexMap::new();
         let mut types = IndexMap::new();
 
+        let imported_from = module;
         let module = module.data(context.compiler());
+        // Functions with this name, ignoring parameters.
+        // This allows to import extensions like `fn <self: String>.shout`
+        let extensions: Vec<_> = module
+            .iter_functions()
+            .filter(|f| f.read().unwrap().text_name() == name)
+            .cloned()
+            .collect();
         let imported_item: hir::ImportedItem = if name == "*" {
-            functions = module.functions.clone();
-            variables = module.variables.clone();
-            types = module.types.clone();
+            functions = module
+                .functions
+                .iter()
+                .map(|(format, set)| {
+                    let set: IndexMap<_, _> = set
+                        .iter()
+                        .filter(|(_, f)| module.is_function_exported(f))
+                        .map(|(name, f)| (name.clone(), f.clone()))
+                        .collect();
+                    (format.clone(), set)
+                })
+                .filter(|(_, set)| !set.is_empty())
+                .collect();
+            variables = module
+                .variables
+                .iter()
+                .filter(|(name, _)| module.is_exported(name))
+                .map(|(name, var)| (name.clone(), var.clone()))
+                .collect();
+            types = module
+                .types
+                .iter()
+                .filter(|(name, _)| module.is_exported(name))
+                .map(|(name, ty)| (name.clone(), ty.clone()))
+                .collect();
             hir::ImportedItem::All
+        } else if !module.is_exported(name) {
+            return Err::<_, Self::Error>(
+                UnresolvedImport {
+                    name: name.to_string(),
+                    at: self.path.last().unwrap().range().into(),
+                }
+                .into(),
+            );
         } else if let Some(var) = module.variables.get(name) {
             variables.insert(var.name().to_string(), var.clone());
             var.clone().into()
@@ -808,6 +1304,14 @@ impl ToHIR for ast::Use {
                 .or_insert_with(IndexMap::new);
             set.insert(f.name().to_string(), f.clone().into());
             f.clone().into()
+        } else if !extensions.is_empty() {
+            for f in &extensions {
+                functions
+                    .entry(f.read().unwrap().name_format().to_string())
+                    .or_insert_with(IndexMap::new)
+                    .insert(f.name().to_string(), f.clone());
+            }
+            hir::ImportedItem::Functions(extensions)
         } else {
             return Err::<_, Self::Error>(
                 UnresolvedImport {
@@ -833,10 +1337,229 @@ impl ToHIR for ast::Use {
             keyword: self.keyword.clone(),
             path: self.path.clone(),
             imported_item,
+            module: imported_from,
         })
     }
 }
 
+impl ToHIR for ast::StaticAssert {
+    type HIR = hir::Block;
+
+    /// Check assertion at compile time. Lowers to empty [`hir::Block`]
+    fn to_hir(&self, context: &mut impl Context) -> Result<Self::HIR, Self::Error> {
+        let mut assertion = self.assertion.to_hir(context)?;
+        assertion.monomorphize(context);
+
+        let hir::Expression::Call(call) = assertion else {
+            return Err(NotAnAssertion {
+                at: self.assertion.range().into(),
+            }
+            .into());
+        };
+        let is_assert = call.function.read().unwrap().mangled_name.as_deref() == Some("assert");
+        if !is_assert {
+            return Err(NotAnAssertion {
+                at: self.assertion.range().into(),
+            }
+            .into());
+        }
+
+        let condition = &call.args[0];
+        if condition.const_eval()? == ConstValue::Bool(false) {
+            let mut message = call.args.get(1);
+            while let Some(hir::Expression::ImplicitConversion(c)) = message {
+                message = Some(&c.expression);
+            }
+            let message = match message {
+                Some(hir::Expression::Literal(hir::Literal::String { value, .. })) => value.clone(),
+                _ => "condition is false".to_string(),
+            };
+            return Err(StaticAssertionFailed {
+                message,
+                at: condition.range().into(),
+            }
+            .into());
+        }
+
+        Ok(hir::Block { statements: vec![] })
+    }
+}
+
+impl ToHIR for ast::Destructuring {
+    type HIR = hir::Block;
+
+    /// Lower [`ast::Destructuring`] to [`hir::Block`] of variable declarations
+    fn to_hir(&self, context: &mut impl Context) -> Result<Self::HIR, Self::Error> {
+        // Tuple literal is destructured into its elements
+        if self.ty.is_none()
+            && let ast::Expression::Tuple(tuple) = &self.initializer
+            && tuple.expressions.len() != 1
+        {
+            return self.tuple_to_hir(tuple, context);
+        }
+
+        let type_reference = self.ty.as_ref().map(|ty| ty.to_hir(context)).transpose()?;
+
+        let mut initializer = self.initializer.to_hir(context)?;
+        initializer.monomorphize(context);
+
+        let (ty, type_span) = match &type_reference {
+            Some(ty) => (ty.referenced_type.clone(), ty.span.clone()),
+            None => (initializer.ty().without_ref(), self.initializer.range()),
+        };
+        let class: hir::Class = ty.clone().try_into().map_err(|_| NonClassConstructor {
+            ty: TypeWithSpan {
+                at: type_span.clone().into(),
+                ty: ty.clone(),
+                // TODO: real source file
+                source_file: None,
+            },
+        })?;
+
+        let initializer = initializer
+            .convert_to(ty.clone().at(type_span.clone()))
+            .within(context)?;
+
+        let offset = self.start();
+        let object = Variable::new(VariableData {
+            keyword: self.keyword.clone(),
+            mutability: self.mutability.clone(),
+            name: Identifier::from(format!("$destructured@{offset}")).at(offset),
+            source_file: Some(context.compiler().current_file().clone()),
+            type_reference,
+            ty: ty.clone(),
+            initializer: Some(initializer),
+        });
+        context.add_variable(object.clone());
+
+        let members = class.read().unwrap().members().to_vec();
+        if self.ty.is_none() && self.names.len() != members.len() {
+            return Err(WrongNumberOfNames {
+                expected: members.len(),
+                got: self.names.len(),
+                at: self.initializer.range().into(),
+            }
+            .into());
+        }
+
+        let mut statements: Vec<hir::Statement> =
+            vec![hir::Declaration::from(object.clone()).into()];
+        for (i, name) in self.names.iter().enumerate() {
+            let found = if self.ty.is_some() {
+                members
+                    .iter()
+                    .enumerate()
+                    .find(|(_, m)| m.name() == name.as_str())
+            } else {
+                members.get(i).map(|m| (i, m))
+            };
+            let Some((index, member)) = found else {
+                return Err(NoMember {
+                    name: name.to_string(),
+                    at: name.range().into(),
+                    ty: ty.clone(),
+                    base_span: type_span.clone().into(),
+                }
+                .into());
+            };
+            check_access(member, &ty, name.range(), context)?;
+
+            let value: hir::Expression = hir::MemberReference {
+                span: name.range().into(),
+                base: Box::new(
+                    hir::VariableReference {
+                        span: self.initializer.range(),
+                        variable: object.clone().into(),
+                    }
+                    .into(),
+                ),
+                member: member.clone(),
+                index,
+            }
+            .into();
+            // Member is copied, as destructured object still owns it
+            let initializer = value
+                .convert_to(member.ty().at(name.range()))
+                .within(context)?;
+            let var = Variable::new(VariableData {
+                keyword: self.keyword.clone(),
+                mutability: self.mutability.clone(),
+                name: name.clone(),
+                source_file: Some(context.compiler().current_file().clone()),
+                type_reference: None,
+                ty: member.ty(),
+                initializer: Some(initializer),
+            });
+            context.add_variable(var.clone());
+            statements.push(hir::Declaration::from(var).into());
+        }
+
+        let missing: Vec<_> = members
+            .iter()
+            .filter(|m| !self.names.iter().any(|name| m.name() == name.as_str()))
+            .map(|m| m.name().to_string())
+            .collect();
+        if !missing.is_empty() {
+            return Err(MissingFields {
+                ty,
+                at: type_span.into(),
+                fields: missing.into(),
+            }
+            .into());
+        }
+
+        Ok(hir::Block { statements })
+    }
+}
+
+impl ast::Destructuring {
+    /// Lower destructuring of tuple literal to declarations of its elements.
+    /// All elements are evaluated before variables are introduced
+    fn tuple_to_hir(
+        &self,
+        tuple: &ast::Tuple,
+        context: &mut impl Context,
+    ) -> Result<hir::Block, Error> {
+        if tuple.expressions.len() != self.names.len() {
+            return Err(WrongNumberOfNames {
+                expected: tuple.expressions.len(),
+                got: self.names.len(),
+                at: tuple.range().into(),
+            }
+            .into());
+        }
+
+        let mut initializers = Vec::with_capacity(tuple.expressions.len());
+        for (name, expression) in self.names.iter().zip(&tuple.expressions) {
+            let mut initializer = expression.to_hir(context)?;
+            initializer.monomorphize(context);
+            let ty = initializer.ty();
+            initializers.push(
+                initializer
+                    .convert_to(ty.at(name.range()))
+                    .within(context)?,
+            );
+        }
+
+        let mut statements: Vec<hir::Statement> = vec![];
+        for (name, initializer) in self.names.iter().zip(initializers) {
+            let var = Variable::new(VariableData {
+                keyword: self.keyword.clone(),
+                mutability: self.mutability.clone(),
+                name: name.clone(),
+                source_file: Some(context.compiler().current_file().clone()),
+                type_reference: None,
+                ty: initializer.ty(),
+                initializer: Some(initializer),
+            });
+            context.add_variable(var.clone());
+            statements.push(hir::Declaration::from(var).into());
+        }
+
+        Ok(hir::Block { statements })
+    }
+}
+
 impl ToHIR for ast::Module {
     type HIR = hir::ModuleData;
     type Error = ErrVec<Error>;
@@ -865,12 +1588,28 @@ impl ToHIR for ast::Module {
                             stmt.monomorphize(context);
                             context.module_mut().statements.push(stmt)
                         }
+                        Err(Error::Errors(errs)) => errors.extend(errs.errors),
                         Err(err) => errors.push(err),
                     }
                 }
             };
         }
 
+        // Collect exported names
+        let exports: Vec<_> = self
+            .statements
+            .iter()
+            .filter_map(|s| match s {
+                S::Export(e) => Some(e),
+                _ => None,
+            })
+            .flat_map(|e| e.names.iter())
+            .collect();
+        if !exports.is_empty() {
+            context.module_mut().exports =
+                Some(exports.iter().map(|n| n.as_str().to_string()).collect());
+        }
+
         // Import things first
         self.statements
             .iter()
@@ -918,16 +1657,46 @@ impl ToHIR for ast::Module {
                             stmt.monomorphize(context);
                             context.module_mut().statements.push(stmt.into())
                         }
+                        Err(Error::Errors(errs)) => errors.extend(errs.errors),
                         Err(err) => errors.push(err),
                     }
                 }
             };
         }
 
-        // Declare Types & Traits
+        // Check that types and traits aren't redeclared
+        let mut type_names: HashMap<&str, &Identifier> = HashMap::new();
+        let mut redeclared = HashSet::new();
+        for (i, stmt) in self.statements.iter().enumerate() {
+            let name = match stmt {
+                S::Declaration(D::Type(t)) => &t.name,
+                S::Declaration(D::Trait(t)) => &t.name,
+                _ => continue,
+            };
+            if let Some(previous) = type_names.get(name.as_str()) {
+                errors.push(
+                    Redeclaration {
+                        name: name.to_string(),
+                        at: name.range().into(),
+                        previous: vec![RelatedSpan {
+                            message: "previous declaration is here".to_string(),
+                            at: previous.range().into(),
+                            source_file: Some(context.compiler().current_file().clone()),
+                        }],
+                    }
+                    .into(),
+                );
+                redeclared.insert(i);
+            } else {
+                type_names.insert(name.as_str(), name);
+            }
+        }
+
+        // Declare Types & Traits, skipping redeclared ones
         self.statements
             .iter()
             .enumerate()
+            .filter(|(i, _)| !redeclared.contains(i))
             .filter(|(_, s)| matches!(s, S::Declaration(D::Type(_) | D::Trait(_))))
             .for_each(declare!());
 
@@ -949,12 +1718,42 @@ impl ToHIR for ast::Module {
         self.statements
             .iter()
             .enumerate()
-            .filter(|(_, s)| !matches!(s, S::Use(_) | S::Declaration(D::Type(_))))
+            .filter(|(_, s)| !matches!(s, S::Use(_) | S::Export(_) | S::Declaration(D::Type(_))))
             .for_each(|(i, stmt)| match stmt {
                 S::Declaration(D::Trait(_) | D::Function(_)) => define!()((i, stmt)),
+                // Destructuring at top level declares global variables
+                S::Destructuring(d) => match d.to_hir(context) {
+                    Ok(block) => {
+                        for mut stmt in block.statements {
+                            stmt.monomorphize(context);
+                            context.module_mut().statements.push(stmt)
+                        }
+                    }
+                    Err(Error::Errors(errs)) => errors.extend(errs.errors),
+                    Err(err) => errors.push(err),
+                },
                 _ => to_ir!()(stmt),
             });
 
+        // Check that exported items exist
+        for name in exports {
+            let module = context.module();
+            let exists = module.types.contains_key(name.as_str())
+                || module.variables.contains_key(name.as_str())
+                || module
+                    .iter_functions()
+                    .any(|f| f.read().unwrap().text_name() == name.as_str());
+            if !exists {
+                errors.push(
+                    UndefinedExport {
+                        name: name.to_string(),
+                        at: name.range().into(),
+                    }
+                    .into(),
+                );
+            }
+        }
+
         if !errors.is_empty() {
             return Err(errors.into());
         }
@@ -962,10 +1761,17 @@ impl ToHIR for ast::Module {
         let mut module = context.module().clone();
         let name = module.name().to_string();
 
+        if context.compiler().import_builtin {
+            module.warn_unused(context);
+        }
+
         debug!(target: &format!("hir-{name}"), "\n{:#}", module);
         trace!(target: "steps", "Running passes on `{}`", module.source_file.path().display());
         module.drive_mut(&mut ParameterNamer::new());
         module.drive_mut(&mut TraitFunctionsLinker::new(context));
+        if context.compiler().strip_asserts {
+            module.drive_mut(&mut AssertsStripper);
+        }
         module.drive_mut(&mut TemporariesInserter::new());
         module.drive_mut(&mut Clonner::new(context));
         module.insert_destructors(context);
@@ -995,6 +1801,7 @@ impl ReplaceWithTypeInfo for TypeReference {
                 keyword: Keyword::<"let">::at(self.start()),
                 mutability: Mutability::Immutable,
                 name: Identifier::from(name).at(self.start()),
+                source_file: Some(context.compiler().current_file().clone()),
                 ty: self.type_for_type.clone(),
                 type_reference: None,
                 initializer: Some(
@@ -1074,3 +1881,442 @@ impl<T: ToHIR> ToHIR for Vec<T> {
         self.iter().map(|t| t.to_hir(context)).try_collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use tempdir::TempDir;
+
+    use super::*;
+    use crate::hir::SpecializeParameters;
+    use pretty_assertions::assert_eq;
+
+    /// Lower module with `source` and return its errors
+    fn errors(source: &str) -> Vec<Error> {
+        let mut compiler = Compiler::new();
+        let mut context = ModuleContext::new(ModuleData::default(), &mut compiler);
+        match source.parse::<ast::Module>().unwrap().to_hir(&mut context) {
+            Ok(_) => vec![],
+            Err(err) => err.errors,
+        }
+    }
+
+    const POINT: &str = "type Point:\n\tx: Integer\n\nfn <p: Point> shifted by <n: Integer> => p.x + n\n\nlet p = Point { x: 1 }\n";
+
+    #[test]
+    fn integer_division_folding() {
+        let mut compiler = Compiler::new();
+        let mut context = ModuleContext::new(ModuleData::default(), &mut compiler);
+        let module = "let x = 7 div (-2)\nlet y = 7 rem (-2)"
+            .parse::<ast::Module>()
+            .unwrap()
+            .to_hir(&mut context)
+            .unwrap();
+
+        let initializer = |name: &str| {
+            let variable = module.variables[name].read().unwrap();
+            variable.initializer.clone().unwrap()
+        };
+        assert!(matches!(
+            initializer("x"),
+            hir::Expression::Literal(hir::Literal::Integer { value, .. }) if value == -4
+        ));
+        assert!(matches!(
+            initializer("y"),
+            hir::Expression::Literal(hir::Literal::Integer { value, .. }) if value == 1
+        ));
+    }
+
+    #[test]
+    fn private_members() {
+        let dir = TempDir::new("ppl").unwrap();
+        fs::create_dir(dir.path().join("src")).unwrap();
+        fs::write(
+            dir.path().join("src/point.ppl"),
+            "type Point:\n\t_x: Integer\n\nfn origin => Point { _x: 0 }\nfn x of <p: &Point> => p._x\n",
+        )
+        .unwrap();
+
+        let compile = |main: &str| {
+            fs::write(dir.path().join("src/main.ppl"), main).unwrap();
+            let mut compiler = Compiler::new().at(dir.path());
+            compiler
+                .compile("main")
+                .map(|_| ())
+                .map_err(|err| err.downcast_ref::<Errors>().unwrap().errors.clone())
+        };
+
+        assert!(compile("use point.*\nprintln (x of origin)").is_ok());
+
+        let errors = compile("use point.*\nprintln origin._x").unwrap_err();
+        assert!(
+            matches!(errors.as_slice(), [Error::PrivateMember(_)]),
+            "{errors:?}"
+        );
+
+        let errors = compile("use point.*\nlet p = Point { _x: 1 }").unwrap_err();
+        assert!(
+            matches!(errors.as_slice(), [Error::PrivateMember(_)]),
+            "{errors:?}"
+        );
+
+        let errors = compile("use point.*\nlet Point { _x } = origin").unwrap_err();
+        assert!(
+            matches!(errors.as_slice(), [Error::PrivateMember(_)]),
+            "{errors:?}"
+        );
+
+        let errors = compile("use point.*\nlet (x) = origin").unwrap_err();
+        assert!(
+            matches!(errors.as_slice(), [Error::PrivateMember(_)]),
+            "{errors:?}"
+        );
+    }
+
+    #[test]
+    fn tuple_destructuring_arity() {
+        let mut compiler = Compiler::new();
+        let mut context = ModuleContext::new(ModuleData::default(), &mut compiler);
+        let errors = "type Point:\n\tx: Integer\n\ty: Integer\n\nlet (x, y, z) = (1, 2)\nlet (a) = Point { x: 1, y: 2 }"
+            .parse::<ast::Module>()
+            .unwrap()
+            .to_hir(&mut context)
+            .unwrap_err()
+            .errors;
+        assert!(
+            matches!(
+                errors.as_slice(),
+                [
+                    Error::WrongNumberOfNames(WrongNumberOfNames {
+                        expected: 2,
+                        got: 3,
+                        ..
+                    }),
+                    Error::WrongNumberOfNames(WrongNumberOfNames {
+                        expected: 2,
+                        got: 1,
+                        ..
+                    })
+                ]
+            ),
+            "{errors:?}"
+        );
+    }
+
+    #[test]
+    fn expected_type_propagation() {
+        let mut compiler = Compiler::new();
+        let mut context = ModuleContext::new(ModuleData::default(), &mut compiler);
+        let module = "fn<T> default -> T => default T
+fn twice <x: Integer> => x + x
+fn zero -> Integer:
+\treturn default
+
+let mut x = 1
+x = default
+let y = twice default
+"
+        .parse::<ast::Module>()
+        .unwrap()
+        .to_hir(&mut context)
+        .unwrap();
+
+        let y = module.variables["y"].read().unwrap();
+        assert!(y.ty().is_integer());
+    }
+
+    #[test]
+    fn call_without_parameters_errors() {
+        let errors = errors("let x = y");
+        assert!(
+            matches!(errors.as_slice(), [Error::UndefinedVariable(_)]),
+            "{errors:?}"
+        );
+
+        // Error of call isn't replaced with undefined variable
+        let errors = errors("fn first => second\nfn second => 1");
+        assert!(
+            matches!(errors.as_slice(), [Error::CantDeduceType(_)]),
+            "{errors:?}"
+        );
+    }
+
+    #[test]
+    fn never_is_coerced() {
+        let mut compiler = Compiler::new();
+        let mut context = ModuleContext::new(ModuleData::default(), &mut compiler);
+        let module = "fn fail -> Integer => exit with code 1
+let x: String = exit with code 2
+let y = loop:
+\tif 1 == 2:
+\t\tbreak exit with code 3
+\tbreak 1
+"
+        .parse::<ast::Module>()
+        .unwrap()
+        .to_hir(&mut context)
+        .unwrap();
+
+        let x = module.variables["x"].read().unwrap();
+        assert!(matches!(
+            x.initializer.as_ref().unwrap(),
+            hir::Expression::ImplicitConversion(hir::ImplicitConversion {
+                kind: hir::ImplicitConversionKind::Never,
+                ..
+            })
+        ));
+        assert!(x.initializer.as_ref().unwrap().ty().is_string());
+
+        // Diverging break doesn't determine type of loop
+        let y = module.variables["y"].read().unwrap();
+        assert!(y.ty().is_integer());
+    }
+
+    #[test]
+    fn never_binds_generic_last() {
+        let mut compiler = Compiler::new();
+        let mut context = ModuleContext::new(ModuleData::default(), &mut compiler);
+        let module = "fn<T> pick <a: T> or <b: T> -> T => a
+let x = pick (exit with code 1) or 2
+let y = pick 3 or (exit with code 4)
+"
+        .parse::<ast::Module>()
+        .unwrap()
+        .to_hir(&mut context)
+        .unwrap();
+
+        assert!(module.variables["x"].read().unwrap().ty().is_integer());
+        assert!(module.variables["y"].read().unwrap().ty().is_integer());
+    }
+
+    #[test]
+    fn division_by_zero() {
+        for op in ["div", "mod", "rem"] {
+            let errors = errors(&format!("let x = 1 {op} 0"));
+            assert!(
+                matches!(errors.as_slice(), [Error::DivisionByZero(_)]),
+                "{errors:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn method_call() {
+        assert_eq!(errors(&format!("{POINT}let y = p.shifted by 2")), vec![]);
+    }
+
+    #[test]
+    fn method_call_keeps_both_errors() {
+        let errors = errors(&format!("{POINT}let y = p.scaled by 2"));
+        assert!(
+            matches!(
+                errors.as_slice(),
+                [Error::NoMember(_), Error::NoFunction(_)]
+            ),
+            "{errors:?}"
+        );
+    }
+
+    #[test]
+    fn property_call_keeps_both_errors() {
+        let errors = errors(&format!("{POINT}let y = p.scaled"));
+        assert!(
+            matches!(
+                errors.as_slice(),
+                [Error::NoMember(_), Error::NoFunction(_)]
+            ),
+            "{errors:?}"
+        );
+    }
+
+    #[test]
+    fn method_call_only_after_no_function() {
+        let errors = errors(&format!("{POINT}let y = p.shifted by undefined"));
+        assert!(
+            matches!(errors.as_slice(), [Error::UndefinedVariable(_)]),
+            "{errors:?}"
+        );
+    }
+
+    #[test]
+    fn local_type_declaration() {
+        for decl in [
+            "type Point:\n\t\tx: Integer",
+            "trait Named:\n\t\tfn name of <:Self> -> String",
+        ] {
+            let errors = errors(&format!("fn origin:\n\t{decl}\n\tprintln 1"));
+            assert!(
+                matches!(errors.as_slice(), [Error::LocalTypeDeclaration(_)]),
+                "{errors:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn inline_annotations() {
+        assert_eq!(errors("@inline\nfn answer => 42"), vec![]);
+        assert_eq!(errors("@noinline\nfn answer => 42"), vec![]);
+
+        let errors = errors("@inline\n@noinline\nfn answer => 42");
+        assert!(
+            matches!(errors.as_slice(), [Error::ConflictingAnnotations(_)]),
+            "{errors:?}"
+        );
+    }
+
+    #[test]
+    fn inline_annotation_on_type() {
+        let errors = errors("@inline\ntype Point:\n\tx: Integer");
+        assert!(
+            matches!(errors.as_slice(), [Error::MisplacedAnnotation(_)]),
+            "{errors:?}"
+        );
+    }
+
+    #[test]
+    fn extern_functions() {
+        let mut compiler = Compiler::new();
+        let mut context = ModuleContext::new(ModuleData::default(), &mut compiler);
+        let source =
+            "extern fn absolute value of <x: I64> -> I64\nextern \"abs\" fn <x: I32> abs -> I32";
+        let module = source
+            .parse::<ast::Module>()
+            .unwrap()
+            .to_hir(&mut context)
+            .unwrap();
+
+        let is_bound_to = |symbol: &str| {
+            module
+                .iter_functions()
+                .any(|f| f.read().unwrap().mangled_name.as_deref() == Some(symbol))
+        };
+        assert!(is_bound_to("absolute_value_of"));
+        assert!(is_bound_to("abs"));
+    }
+
+    #[test]
+    fn extern_function_errors() {
+        let errors = errors("extern fn abs <x: I32> -> I32 => x");
+        assert!(
+            matches!(errors.as_slice(), [Error::ExternWithBody(_)]),
+            "{errors:?}"
+        );
+
+        for source in ["extern fn print <:String>", "extern fn answer -> Integer"] {
+            let errors = errors(source);
+            assert!(
+                matches!(errors.as_slice(), [Error::NonCAbiType(_)]),
+                "{errors:?}"
+            );
+        }
+
+        let errors = errors("@mangle_as(\"abs\")\nextern fn abs <x: I32> -> I32");
+        assert!(
+            matches!(errors.as_slice(), [Error::ConflictingAnnotations(_)]),
+            "{errors:?}"
+        );
+    }
+
+    #[test]
+    fn intrinsic_functions() {
+        assert_eq!(
+            errors("@intrinsic(\"llvm.sqrt.f64\")\nfn root of <:F64> -> F64"),
+            vec![]
+        );
+
+        for source in [
+            "@intrinsic(\"llvm.sqrt.f64\")\nfn root of <:I64> -> F64",
+            "@intrinsic(\"llvm.sqrt.f64\")\nfn <:F64> root <:F64> -> F64",
+            "@intrinsic(\"llvm.sqrt.f64\")\nfn root of <:String> -> F64",
+        ] {
+            let errors = errors(source);
+            assert!(
+                matches!(errors.as_slice(), [Error::IntrinsicSignatureMismatch(_)]),
+                "{errors:?}"
+            );
+        }
+
+        let errors = errors("@intrinsic(\"llvm.sine.f64\")\nfn sine of <:F64> -> F64");
+        assert!(
+            matches!(errors.as_slice(), [Error::UnknownIntrinsic(_)]),
+            "{errors:?}"
+        );
+
+        let errors = errors("@intrinsic(\"llvm.sqrt.f64\")\nfn root of <x: F64> -> F64 => x");
+        assert!(
+            matches!(errors.as_slice(), [Error::IntrinsicWithBody(_)]),
+            "{errors:?}"
+        );
+    }
+
+    #[test]
+    fn array_destructor_for_any_elements() {
+        let mut compiler = Compiler::new();
+        let mut context = ModuleContext::new(ModuleData::default(), &mut compiler);
+        let bool = context.builtin().types().bool();
+        let array: Type = context
+            .find_type("Array")
+            .unwrap()
+            .as_class()
+            .specialize_parameters(std::iter::once(bool))
+            .into();
+        assert!(context.destructor_for(array).is_some());
+    }
+
+    #[test]
+    fn deprecated_call_warns() {
+        let mut compiler = Compiler::new();
+        let mut context = ModuleContext::new(ModuleData::default(), &mut compiler);
+        "@deprecated(\"use `new answer` instead\")\nfn answer => 42\nlet _x = answer"
+            .parse::<ast::Module>()
+            .unwrap()
+            .to_hir(&mut context)
+            .unwrap();
+
+        assert_eq!(compiler.warnings.len(), 1);
+        assert_eq!(
+            compiler.warnings[0].to_string(),
+            "`answer` is deprecated: use `new answer` instead"
+        );
+    }
+
+    #[test]
+    fn for_loop_over_string() {
+        let source = "let str = \"héllo\"
+for c in str:
+\tprintln c
+for g in graphemes of str:
+\tprintln g";
+        assert_eq!(errors(source), vec![]);
+    }
+
+    #[test]
+    fn redeclaration() {
+        for source in [
+            "fn answer => 42\nfn answer => 0\n\nprintln answer",
+            "type Point:\n\tx: Integer\n\ntype Point:\n\ty: Integer\n\nlet p = Point { x: 1 }",
+            "type Point:\n\tx: Integer\n\tx: Integer",
+        ] {
+            let errors = errors(source);
+            assert!(
+                matches!(errors.as_slice(), [Error::Redeclaration(_)]),
+                "{errors:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn assignment_to_immutable() {
+        let errors = errors("let x = 1\nx = 2");
+        let [Error::AssignmentToImmutable(error)] = errors.as_slice() else {
+            panic!("{errors:?}");
+        };
+        let [declared_at] = error.declared_at.as_slice() else {
+            panic!("{error:?}");
+        };
+        assert_eq!(declared_at.message, "`x` is declared immutable here");
+        assert_eq!(declared_at.at, (4..5).into());
+        assert!(declared_at.source_file.is_some());
+    }
+}
@@ -0,0 +1,124 @@
+use std::{collections::HashSet, sync::Arc};
+
+use derive_visitor::{DriveMut, VisitorMut};
+
+use crate::{
+    hir::{
+        Call, Declaration, Function, ModuleData, ParameterOrVariable, Statement, Variable,
+        VariableReference,
+    },
+    named::Named,
+    syntax::Ranged,
+    DataHolder,
+};
+
+use super::{
+    warning::{UnusedFunction, UnusedVariable},
+    Context,
+};
+
+/// Address of data, shared by holders
+fn address<H: DataHolder>(holder: &H) -> *const () {
+    Arc::as_ptr(holder.inner()) as *const ()
+}
+
+/// Collects declarations and usages of variables and functions
+#[derive(VisitorMut, Default)]
+#[visitor(Declaration(enter), VariableReference(enter), Call(enter))]
+struct UsageCollector {
+    /// Declared variables
+    variables: Vec<Variable>,
+    /// Defined functions
+    functions: Vec<Function>,
+    /// Addresses of referenced variables and called functions
+    used: HashSet<*const ()>,
+}
+
+impl UsageCollector {
+    fn enter_declaration(&mut self, declaration: &mut Declaration) {
+        match declaration {
+            Declaration::Variable(var) => self.variables.push(var.clone()),
+            Declaration::Function(f) => self.functions.push(f.clone()),
+            _ => {}
+        }
+    }
+
+    fn enter_variable_reference(&mut self, reference: &mut VariableReference) {
+        if let ParameterOrVariable::Variable(var) = &reference.variable {
+            self.used.insert(address(var));
+        }
+    }
+
+    fn enter_call(&mut self, call: &mut Call) {
+        self.used.insert(address(&call.function));
+        if let Some(generic) = &call.generic {
+            self.used.insert(address(generic));
+        }
+    }
+}
+
+/// Trait to warn about unused declarations
+pub trait WarnUnused {
+    /// Warn about variables and functions, that are never used.
+    /// Names, starting with `_`, are skipped
+    fn warn_unused(&self, context: &mut impl Context);
+}
+
+impl WarnUnused for ModuleData {
+    fn warn_unused(&self, context: &mut impl Context) {
+        let mut collector = UsageCollector::default();
+        self.clone().drive_mut(&mut collector);
+
+        // Nothing imports main module, so its declarations aren't exported
+        let is_main = self.name() == "main";
+        let is_global = |var: &Variable| {
+            self.statements.iter().any(
+                |s| matches!(s, Statement::Declaration(Declaration::Variable(v)) if v.is_same(var)),
+            )
+        };
+
+        for var in collector.variables {
+            if collector.used.contains(&address(&var)) || var.is_temporary() {
+                continue;
+            }
+
+            let name = var.name().to_string();
+            if name.starts_with('_') || (!is_main && is_global(&var) && self.is_exported(&name)) {
+                continue;
+            }
+
+            let at = var.read().unwrap().name.range().into();
+            context.compiler_mut().warn(UnusedVariable { name, at });
+        }
+
+        for f in collector.functions {
+            if collector.used.contains(&address(&f)) {
+                continue;
+            }
+
+            let name = f.name().to_string();
+            if name.starts_with('_') || (!is_main && self.is_function_exported(&f)) {
+                continue;
+            }
+
+            let data = f.read().unwrap();
+            // Implementations of traits are called implicitly
+            let implements_trait = context.compiler().traits.values().any(|tr| {
+                tr.functions
+                    .values()
+                    .any(|tf| tf.read().unwrap().name_format() == data.name_format())
+            });
+            if !data.is_definition() || implements_trait {
+                continue;
+            }
+
+            let end = data
+                .name_parts
+                .last()
+                .map_or(data.keyword.end(), |p| p.end());
+            let at = (data.start()..end).into();
+            drop(data);
+            context.compiler_mut().warn(UnusedFunction { name, at });
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use miette::{Diagnostic, SourceSpan};
+use thiserror::Error;
+
+/// Warning for call of deprecated function
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq)]
+#[error("`{name}` is deprecated{}", if message.is_empty() { String::new() } else { format!(": {message}") })]
+#[diagnostic(code(semantics::deprecated), severity(Warning))]
+pub struct Deprecated {
+    /// Name of deprecated function
+    pub name: String,
+    /// Message of `@deprecated` annotation
+    pub message: String,
+    /// Span of call
+    #[label("deprecated function is called here")]
+    pub at: SourceSpan,
+}
+
+/// Warning for concatenation of strings inside of loop
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq)]
+#[error("strings are concatenated inside of loop")]
+#[diagnostic(
+    code(semantics::concatenation_in_loop),
+    severity(Warning),
+    help("repeated concatenation copies the whole string each time. Use `StringBuilder` instead")
+)]
+pub struct ConcatenationInLoop {
+    /// Span of concatenation
+    #[label("strings are concatenated here")]
+    pub at: SourceSpan,
+}
+
+/// Warning for variable, that is never used
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq)]
+#[error("variable `{name}` is never used")]
+#[diagnostic(
+    code(semantics::unused_variable),
+    severity(Warning),
+    help("prefix its name with `_`, if this is intentional")
+)]
+pub struct UnusedVariable {
+    /// Name of variable
+    pub name: String,
+    /// Span of variable's name
+    #[label("variable is declared here")]
+    pub at: SourceSpan,
+}
+
+/// Warning for function, that is never called
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq)]
+#[error("function `{name}` is never used")]
+#[diagnostic(
+    code(semantics::unused_function),
+    severity(Warning),
+    help("prefix its name with `_`, if this is intentional")
+)]
+pub struct UnusedFunction {
+    /// Name of function
+    pub name: String,
+    /// Span of function's signature
+    #[label("function is declared here")]
+    pub at: SourceSpan,
+}
@@ -6,7 +6,8 @@ use std::{
 
 use thiserror::Error;
 
-use miette::{MietteError, NamedSource, SourceCode, SpanContents};
+use miette::{MietteError, MietteSpanContents, NamedSource, SourceCode, SpanContents};
+use unicode_segmentation::UnicodeSegmentation;
 
 /// Wrapper around [`PathBuf`] that implements [`SourceCode`]
 #[derive(Debug, Clone)]
@@ -54,6 +55,11 @@ impl SourceFile {
         &self.path
     }
 
+    /// Contents of the source file
+    pub fn contents(&self) -> &str {
+        self.source.inner()
+    }
+
     /// Name of the source file
     pub fn name(&self) -> &str {
         self.source.name()
@@ -74,6 +80,49 @@ impl SourceFile {
         let last_line = str[..end].rfind('\n').map_or(0, |i| i + 1);
         ColumnNumber::from_zero_based(end - last_line)
     }
+
+    /// Column number for byte index in UTF-16 code units, as used by LSP
+    pub fn utf16_column_number(&self, offset: usize) -> ColumnNumber {
+        ColumnNumber::from_zero_based(self.line_before(offset).encode_utf16().count())
+    }
+
+    /// Column number for byte index in grapheme clusters, as seen by user
+    pub fn grapheme_column_number(&self, offset: usize) -> ColumnNumber {
+        ColumnNumber::from_zero_based(self.line_before(offset).graphemes(true).count())
+    }
+
+    /// Byte index for line and UTF-16 column number, as used by LSP
+    pub fn offset_from_utf16(&self, line: LineNumber, column: ColumnNumber) -> usize {
+        let str = self.source.inner();
+        let line_start = if line.zero_based() == 0 {
+            0
+        } else {
+            match str.match_indices('\n').nth(line.zero_based() - 1) {
+                Some((i, _)) => i + 1,
+                None => return str.len(),
+            }
+        };
+
+        let mut units = 0;
+        for (i, c) in str[line_start..].char_indices() {
+            if units >= column.zero_based() || c == '\n' {
+                return line_start + i;
+            }
+            units += c.len_utf16();
+        }
+        str.len()
+    }
+
+    /// Part of the line before byte index
+    fn line_before(&self, offset: usize) -> &str {
+        let str = self.source.inner();
+        let mut end = offset.min(str.len());
+        while !str.is_char_boundary(end) {
+            end -= 1;
+        }
+        let last_line = str[..end].rfind('\n').map_or(0, |i| i + 1);
+        &str[last_line..end]
+    }
 }
 
 impl SourceCode for SourceFile {
@@ -88,6 +137,71 @@ impl SourceCode for SourceFile {
     }
 }
 
+/// Source code, that shows only its part, starting at some statement.
+///
+/// Used by REPL, where source code of all entered statements is accumulated,
+/// to highlight just the last one
+#[derive(Debug, Clone)]
+pub struct StatementSource {
+    /// Name of the source
+    name: String,
+    /// Whole source code
+    source: String,
+    /// Byte offset of the statement
+    start: usize,
+}
+
+impl StatementSource {
+    /// Show `source` only starting at statement at `start` offset.
+    /// Whitespace before statement is skipped
+    pub fn new(name: impl Into<String>, source: impl Into<String>, start: usize) -> Self {
+        let source = source.into();
+        let start = start.min(source.len());
+        let statement = &source[start..];
+        let start = start + statement.len() - statement.trim_start().len();
+        Self {
+            name: name.into(),
+            source,
+            start,
+        }
+    }
+
+    /// Source code of the statement
+    pub fn statement(&self) -> &str {
+        &self.source[self.start..]
+    }
+}
+
+impl SourceCode for StatementSource {
+    fn read_span<'a>(
+        &'a self,
+        span: &miette::SourceSpan,
+        context_lines_before: usize,
+        context_lines_after: usize,
+    ) -> Result<Box<dyn SpanContents<'a> + 'a>, MietteError> {
+        let Some(offset) = span.offset().checked_sub(self.start) else {
+            // Span from one of the previous statements
+            return self
+                .source
+                .read_span(span, context_lines_before, context_lines_after);
+        };
+
+        let contents = self.statement().read_span(
+            &(offset, span.len()).into(),
+            context_lines_before,
+            context_lines_after,
+        )?;
+        Ok(Box::new(MietteSpanContents::new_named(
+            self.name.clone(),
+            contents.data(),
+            (contents.span().offset() + self.start, contents.span().len()).into(),
+            contents.line(),
+            contents.column(),
+            contents.line_count(),
+        )))
+    }
+}
+
 /// A line number
 pub struct LineNumber(usize);
 
@@ -178,6 +292,18 @@ mod tests {
         assert_eq!(source_file.line_number(n + 1).one_based(), 2);
     }
 
+    #[test]
+    fn statement_source() {
+        let source = StatementSource::new("repl", "let x = 1\n\nx + y\n", 9);
+        assert_eq!(source.statement(), "x + y\n");
+
+        let contents = source.read_span(&(15, 1).into(), 0, 0).unwrap();
+        assert_eq!(contents.name(), Some("repl"));
+        assert!(contents.data().starts_with(b"x + y"));
+        assert_eq!(contents.span().offset(), 11);
+        assert_eq!(contents.line(), 0);
+    }
+
     #[test]
     fn column_number() {
         let content = "Hello\nworld!";
@@ -202,4 +328,43 @@ mod tests {
         assert_eq!(source_file.column_number(n).one_based(), 7);
         assert_eq!(source_file.column_number(n + 1).one_based(), 7);
     }
+
+    #[test]
+    fn utf16_column_number() {
+        // 'п' is 2 bytes and 1 UTF-16 unit, '𝕡' is 4 bytes and 2 UTF-16 units
+        let content = "let x = \"п𝕡\"\nп";
+        let source_file =
+            SourceFile::in_memory(NamedSource::new("test".to_string(), content.to_string()));
+        assert_eq!(source_file.utf16_column_number(8).zero_based(), 8);
+        assert_eq!(source_file.utf16_column_number(9).zero_based(), 9);
+        assert_eq!(source_file.utf16_column_number(11).zero_based(), 10);
+        assert_eq!(source_file.utf16_column_number(15).zero_based(), 12);
+        assert_eq!(source_file.utf16_column_number(17).zero_based(), 0);
+        assert_eq!(source_file.utf16_column_number(19).zero_based(), 1);
+
+        let offset = |line, column| {
+            source_file.offset_from_utf16(
+                LineNumber::from_zero_based(line),
+                ColumnNumber::from_zero_based(column),
+            )
+        };
+        assert_eq!(offset(0, 0), 0);
+        assert_eq!(offset(0, 10), 11);
+        assert_eq!(offset(0, 12), 15);
+        assert_eq!(offset(0, 100), 16);
+        assert_eq!(offset(1, 0), 17);
+        assert_eq!(offset(1, 1), 19);
+        assert_eq!(offset(2, 0), content.len());
+    }
+
+    #[test]
+    fn grapheme_column_number() {
+        // "e\u{301}" is a single grapheme of 3 bytes
+        let content = "e\u{301}x";
+        let source_file =
+            SourceFile::in_memory(NamedSource::new("test".to_string(), content.to_string()));
+        assert_eq!(source_file.grapheme_column_number(0).zero_based(), 0);
+        assert_eq!(source_file.grapheme_column_number(3).zero_based(), 1);
+        assert_eq!(source_file.grapheme_column_number(4).zero_based(), 2);
+    }
 }
@@ -1,6 +1,6 @@
 use miette::SourceSpan;
 
-use crate::SourceFile;
+use crate::{ColumnNumber, LineNumber, SourceFile};
 
 /// Location inside a source file
 #[derive(Debug, Clone)]
@@ -11,6 +11,16 @@ pub struct SourceLocation {
     pub at: SourceSpan,
 }
 
+impl SourceLocation {
+    /// Line and UTF-16 column of the start of this location, as used by LSP.
+    /// Returns `None` if source file is unknown
+    pub fn utf16_start(&self) -> Option<(LineNumber, ColumnNumber)> {
+        let file = self.source_file.as_ref()?;
+        let offset = self.at.offset();
+        Some((file.line_number(offset), file.utf16_column_number(offset)))
+    }
+}
+
 impl<S: Into<SourceSpan>> From<S> for SourceLocation {
     fn from(value: S) -> Self {
         SourceLocation {
@@ -4,6 +4,7 @@ use miette::{Diagnostic, SourceSpan};
 use thiserror::Error;
 
 use super::Token;
+use crate::FixIt;
 
 /// Diagnostic for unwanted extra tokens
 #[derive(Error, Diagnostic, Debug, Clone, PartialEq, Eq)]
@@ -38,6 +39,26 @@ pub struct ExtraToken {
     pub at: SourceSpan,
 }
 
+/// Suggest to insert expected token at `offset`,
+/// if there is only one alternative and it's punctuation
+pub fn insert_expected(expected: &[Token], offset: usize) -> Vec<FixIt> {
+    match expected {
+        [token @ (Token::Assign
+        | Token::Colon
+        | Token::Arrow
+        | Token::FatArrow
+        | Token::LParen
+        | Token::RParen
+        | Token::LBrace
+        | Token::RBrace
+        | Token::Dot
+        | Token::Comma
+        | Token::Less
+        | Token::Greater)] => vec![FixIt::insert(token.to_string(), offset)],
+        _ => vec![],
+    }
+}
+
 /// Diagnostic for missing token
 #[derive(Error, Debug, Clone, Diagnostic, PartialEq, Eq)]
 #[diagnostic(code(lexer::missing_token))]
@@ -48,6 +69,10 @@ pub struct MissingToken {
     /// Span of the token
     #[label("here")]
     pub at: SourceSpan,
+
+    /// Suggestions to insert expected token
+    #[related]
+    pub fix_its: Vec<FixIt>,
 }
 
 impl Display for MissingToken {
@@ -80,6 +105,10 @@ pub struct UnexpectedToken {
     /// Span of the token
     #[label]
     pub at: SourceSpan,
+
+    /// Suggestions to insert expected token
+    #[related]
+    pub fix_its: Vec<FixIt>,
 }
 
 impl Display for UnexpectedToken {
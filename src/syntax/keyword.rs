@@ -1,6 +1,9 @@
 use super::{Ranged, Token};
 
-/// A keyword in the language
+/// Punctuation with its position, e.g `Punctuation<"(">`
+pub type Punctuation<const PUNCTUATION: &'static str> = Keyword<PUNCTUATION>;
+
+/// A keyword or punctuation in the language
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 pub struct Keyword<const KEYWORD: &'static str> {
     /// Position of the keyword in the source code
@@ -33,9 +36,21 @@ impl<const KEYWORD: &'static str> Keyword<KEYWORD> {
             "false" => Token::False,
             "loop" => Token::Loop,
             "while" => Token::While,
+            "break" => Token::Break,
+            "for" => Token::For,
+            "in" => Token::In,
             "trait" => Token::Trait,
             "use" => Token::Use,
+            "export" => Token::Export,
+            "extern" => Token::Extern,
+            "static" => Token::Static,
             "&" => Token::Ampersand,
+            "(" => Token::LParen,
+            ")" => Token::RParen,
+            "{" => Token::LBrace,
+            "}" => Token::RBrace,
+            "<" => Token::Less,
+            ">" => Token::Greater,
             _ => panic!("Unknown keyword: {}", KEYWORD),
         }
     }
@@ -3,7 +3,7 @@ use std::cell::RefCell;
 use log::debug;
 use logos::{Logos, Span};
 
-use crate::syntax::error::{LexerError, MissingToken, UnexpectedToken};
+use crate::syntax::error::{insert_expected, LexerError, MissingToken, UnexpectedToken};
 
 use super::{OperatorKind, StringWithOffset, Token};
 
@@ -96,7 +96,8 @@ pub trait Lexer: Iterator<Item = Token> {
     /// 		UnexpectedToken {
     /// 			expected: vec![Token::Id],
     /// 			got: Token::Integer,
-    /// 			at: lexer.peek_span().into()
+    /// 			at: lexer.peek_span().into(),
+    /// 			fix_its: vec![],
     /// 		}.into()
     /// 	)
     /// );
@@ -125,7 +126,8 @@ pub trait Lexer: Iterator<Item = Token> {
     /// 		UnexpectedToken {
     /// 			expected: vec![Token::None, Token::Id],
     /// 			got: Token::Integer,
-    /// 			at: lexer.peek_span().into()
+    /// 			at: lexer.peek_span().into(),
+    /// 			fix_its: vec![],
     /// 		}.into()
     /// 	)
     /// );
@@ -139,6 +141,7 @@ pub trait Lexer: Iterator<Item = Token> {
             return Err(MissingToken {
                 expected: tokens.to_vec(),
                 at: self.span().end.into(),
+                fix_its: insert_expected(tokens, self.span().end),
             }
             .into());
         }
@@ -154,6 +157,7 @@ pub trait Lexer: Iterator<Item = Token> {
                 expected: tokens.to_owned(),
                 got: token,
                 at: self.peek_span().into(),
+                fix_its: insert_expected(tokens, self.peek_span().start),
             }
             .into());
         }
@@ -179,7 +183,8 @@ pub trait Lexer: Iterator<Item = Token> {
     /// 		UnexpectedToken {
     /// 			expected: vec![Token::Id],
     /// 			got: Token::Integer,
-    /// 			at: lexer.peek_span().into()
+    /// 			at: lexer.peek_span().into(),
+    /// 			fix_its: vec![],
     /// 		}.into()
     /// 	)
     /// );
@@ -207,7 +212,8 @@ pub trait Lexer: Iterator<Item = Token> {
     /// 		UnexpectedToken {
     /// 			expected: vec![Token::None, Token::Id],
     /// 			got: Token::Integer,
-    /// 			at: lexer.peek_span().into()
+    /// 			at: lexer.peek_span().into(),
+    /// 			fix_its: vec![],
     /// 		}.into()
     /// 	)
     /// );
@@ -244,7 +250,8 @@ pub trait Lexer: Iterator<Item = Token> {
     ///                 Token::Ampersand
     /// 			],
     /// 			got: Token::Integer,
-    /// 			at: lexer.peek_span().into()
+    /// 			at: lexer.peek_span().into(),
+    /// 			fix_its: vec![],
     /// 		}.into()
     /// 	)
     /// );
@@ -314,6 +321,23 @@ pub trait Lexer: Iterator<Item = Token> {
         self.next();
         self
     }
+
+    /// Skip tokens until next non-blank line with at most `indentation` tabs.
+    /// Used to recover from syntax errors, as such line starts a new statement
+    /// of the current block or ends it
+    fn skip_till_next_statement(&mut self, indentation: usize) -> &mut Self {
+        loop {
+            self.skip_till_next_line();
+            self.skip_indentation();
+            match self.peek() {
+                None => break,
+                Some(Token::Newline) => continue,
+                _ if self.indentation() <= indentation => break,
+                _ => continue,
+            }
+        }
+        self
+    }
 }
 
 /// Lexer for full source code of PPL
@@ -690,7 +714,7 @@ impl<F: Fn() -> String> Lexer for InteractiveLexer<F> {
 mod tests {
     use crate::syntax::Lexer;
 
-    use super::InteractiveLexer;
+    use super::{FullSourceLexer, InteractiveLexer};
 
     #[test]
     fn correct_peek_after_skipping_newlines() {
@@ -717,4 +741,22 @@ mod tests {
         assert_eq!(lexer.peek_slice(), "x");
         assert_eq!(lexer.peek_span(), 2..3);
     }
+
+    #[test]
+    fn range_operator() {
+        let mut lexer = FullSourceLexer::new("1..3");
+
+        assert_eq!(lexer.next(), Some(super::Token::Integer));
+        assert_eq!(lexer.slice(), "1");
+
+        assert_eq!(
+            lexer.next(),
+            Some(super::Token::Operator(super::OperatorKind::Infix))
+        );
+        assert_eq!(lexer.slice(), "..");
+        assert_eq!(lexer.span(), 1..3);
+
+        assert_eq!(lexer.next(), Some(super::Token::Integer));
+        assert_eq!(lexer.slice(), "3");
+    }
 }
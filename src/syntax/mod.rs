@@ -12,6 +12,9 @@ pub use identifier::*;
 mod keyword;
 pub use keyword::*;
 
+mod symbol;
+pub use symbol::*;
+
 mod with_offset;
 pub use with_offset::*;
 
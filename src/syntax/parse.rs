@@ -2,7 +2,7 @@ use std::ops::Range;
 
 use super::{
     error::{EmptyBlock, LexerError, ParseError},
-    Identifier, Keyword, PrecedenceGroups, Ranged, StringWithOffset, Token,
+    Identifier, Keyword, PrecedenceGroups, Punctuation, Ranged, StringWithOffset, Token,
 };
 
 /// Context for parsing
@@ -11,11 +11,19 @@ pub struct Context<Lexer: super::Lexer> {
     pub lexer: Lexer,
     /// Currently active precedence groups for operators
     pub precedence_groups: PrecedenceGroups,
+    /// Recover from errors inside of blocks, instead of stopping at the first one
+    pub recover: bool,
+    /// Errors, that parser recovered from
+    pub errors: Vec<ParseError>,
 }
 
 impl<Lexer: super::Lexer> Context<Lexer> {
     /// Consume end of line (newline or eof)
     pub fn consume_eol(&mut self) -> Result<Option<StringWithOffset>, LexerError> {
+        if self.block_ended() {
+            return Ok(None);
+        }
+
         if self.lexer.peek().is_some() {
             return Ok(Some(self.lexer.consume(Token::Newline)?));
         }
@@ -31,12 +39,40 @@ impl<Lexer: super::Lexer> Context<Lexer> {
         Ok(Keyword::<KEYWORD>::at(offset))
     }
 
+    /// Consume punctuation
+    pub fn consume_punctuation<const PUNCTUATION: &'static str>(
+        &mut self,
+    ) -> Result<Punctuation<PUNCTUATION>, LexerError> {
+        if PUNCTUATION == ">" {
+            // `>>` may be closing 2 generic lists
+            let offset = self.lexer.consume_greater()?.start();
+            return Ok(Punctuation::at(offset));
+        }
+        self.consume_keyword::<PUNCTUATION>()
+    }
+
     /// Consume id or escaped id
     pub fn consume_id(&mut self) -> Result<Identifier, LexerError> {
         self.lexer.consume_one_of(&[Token::Id, Token::EscapedId])?;
         Ok(self.lexer.string_with_offset().into())
     }
 
+    /// Consume name of function without parameters, e.g. `distance from to`.
+    /// Words of name are joined with single space
+    pub fn consume_function_name(&mut self) -> Result<Identifier, LexerError> {
+        let first = self.consume_id()?;
+        let mut name = first.as_str().to_string();
+        while self
+            .lexer
+            .consume_one_of(&[Token::Id, Token::EscapedId, Token::In, Token::For])
+            .is_ok()
+        {
+            name.push(' ');
+            name.push_str(Identifier::from(self.lexer.string_with_offset()).as_str());
+        }
+        Ok(Identifier::from(name).at(first.start()))
+    }
+
     /// Parse block of items
     pub fn parse_maybe_empty_block<T>(
         &mut self,
@@ -49,7 +85,14 @@ impl<Lexer: super::Lexer> Context<Lexer> {
 
         let mut stmts = Vec::new();
         while self.lexer.indentation() == indentation && self.lexer.peek().is_some() {
-            stmts.push(parse(self)?);
+            match parse(self) {
+                Ok(stmt) => stmts.push(stmt),
+                Err(err) if self.recover => {
+                    self.errors.push(err);
+                    self.lexer.skip_till_next_statement(indentation);
+                }
+                Err(err) => return Err(err),
+            }
             self.lexer.skip_indentation();
         }
 
@@ -62,9 +105,11 @@ impl<Lexer: super::Lexer> Context<Lexer> {
         parse: impl Fn(&mut Self) -> Result<T, ParseError>,
         error_range: Range<usize>,
     ) -> Result<Vec<T>, ParseError> {
+        let recovered = self.errors.len();
         let stmts = self.parse_maybe_empty_block(parse)?;
 
-        if stmts.is_empty() {
+        // Block isn't empty, if its statements had errors
+        if stmts.is_empty() && self.errors.len() == recovered {
             return Err(EmptyBlock {
                 at: error_range.into(),
             }
@@ -100,6 +145,14 @@ impl<Lexer: super::Lexer> Context<Lexer> {
         self.parse_separated(parse, Token::Comma)
     }
 
+    /// Has block just ended?
+    ///
+    /// Block consumes end of its last line, so nothing else
+    /// may follow it on the same line
+    pub fn block_ended(&self) -> bool {
+        matches!(self.lexer.token(), Some(Token::Newline | Token::Tab))
+    }
+
     /// Has space between current token and the next one?
     pub fn has_space_before_next_token(&mut self) -> bool {
         self.lexer.peek_span().start != self.lexer.span().end
@@ -117,6 +170,8 @@ impl<'l, Lexer: super::Lexer> Context<Lexer> {
         Self {
             lexer,
             precedence_groups: PrecedenceGroups::default(),
+            recover: false,
+            errors: Vec::new(),
         }
     }
 }
@@ -67,6 +67,10 @@ impl Default for PrecedenceGroups {
                     name: "ComparisonPrecedence".to_string(),
                     associativity: Associativity::Chain,
                 },
+                PrecedenceGroup {
+                    name: "RangePrecedence".to_string(),
+                    associativity: Associativity::Left,
+                },
                 PrecedenceGroup {
                     name: "AdditionPrecedence".to_string(),
                     associativity: Associativity::Left,
@@ -75,6 +79,10 @@ impl Default for PrecedenceGroups {
                     name: "MultiplicationPrecedence".to_string(),
                     associativity: Associativity::Left,
                 },
+                PrecedenceGroup {
+                    name: "BitwiseShiftPrecedence".to_string(),
+                    associativity: Associativity::Left,
+                },
                 PrecedenceGroup {
                     name: "PowerPrecedence".to_string(),
                     associativity: Associativity::Right,
@@ -87,12 +95,17 @@ impl Default for PrecedenceGroups {
                 (">".to_string(), 1),
                 ("<=".to_string(), 1),
                 (">=".to_string(), 1),
-                ("+".to_string(), 2),
-                ("-".to_string(), 2),
-                ("*".to_string(), 3),
-                ("/".to_string(), 3),
-                ("%".to_string(), 3),
-                ("^".to_string(), 4),
+                ("..".to_string(), 2),
+                ("+".to_string(), 3),
+                ("-".to_string(), 3),
+                ("|".to_string(), 3),
+                ("*".to_string(), 4),
+                ("/".to_string(), 4),
+                ("%".to_string(), 4),
+                ("&".to_string(), 4),
+                ("<<".to_string(), 5),
+                (">>".to_string(), 5),
+                ("^".to_string(), 6),
             ]
             .into_iter()
             .collect(),
@@ -0,0 +1,120 @@
+use std::{
+    cell::RefCell,
+    collections::HashSet,
+    fmt::{Debug, Display},
+    ops::Deref,
+    sync::{Arc, Mutex},
+};
+
+thread_local! {
+    /// Interner, that is used for strings created on this thread
+    static CURRENT: RefCell<Option<Interner>> = const { RefCell::new(None) };
+}
+
+/// Interned strings of a single compilation.
+/// Each distinct string is allocated only once,
+/// and freed when the interner and all its symbols are dropped
+#[derive(Debug, Default, Clone)]
+pub struct Interner {
+    symbols: Arc<Mutex<HashSet<Arc<str>>>>,
+}
+
+impl Interner {
+    /// Intern string
+    ///
+    /// # Example
+    /// ```
+    /// use ppl::syntax::Interner;
+    ///
+    /// let interner = Interner::default();
+    /// let a = interner.intern("hello");
+    /// let b = interner.intern(&"hello".to_string());
+    /// assert!(std::ptr::eq(a.as_str(), b.as_str()));
+    /// ```
+    pub fn intern(&self, value: &str) -> Symbol {
+        let mut symbols = self.symbols.lock().unwrap();
+        if let Some(symbol) = symbols.get(value) {
+            return Symbol(symbol.clone());
+        }
+
+        let symbol: Arc<str> = value.into();
+        symbols.insert(symbol.clone());
+        Symbol(symbol)
+    }
+
+    /// Intern all symbols, created inside `f`, with this interner
+    ///
+    /// # Example
+    /// ```
+    /// use ppl::syntax::{Interner, Symbol};
+    ///
+    /// let interner = Interner::default();
+    /// let (a, b) = interner.scope(|| (Symbol::intern("hello"), Symbol::intern("hello")));
+    /// assert!(std::ptr::eq(a.as_str(), b.as_str()));
+    /// ```
+    pub fn scope<R>(&self, f: impl FnOnce() -> R) -> R {
+        /// Restores previous interner, even if `f` panics
+        struct Restore(Option<Interner>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                CURRENT.set(self.0.take());
+            }
+        }
+
+        let _restore = Restore(CURRENT.replace(Some(self.clone())));
+        f()
+    }
+}
+
+/// Interned string, that is cheap to clone
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct Symbol(Arc<str>);
+
+impl Symbol {
+    /// Intern string with the current [`Interner`].
+    /// Outside of [`Interner::scope`] the string is not shared
+    pub fn intern(value: &str) -> Self {
+        CURRENT.with_borrow(|interner| match interner {
+            Some(interner) => interner.intern(value),
+            None => Self(value.into()),
+        })
+    }
+
+    /// Convert this to [`&str`]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for Symbol {
+    fn from(value: &str) -> Self {
+        Self::intern(value)
+    }
+}
+
+impl From<String> for Symbol {
+    fn from(value: String) -> Self {
+        Self::intern(&value)
+    }
+}
+
+impl Deref for Symbol {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Debug for Symbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", &*self.0)
+    }
+}
+
+impl Display for Symbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", &*self.0)
+    }
+}
@@ -81,6 +81,7 @@ pub enum Token {
 
     /// Token for operator
     #[regex(r"[-+*/=<>?!~|&^%$#\\]+", operator, priority = 0)]
+    #[token("..", operator)]
     Operator(OperatorKind),
 
     /// '&' token
@@ -195,6 +196,18 @@ pub enum Token {
     #[token("while")]
     While,
 
+    /// "break" token
+    #[token("break")]
+    Break,
+
+    /// "for" token
+    #[token("for")]
+    For,
+
+    /// "in" token
+    #[token("in")]
+    In,
+
     /// "trait" token
     #[token("trait")]
     Trait,
@@ -219,6 +232,18 @@ pub enum Token {
     #[token("use")]
     Use,
 
+    /// "export" token
+    #[token("export")]
+    Export,
+
+    /// "extern" token
+    #[token("extern")]
+    Extern,
+
+    /// "static" token
+    #[token("static")]
+    Static,
+
     /// Error token
     #[regex("\n[ ]+", |_| ErrorKind::InvalidIndentation)]
     Error(ErrorKind),
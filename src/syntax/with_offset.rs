@@ -3,7 +3,7 @@ use std::{
     ops::{Deref, Range},
 };
 
-use super::Ranged;
+use super::{Ranged, Symbol};
 
 /// Value with starting offset
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -14,13 +14,14 @@ pub struct WithOffset<T> {
     pub value: T,
 }
 
-/// String at some offset
+/// String at some offset.
+/// Strings are interned, so tokens don't allocate
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub struct StringWithOffset {
     /// Offset of the start of the string
     pub offset: usize,
     /// String value
-    pub value: String,
+    pub value: Symbol,
 }
 
 impl StringWithOffset {
@@ -51,7 +52,7 @@ impl From<&str> for StringWithOffset {
     fn from(value: &str) -> Self {
         Self {
             offset: 0,
-            value: value.to_string(),
+            value: value.into(),
         }
     }
 }
@@ -67,7 +68,10 @@ impl From<String> for StringWithOffset {
     /// assert_eq!(value.range(), 0..5);
     /// ```
     fn from(value: String) -> Self {
-        Self { offset: 0, value }
+        Self {
+            offset: 0,
+            value: value.into(),
+        }
     }
 }
 
@@ -75,7 +79,7 @@ impl Deref for StringWithOffset {
     type Target = str;
 
     fn deref(&self) -> &Self::Target {
-        &self.value
+        self.value.as_str()
     }
 }
 
@@ -87,13 +91,13 @@ impl Display for StringWithOffset {
 
 impl From<StringWithOffset> for String {
     fn from(value: StringWithOffset) -> Self {
-        value.value
+        value.value.to_string()
     }
 }
 
 impl From<&StringWithOffset> for String {
     fn from(value: &StringWithOffset) -> Self {
-        value.value.clone()
+        value.value.to_string()
     }
 }
 
@@ -114,13 +118,13 @@ impl Ranged for StringWithOffset {
 
 impl PartialEq<&str> for StringWithOffset {
     fn eq(&self, other: &&str) -> bool {
-        self.value == *other
+        self.value.as_str() == *other
     }
 }
 
 impl StringWithOffset {
     /// Convert this to [`&str`]
     pub fn as_str(&self) -> &str {
-        &self.value
+        self.value.as_str()
     }
 }
@@ -3,24 +3,46 @@ use crate::e2es;
 e2es! {
     address_of,
     array,
+    array_api,
+    array_ownership,
+    assignment_to_immutable,
+    break_outside_loop,
     candidate_not_viable,
+    channel,
     cant_use_global_before_decl,
     clone,
     common_functions,
+    concatenation_in_loop,
     constraints,
     constraints_in_constructor,
     consume_greater,
+    deprecated,
     deps,
     deref_member_ref,
     destructor,
+    destructuring,
+    did_you_mean_function,
+    did_you_mean_type,
+    did_you_mean_variable,
+    duplicate_function,
+    duplicate_member,
+    duplicate_type,
     empty_block,
     empty_constructor,
     escaped_id,
+    extern_function,
+    extern_with_body,
+    for_loop,
+    format,
     generics,
     import_all,
+    import_extension,
+    init,
     integer,
     integer_not_eq_rational,
     invalid_indentation,
+    loop_value,
+    math,
     memory,
     missing_fields,
     monomorphize,
@@ -28,6 +50,9 @@ e2es! {
     multifile,
     multiple_errors,
     multiple_initialization,
+    mutex,
+    never,
+    non_c_abi_type,
     non_class_constructor,
     plus_assign,
     predeclare_function,
@@ -37,16 +62,26 @@ e2es! {
     reference_to_literal,
     reference_to_none,
     references,
+    set,
+    short_circuit,
     specify_variable_ty,
     star,
     store_ref,
     string,
+    string_api,
+    string_builder,
+    string_concatenation,
+    string_iteration,
     supertraits,
+    trait_object_pair,
+    trait_objects,
     traits,
     type_as_value,
     swap,
     trait_with_ref,
     type_of,
+    unused_function,
+    unused_variable,
     wrong_initializer_type
 }
 
@@ -76,3 +111,138 @@ fn ppl() {
     let ir = crate::e2e::internal::ir(&tmp, name, &dir);
     assert_snapshot!("ppl.ir", ir);
 }
+
+#[test]
+fn emit() {
+    use std::path::Path;
+
+    use tempdir::TempDir;
+
+    use crate::driver::commands::compile::OutputType;
+
+    let dir = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/src/tests/multifile"));
+    let output_types = [
+        ("ir", OutputType::IR),
+        ("bitcode", OutputType::Bitcode),
+        ("object", OutputType::Object),
+        ("assembler", OutputType::Assembler),
+    ];
+    for (emit, output_type) in output_types {
+        let temp_dir = TempDir::new("ppl").unwrap();
+        let tmp = temp_dir.path();
+        crate::e2e::internal::emit(&tmp, &dir, emit);
+
+        for module in ["main", "greet"] {
+            let file = tmp.join(output_type.named(module));
+            assert!(file.exists(), "{} is missing", file.display());
+        }
+    }
+}
+
+#[test]
+fn optimized() {
+    use std::path::Path;
+
+    use insta::assert_snapshot;
+    use miette::miette;
+    use tempdir::TempDir;
+
+    let temp_dir = TempDir::new("ppl").unwrap();
+    let tmp = temp_dir.path();
+    let name = "optimized";
+    let dir = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/src/tests/optimized"));
+
+    crate::e2e::internal::compile_optimized(&tmp, &dir, 2);
+
+    let (run_log, status) = crate::e2e::internal::run(&tmp, name, &dir);
+    assert_snapshot!("optimized.run", run_log);
+    status.exit_ok().map_err(|e| miette!("{e}")).unwrap();
+}
+
+#[test]
+fn panic_location() {
+    use std::path::Path;
+
+    use tempdir::TempDir;
+
+    let temp_dir = TempDir::new("ppl").unwrap();
+    let tmp = temp_dir.path();
+    let name = "panic_location";
+    let dir = Path::new(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/src/tests/panic_location"
+    ));
+
+    crate::e2e::internal::compile(&tmp, &dir);
+
+    let (run_log, status) = crate::e2e::internal::run(&tmp, name, &dir);
+    assert!(!status.success(), "{run_log}");
+    assert!(run_log.contains("division by zero at "), "{run_log}");
+    assert!(run_log.contains("main.ppl:3:9"), "{run_log}");
+}
+
+#[test]
+fn program_arguments() {
+    use std::fs;
+
+    use insta::assert_snapshot;
+    use tempdir::TempDir;
+
+    // `ppl run` builds package in its directory, so copy it to temporary one
+    let temp_dir = TempDir::new("ppl").unwrap();
+    let package = temp_dir.path().join("program_arguments");
+    fs::create_dir_all(package.join("src")).unwrap();
+    fs::copy(
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/tests/program_arguments/src/main.ppl"
+        ),
+        package.join("src/main.ppl"),
+    )
+    .unwrap();
+
+    let run_log = crate::e2e::internal::run_package(&package, &["--", "a", "--b"]);
+    assert_snapshot!("program_arguments.run", run_log);
+}
+
+#[test]
+fn read_line() {
+    use std::path::Path;
+
+    use insta::assert_snapshot;
+    use miette::miette;
+    use tempdir::TempDir;
+
+    let temp_dir = TempDir::new("ppl").unwrap();
+    let tmp = temp_dir.path();
+    let name = "read_line";
+    let dir = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/src/tests/read_line"));
+
+    crate::e2e::internal::compile(&tmp, &dir);
+
+    // Last line is read after the end of input
+    let (run_log, status) = crate::e2e::internal::run_with_input(&tmp, name, &dir, "Bob\n41\r\n");
+    assert_snapshot!("read_line.run", run_log);
+    status.exit_ok().map_err(|e| miette!("{e}")).unwrap();
+}
+
+#[test]
+fn stderr() {
+    use std::path::Path;
+
+    use insta::assert_snapshot;
+    use miette::miette;
+    use tempdir::TempDir;
+
+    let temp_dir = TempDir::new("ppl").unwrap();
+    let tmp = temp_dir.path();
+    let name = "stderr";
+    let dir = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/src/tests/stderr"));
+
+    crate::e2e::internal::compile(&tmp, &dir);
+
+    // Run log has stdout before stderr
+    let (run_log, status) = crate::e2e::internal::run(&tmp, name, &dir);
+    assert_snapshot!("stderr.run", run_log);
+    status.exit_ok().map_err(|e| miette!("{e}")).unwrap();
+}